@@ -1,33 +1,66 @@
-use soroban_sdk::{Address, Env};
+use soroban_sdk::{panic_with_error, Address, Env, Symbol};
 
+use crate::error::{Error, FeatureError};
 use crate::storage_types::DataKey;
 
-pub fn read_administrator(e: &Env) -> Address {
+pub fn read_admin(e: &Env) -> Address {
     e.storage().instance().get(&DataKey::Admin).unwrap()
 }
 
-pub fn write_administrator(e: &Env, id: &Address) {
+/// Stores the admin and drops any pending handover, which the previous
+/// admin proposed.
+pub fn write_admin(e: &Env, id: &Address) {
     e.storage().instance().set(&DataKey::Admin, id);
+    clear_pending_admin(e);
 }
 
-pub fn has_administrator(e: &Env) -> bool {
+pub fn has_admin(e: &Env) -> bool {
     e.storage().instance().has(&DataKey::Admin)
 }
 
 pub fn check_admin(e: &Env, admin: &Address) {
     admin.require_auth();
-    let stored = read_administrator(e);
+    let stored = read_admin(e);
     if admin != &stored {
-        panic!("not authorized: caller is not the admin");
+        panic_with_error!(e, Error::Unauthorized);
     }
 }
 
 
-pub fn transfer_admin(e: &Env, new_admin: Address) {
-    // 1. Verify that the current admin is authorizing this call
+/// Admin awaiting `accept_admin`, if a handover is in progress.
+pub fn read_pending_admin(e: &Env) -> Option<Address> {
+    e.storage().instance().get(&DataKey::PendingAdmin)
+}
+
+/// First step of a handover: records `new_admin` without giving it any
+/// power. A later proposal replaces an earlier one.
+pub fn propose_admin(e: &Env, new_admin: Address) {
     let current_admin = read_admin(e);
     current_admin.require_auth();
 
-    // 2. Write the new admin to persistent storage
+    e.storage().instance().set(&DataKey::PendingAdmin, &new_admin);
+    e.events().publish(
+        (Symbol::new(e, "admin"), Symbol::new(e, "proposed")),
+        (current_admin, new_admin)
+    );
+}
+
+/// Drops any handover in progress, e.g. when admin rotation moves behind
+/// multisig approval or the timelock.
+pub fn clear_pending_admin(e: &Env) {
+    e.storage().instance().remove(&DataKey::PendingAdmin);
+}
+
+/// Second step of a handover: the proposed admin takes over, proving it
+/// controls the address.
+pub fn accept_admin(e: &Env) {
+    let new_admin = read_pending_admin(e).unwrap_or_else(|| panic_with_error!(e, FeatureError::NoPendingAdmin));
+    new_admin.require_auth();
+
+    let old_admin = read_admin(e);
     write_admin(e, &new_admin);
-}
\ No newline at end of file
+    e.events().publish(
+        (Symbol::new(e, "admin"), Symbol::new(e, "accepted")),
+        (old_admin, new_admin)
+    );
+}
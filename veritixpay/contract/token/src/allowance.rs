@@ -1,5 +1,6 @@
-use crate::storage_types::{AllowanceDataKey, AllowanceValue, DataKey};
-use soroban_sdk::{Address, Env};
+use crate::error::{Error, FeatureError};
+use crate::storage_types::{AllowanceDataKey, AllowanceValue, DataKey, DEFAULT_ALLOWANCE_WINDOW};
+use soroban_sdk::{panic_with_error, Address, Env};
 
 pub fn read_allowance(e: &Env, from: Address, spender: Address) -> AllowanceValue {
     let key = DataKey::Allowance(AllowanceDataKey {
@@ -32,7 +33,7 @@ pub fn write_allowance(
     expiration_ledger: u32,
 ) {
     if expiration_ledger < e.ledger().sequence() {
-        panic!("expiration ledger is in the past");
+        panic_with_error!(e, Error::ExpirationInPast);
     }
 
     let key = DataKey::Allowance(AllowanceDataKey {
@@ -51,22 +52,117 @@ pub fn write_allowance(
     }
 }
 
-pub fn spend_allowance(e: &Env, from: Address, spender: Address, amount: i128) {
+/// Deletes an expired allowance entry to reclaim its storage. Anyone may
+/// call this; it panics while the allowance is still live.
+pub fn sweep_allowance(e: &Env, from: Address, spender: Address) {
+    let key = DataKey::Allowance(AllowanceDataKey { from, spender });
+    let allowance: AllowanceValue = e
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| panic_with_error!(e, Error::AllowanceNotFound));
+
+    if allowance.expiration_ledger >= e.ledger().sequence() {
+        panic_with_error!(e, Error::AllowanceLive);
+    }
+    e.storage().persistent().remove(&key);
+}
+
+pub fn spend_allowance(e: &Env, from: Address, spender: Address, amount: i128) -> AllowanceValue {
     let allowance = read_allowance(e, from.clone(), spender.clone());
     
     if allowance.expiration_ledger < e.ledger().sequence() {
-        panic!("allowance is expired");
+        panic_with_error!(e, Error::AllowanceExpired);
     }
     
     if allowance.amount < amount {
-        panic!("insufficient allowance");
+        panic_with_error!(e, Error::InsufficientAllowance);
     }
     
+    let remaining = AllowanceValue {
+        amount: allowance.amount - amount,
+        expiration_ledger: allowance.expiration_ledger,
+    };
     write_allowance(
         e,
         from,
         spender,
-        allowance.amount - amount,
-        allowance.expiration_ledger,
+        remaining.amount,
+        remaining.expiration_ledger,
     );
+    remaining
+}
+
+/// Adds `amount` to an allowance. Without an explicit `expiration_ledger`,
+/// a live allowance keeps its stored expiration and a new or expired one
+/// gets `default_expiration_ledger`. Returns the updated allowance.
+pub fn increase_allowance(
+    e: &Env,
+    from: Address,
+    spender: Address,
+    amount: i128,
+    expiration_ledger: Option<u32>,
+) -> AllowanceValue {
+    if amount <= 0 {
+        panic_with_error!(e, Error::InvalidAmount);
+    }
+
+    let current = read_allowance(e, from.clone(), spender.clone());
+    let expiration_ledger = match expiration_ledger {
+        Some(ledger) => ledger,
+        None if current.amount > 0 => current.expiration_ledger,
+        None => default_expiration_ledger(e),
+    };
+    let new_amount = current
+        .amount
+        .checked_add(amount)
+        .unwrap_or_else(|| panic_with_error!(e, Error::AllowanceOverflow));
+
+    write_allowance(e, from, spender, new_amount, expiration_ledger);
+    AllowanceValue {
+        amount: new_amount,
+        expiration_ledger,
+    }
+}
+
+/// Subtracts `amount` from a live allowance, keeping its expiration.
+/// Panics rather than going below zero. Returns the updated allowance.
+pub fn decrease_allowance(e: &Env, from: Address, spender: Address, amount: i128) -> AllowanceValue {
+    if amount <= 0 {
+        panic_with_error!(e, Error::InvalidAmount);
+    }
+
+    let current = read_allowance(e, from.clone(), spender.clone());
+    if current.amount < amount {
+        panic_with_error!(e, Error::InsufficientAllowance);
+    }
+    let remaining = AllowanceValue {
+        amount: current.amount - amount,
+        expiration_ledger: current.expiration_ledger,
+    };
+
+    write_allowance(e, from, spender, remaining.amount, remaining.expiration_ledger);
+    remaining
+}
+
+pub fn read_default_allowance_window(e: &Env) -> u32 {
+    e.storage()
+        .instance()
+        .get(&DataKey::DefaultAllowanceWindow)
+        .unwrap_or(DEFAULT_ALLOWANCE_WINDOW)
+}
+
+pub fn write_default_allowance_window(e: &Env, ledgers: u32) {
+    e.storage()
+        .instance()
+        .set(&DataKey::DefaultAllowanceWindow, &ledgers);
+}
+
+/// Expiration ledger for an allowance created without an explicit one.
+/// Existing allowances keep their stored `expiration_ledger` when spent.
+pub fn default_expiration_ledger(e: &Env) -> u32 {
+    e.ledger()
+        .sequence()
+        .checked_add(read_default_allowance_window(e))
+        .unwrap_or_else(|| panic_with_error!(e, FeatureError::InvalidExpiration))
 }
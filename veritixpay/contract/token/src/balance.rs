@@ -1,5 +1,6 @@
-use crate::storage_types::{DataKey, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT};
-use soroban_sdk::{Address, Env};
+use crate::error::Error;
+use crate::storage_types::{BalanceKey, DataKey, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT};
+use soroban_sdk::{panic_with_error, Address, Env};
 
 /// Returns the balance for an address, or 0 if not set
 pub fn read_balance(e: &Env, addr: Address) -> i128 {
@@ -14,50 +15,111 @@ pub fn read_balance(e: &Env, addr: Address) -> i128 {
     }
 }
 
-/// Adds amount to address balance
+/// Returns the balance for an address without extending its TTL.
+/// Use this on read-only paths so view calls do not write to storage.
+pub fn peek_balance(e: &Env, addr: Address) -> i128 {
+    e.storage()
+        .persistent()
+        .get::<DataKey, i128>(&DataKey::Balance(addr))
+        .unwrap_or(0)
+}
+
+/// Whether the address has ever held a balance, i.e. a balance entry
+/// exists even if it is now zero. Does not extend the entry's TTL.
+pub fn is_active_account(e: &Env, addr: Address) -> bool {
+    e.storage().persistent().has(&DataKey::Balance(addr))
+}
+
+/// Adds amount to address balance — panics on a negative amount
 pub fn receive_balance(e: &Env, addr: Address, amount: i128) {
-    if crate::freeze::is_frozen(e, &addr) {
-        panic!("account frozen");
+    if amount < 0 {
+        panic_with_error!(e, Error::InvalidAmount);
+    }
+    crate::freeze::require_not_blacklisted(e, &addr);
+    if crate::freeze::is_inbound_frozen(e, &addr) {
+        panic_with_error!(e, Error::RecipientFrozen);
     }
     
     let key = DataKey::Balance(addr.clone());
-    let current_balance = read_balance(e, addr); // TTL is extended here
-    let new_balance = current_balance + amount;
+    let current_balance = read_balance(e, addr.clone()); // TTL is extended here
+    crate::snapshot::checkpoint_balance(e, &addr, current_balance);
+    crate::reflection::checkpoint_rewards(e, &addr, current_balance);
+    let new_balance = current_balance
+        .checked_add(amount)
+        .unwrap_or_else(|| panic_with_error!(e, Error::BalanceOverflow));
     
     e.storage().persistent().set(&key, &new_balance);
+    crate::votes::move_delegated_votes(e, &addr, amount);
 }
-/// Subtracts amount from address balance — panics if insufficient
+/// Subtracts amount from address balance — panics if insufficient or
+/// negative
 pub fn spend_balance(e: &Env, addr: Address, amount: i128) {
+    if amount < 0 {
+        panic_with_error!(e, Error::InvalidAmount);
+    }
     let key = DataKey::Balance(addr.clone());
-    let current_balance = read_balance(e, addr);
+    let current_balance = read_balance(e, addr.clone());
     
     if current_balance < amount {
-        panic!("insufficient balance: attempted to spend {} but only {} available", amount, current_balance);
+        panic_with_error!(e, Error::InsufficientBalance);
     }
+    crate::snapshot::checkpoint_balance(e, &addr, current_balance);
+    crate::reflection::checkpoint_rewards(e, &addr, current_balance);
     
     let new_balance = current_balance - amount;
     
     let storage = e.storage().persistent();
     storage.set(&key, &new_balance);
     storage.extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+    crate::votes::move_delegated_votes(e, &addr, -amount);
 }
 
-// In veritixpay/contract/token/src/balance.rs
-// (Make sure to import DataKey if not already imported)
+/// Amount the address has locked in escrows and splits that are not yet
+/// settled. These funds are held by the contract, so they are already
+/// excluded from the address's balance.
+pub fn read_locked(e: &Env, addr: Address) -> i128 {
+    e.storage()
+        .persistent()
+        .get::<BalanceKey, i128>(&BalanceKey::Locked(addr))
+        .unwrap_or(0)
+}
+
+/// Records `amount` as locked on behalf of the address.
+pub fn lock_balance(e: &Env, addr: Address, amount: i128) {
+    let locked = read_locked(e, addr.clone())
+        .checked_add(amount)
+        .unwrap_or_else(|| panic_with_error!(e, Error::BalanceOverflow));
+    e.storage().persistent().set(&BalanceKey::Locked(addr), &locked);
+}
+
+/// Releases `amount` of the address's locked funds once they are paid out
+/// or returned. Saturating, as locks made before the counter existed are
+/// untracked.
+pub fn unlock_balance(e: &Env, addr: Address, amount: i128) {
+    let key = BalanceKey::Locked(addr.clone());
+    let locked = (read_locked(e, addr) - amount).max(0);
+    if locked == 0 {
+        e.storage().persistent().remove(&key);
+    } else {
+        e.storage().persistent().set(&key, &locked);
+    }
+}
 
 pub fn read_total_supply(e: &Env) -> i128 {
     e.storage().instance().get(&DataKey::TotalSupply).unwrap_or(0)
 }
 
 pub fn increase_supply(e: &Env, amount: i128) {
-    let supply = read_total_supply(e);
-    e.storage().instance().set(&DataKey::TotalSupply, &(supply + amount));
+    let supply = read_total_supply(e)
+        .checked_add(amount)
+        .unwrap_or_else(|| panic_with_error!(e, Error::SupplyOverflow));
+    e.storage().instance().set(&DataKey::TotalSupply, &supply);
 }
 
 pub fn decrease_supply(e: &Env, amount: i128) {
     let supply = read_total_supply(e);
     if supply < amount {
-        panic!("supply cannot be negative");
+        panic_with_error!(e, Error::NegativeSupply);
     }
     e.storage().instance().set(&DataKey::TotalSupply, &(supply - amount));
-}
\ No newline at end of file
+}
@@ -1,209 +1,1488 @@
-use crate::admin::{check_admin, has_admin, write_admin, transfer_admin};
-use crate::allowance::{read_allowance, write_allowance};
-use crate::balance::{read_balance, receive_balance, spend_balance};
-use crate::metadata::{read_decimal, read_name, read_symbol, write_metadata};
-use soroban_sdk::{contract, contractimpl, symbol_short, Address, Env, String};
+use crate::admin::{
+    accept_admin, check_admin, clear_pending_admin, has_admin, propose_admin, read_admin,
+    read_pending_admin, write_admin,
+};
+use crate::allowance::{
+    decrease_allowance, default_expiration_ledger, increase_allowance, read_allowance,
+    read_default_allowance_window, spend_allowance, sweep_allowance, write_allowance,
+    write_default_allowance_window,
+};
+use crate::balance::{
+    decrease_supply, increase_supply, is_active_account, peek_balance, read_locked,
+    read_total_supply, receive_balance, spend_balance,
+};
+use crate::dispute::{
+    appeal_dispute, cast_panel_vote, execute_panel_decision, finalize_dispute, get_dispute,
+    get_evidence_with_meta, open_dispute, open_panel_dispute, panel_tally, read_dispute_bond,
+    resolve_dispute, resolve_dispute_split, resolve_dispute_with_burn, resolver_stats,
+    submit_evidence, write_appeal_panel, write_appeal_resolver, write_dispute_bond, DisputeRecord,
+    ResolverStats,
+};
+use crate::error::{Error, FeatureError};
+use crate::escrow::{
+    active_escrow_count, approve_early_release, cancel_escrow, change_escrow_beneficiary,
+    claim_expired, claim_multi_escrow, create_binding_escrow, create_escrow, create_escrow_ttl,
+    create_escrow_with_arbiter, create_multi_escrow, create_token_escrow,
+    create_vesting_multi_escrow, escrow_status, get_escrow, get_escrows_for, get_multi_escrow,
+    multi_claimable, multi_escrow_remaining, refund_escrow, refund_escrow_partial,
+    refund_multi_escrow, release_escrow, release_escrow_split, release_escrows,
+    release_multi_escrow, release_multi_escrow_to_claims, release_multi_recipient,
+    release_multi_recipients, settle_external, try_get_escrow, EscrowRecord, MultiEscrowRecord
+};
+use crate::export::{export_config, export_escrow_ids, export_split_ids, ExportedConfig};
+use crate::fee::{
+    quote_escrow_release, quote_transfer, read_fee_config, read_transfer_fee, take_transfer_fee,
+    write_fee_config, write_transfer_fee,
+};
+use crate::freeze::{
+    blacklist_account, freeze_account, freeze_many, is_blacklisted, is_frozen,
+    read_frozen_accounts, require_can_send, unblacklist_account, unfreeze_account, unfreeze_many,
+    FreezeMode,
+};
+use crate::milestone::{
+    approve_milestone, create_milestone_escrow, get_milestone_escrow, refund_milestones,
+    release_milestone, MilestoneEscrow,
+};
+use crate::htlc::{
+    claim_hashlock, create_hashlock_escrow, create_swap, get_hashlock_escrow, hashlock_timeout,
+    refund_hashlock, HashlockEscrow,
+};
+use crate::metadata::{
+    from_display_units, read_decimal, read_metadata, read_name, read_symbol, to_display_units,
+    update_metadata, write_metadata, TokenMetadata, MAX_DECIMALS, MAX_NAME_LEN, MAX_SYMBOL_LEN,
+};
+use crate::multisig::{
+    approve_action, get_action, propose_action, read_multisig, require_no_multisig,
+    take_approved_action, write_multisig, ActionProposal, AdminAction, MultisigConfig,
+};
+use crate::pause::{is_paused, require_not_paused, set_paused};
+use crate::rate_limit::{
+    clear_rate_limit, consume_mint_limit, consume_rate_limit, write_mint_limit, write_rate_limit,
+};
+use crate::recurring::{
+    cancel_recurring, execute_recurring, get_recurring, list_due_payments, next_payment_ledger,
+    remaining_iterations, setup_recurring, setup_recurring_charge_now, setup_recurring_split,
+    RecurringRecord
+};
+use crate::reflection::{claim_rewards, pending_rewards};
+use crate::roles::{check_role, grant_role, has_role, revoke_role, Role};
+use crate::scheduled::{
+    cancel_scheduled, execute_scheduled, get_scheduled, schedule_transfer, ScheduledTransfer,
+};
+use crate::snapshot::{balance_at, take_snapshot};
+use crate::splitter::{
+    calculate_distribution, claim_split, create_claimable_split, create_fixed_split, create_split,
+    create_split_with_strategy, create_token_split, distribute, get_split, read_max_recipients,
+    try_get_split, validate_bps, write_max_recipients, DustStrategy, SplitRecipient, SplitRecord
+};
+use crate::storage_types::{FeeConfig, TransferFee};
+use crate::supply::{
+    finalize_supply, is_mint_finalized, lower_supply_cap, read_supply_cap,
+    require_minting_enabled, require_within_cap, write_supply_cap, MAX_MINT_BATCH,
+};
+use crate::sweep::{admin_sweep, contract_balance, contract_token_balance};
+use crate::timelock::{
+    cancel_action, get_queued_action, queue_action, read_admin_delay, require_no_timelock,
+    take_ready_action, write_admin_delay, QueuedAction, TimelockedAction,
+};
+use crate::votes::{delegate, get_votes};
+use soroban_sdk::{
+    contract, contractimpl, panic_with_error, symbol_short,
+    token::{self, Interface as _},
+    Address, Bytes, BytesN, Env, String, Symbol, Vec,
+};
 
 #[contract]
 pub struct VeritixToken;
 
 #[contractimpl]
 impl VeritixToken {
+    /// Sets admin and metadata. Panics if already initialized.
+    pub fn initialize(e: Env, admin: Address, name: String, symbol: String, decimal: u32) {
+        Self::initialize_with_cap(e, admin, name, symbol, decimal, 0);
+    }
 
-    // --- NEW ADMIN FUNCTIONS ---
-    
-    pub fn freeze(e: Env, target: Address) {
-        crate::admin::check_admin(&e);
-        let admin = crate::admin::read_admin(&e);
-        freeze_account(&e, admin, target);
+    /// Like `initialize`, but fixes a maximum supply at genesis that `mint`
+    /// can never exceed. A `max_supply` of 0 means uncapped.
+    pub fn initialize_with_cap(
+        e: Env,
+        admin: Address,
+        name: String,
+        symbol: String,
+        decimal: u32,
+        max_supply: i128,
+    ) {
+        if has_admin(&e) {
+            panic_with_error!(e, FeatureError::AlreadyInitialized);
+        }
+        if decimal > MAX_DECIMALS {
+            panic_with_error!(e, FeatureError::InvalidDecimals);
+        }
+        if name.is_empty() || name.len() > MAX_NAME_LEN {
+            panic_with_error!(e, FeatureError::InvalidMetadata);
+        }
+        if symbol.is_empty() || symbol.len() > MAX_SYMBOL_LEN {
+            panic_with_error!(e, FeatureError::InvalidMetadata);
+        }
+        if max_supply < 0 {
+            panic_with_error!(e, FeatureError::InvalidSupplyCap);
+        }
+        write_admin(&e, &admin);
+        write_supply_cap(&e, max_supply);
+        write_metadata(
+            &e,
+            TokenMetadata {
+                name,
+                symbol,
+                decimal,
+            },
+        );
     }
 
-    pub fn unfreeze(e: Env, target: Address) {
-        crate::admin::check_admin(&e);
-        let admin = crate::admin::read_admin(&e);
-        unfreeze_account(&e, admin, target);
+    // --- Admin Functions ---
+
+    /// Admin-only. Delegates `role` to `account`; the admin holds every role.
+    pub fn grant_role(e: Env, admin: Address, role: Role, account: Address) {
+        check_admin(&e, &admin);
+        require_no_multisig(&e);
+        require_no_timelock(&e);
+        grant_role(&e, role, account);
     }
 
-    // --- UPDATED TOKEN FUNCTIONS ---
+    pub fn revoke_role(e: Env, admin: Address, role: Role, account: Address) {
+        check_admin(&e, &admin);
+        require_no_multisig(&e);
+        require_no_timelock(&e);
+        revoke_role(&e, role, account);
+    }
 
-    pub fn burn(e: Env, from: Address, amount: i128) {
-        if is_frozen(&e, &from) {
-            panic!("account frozen");
-        }
-        from.require_auth();
-        spend_balance(&e, from.clone(), amount);
-        e.events().publish((symbol_short!("burn"), from), amount);
+    /// Freezer role. Blocks the target from sending; it can still receive.
+    pub fn freeze(e: Env, freezer: Address, target: Address) {
+        check_role(&e, Role::Freezer, &freezer);
+        require_no_multisig(&e);
+        freeze_account(&e, target, FreezeMode::Outbound);
     }
 
-    pub fn transfer(e: Env, from: Address, to: Address, amount: i128) {
-        if is_frozen(&e, &from) {
-            panic!("account frozen");
-        }
-        from.require_auth();
-        spend_balance(&e, from.clone(), amount);
-        receive_balance(&e, to.clone(), amount);
-        e.events().publish((symbol_short!("transfer"), from, to), amount);
+    /// Freezer role. Freezes with an explicit mode; `Full` also blocks receiving.
+    pub fn freeze_with_mode(e: Env, freezer: Address, target: Address, mode: FreezeMode) {
+        check_role(&e, Role::Freezer, &freezer);
+        require_no_multisig(&e);
+        freeze_account(&e, target, mode);
     }
 
-    pub fn transfer_from(e: Env, spender: Address, from: Address, to: Address, amount: i128) {
-        if is_frozen(&e, &from) {
-            panic!("account frozen");
-        }
-        spender.require_auth();
-        let allowance = read_allowance(&e, from.clone(), spender.clone());
-        if allowance < amount {
-            panic!("insufficient allowance");
-        }
-        write_allowance(&e, from.clone(), spender, allowance - amount, e.ledger().sequence() + 100);
-        spend_balance(&e, from.clone(), amount);
-        receive_balance(&e, to.clone(), amount);
-        e.events().publish((symbol_short!("transfer"), from, to), amount);
+    pub fn unfreeze(e: Env, freezer: Address, target: Address) {
+        check_role(&e, Role::Freezer, &freezer);
+        require_no_multisig(&e);
+        unfreeze_account(&e, target);
     }
 
-    /// Sets admin and metadata. Panics if already initialized.
-    pub fn initialize(e: Env, admin: Address, decimal: u32, name: String, symbol: String) {
-        if has_admin(&e) {
-            panic!("already initialized");
+    /// Freezer role. Outbound-freezes up to `MAX_FREEZE_BATCH` addresses at once.
+    pub fn freeze_many(e: Env, freezer: Address, targets: Vec<Address>) {
+        check_role(&e, Role::Freezer, &freezer);
+        require_no_multisig(&e);
+        freeze_many(&e, targets);
+    }
+
+    pub fn unfreeze_many(e: Env, freezer: Address, targets: Vec<Address>) {
+        check_role(&e, Role::Freezer, &freezer);
+        require_no_multisig(&e);
+        unfreeze_many(&e, targets);
+    }
+
+    /// Admin-only. Stellar Asset style authorization: deauthorizing applies
+    /// a `Full` freeze, authorizing lifts any freeze.
+    pub fn set_authorized(e: Env, admin: Address, id: Address, authorize: bool) {
+        check_admin(&e, &admin);
+        require_no_multisig(&e);
+        require_no_timelock(&e);
+        if authorize {
+            unfreeze_account(&e, id);
+        } else {
+            freeze_account(&e, id, FreezeMode::Full);
         }
-        write_admin(&e, &admin);
-        write_metadata(&e, decimal, name, symbol);
+    }
+
+    /// Pauser role. Halts transfers, burns, escrow and split creation,
+    /// recurring charges, scheduled transfers, hashlock claims and refunds and
+    /// external settlements until `unpause`. Hashlock timeouts keep running.
+    pub fn pause(e: Env, pauser: Address) {
+        check_role(&e, Role::Pauser, &pauser);
+        set_paused(&e, true);
+    }
+
+    pub fn unpause(e: Env, pauser: Address) {
+        check_role(&e, Role::Pauser, &pauser);
+        set_paused(&e, false);
+    }
+
+    /// Admin-only. Permanently sanctions an address: it can no longer send,
+    /// receive, or be an escrow party.
+    pub fn blacklist(e: Env, admin: Address, target: Address) {
+        check_admin(&e, &admin);
+        require_no_multisig(&e);
+        require_no_timelock(&e);
+        blacklist_account(&e, target);
+    }
+
+    pub fn unblacklist(e: Env, admin: Address, target: Address) {
+        check_admin(&e, &admin);
+        require_no_multisig(&e);
+        require_no_timelock(&e);
+        unblacklist_account(&e, target);
     }
 
     /// Admin-only. Reclaims tokens from an address and destroys them.
-    pub fn clawback(e: Env, from: Address, amount: i128) {
-        check_admin(&e);
-        
-        // Deduct balance without redistributing, effectively burning the tokens
+    pub fn clawback(e: Env, admin: Address, from: Address, amount: i128) {
+        check_admin(&e, &admin);
+        require_no_multisig(&e);
+        require_no_timelock(&e);
+        clawback_from(&e, admin, from, amount);
+    }
+
+    /// Admin-only. Corrects a misdirected mint by moving `amount` from
+    /// `from` to `to`. Total supply is unchanged.
+    pub fn reverse_mint(e: Env, admin: Address, from: Address, amount: i128, to: Address) {
+        check_admin(&e, &admin);
+        require_no_multisig(&e);
+        require_no_timelock(&e);
+        if amount <= 0 {
+            panic_with_error!(e, Error::InvalidAmount);
+        }
+
         spend_balance(&e, from.clone(), amount);
+        receive_balance(&e, to.clone(), amount);
 
-        // Emit transparency event
         e.events().publish(
-            (symbol_short!("clawback"), from),
-            amount
+            (symbol_short!("mint"), symbol_short!("reversed")),
+            (from, to, amount)
         );
     }
 
-    /// Rotates the contract administrator. Requires current admin auth.
+    /// Alias of `propose_admin`, kept for Stellar Asset compatibility;
+    /// `new_admin` must still call `accept_admin`.
     pub fn set_admin(e: Env, new_admin: Address) {
-        transfer_admin(&e, new_admin);
+        require_no_multisig(&e);
+        require_no_timelock(&e);
+        propose_admin(&e, new_admin);
     }
 
-    /// Admin-only. Mints new tokens to a specific address.
-    pub fn mint(e: Env, to: Address, amount: i128) {
-        check_admin(&e);
-        receive_balance(&e, to.clone(), amount);
-        
-        // Emit Event
-        e.events().publish((symbol_short!("mint"), to), amount);
+    /// Starts a two-step handover; `new_admin` must call `accept_admin`
+    /// before it takes effect. Requires current admin auth.
+    pub fn propose_admin(e: Env, new_admin: Address) {
+        require_no_multisig(&e);
+        require_no_timelock(&e);
+        propose_admin(&e, new_admin);
     }
 
-    /// Caller burns their own tokens.
-    pub fn burn(e: Env, from: Address, amount: i128) {
+    /// Completes a handover started with `propose_admin`. Requires the
+    /// proposed admin's auth.
+    pub fn accept_admin(e: Env) {
+        require_no_multisig(&e);
+        require_no_timelock(&e);
+        accept_admin(&e);
+    }
+
+    /// Admin-only. Switches to multisig administration: from then on `mint`,
+    /// `clawback`, admin rotation, freezes and unfreezes only run as approved
+    /// actions, and `reverse_mint`, `set_authorized`, `blacklist`,
+    /// `unblacklist`, role changes, `set_transfer_fee` and `admin_sweep` are
+    /// unavailable.
+    /// Later changes to the signer set are themselves actions. Exclusive with
+    /// the admin timelock, so it cannot be enabled while a delay is set.
+    /// Drops any pending admin handover.
+    pub fn set_multisig(e: Env, admin: Address, signers: Vec<Address>, threshold: u32) {
+        check_admin(&e, &admin);
+        require_no_multisig(&e);
+        require_no_timelock(&e);
+        clear_pending_admin(&e);
+        write_multisig(&e, signers, threshold);
+    }
+
+    /// Signer-only. Proposes a sensitive admin action and approves it.
+    pub fn propose_action(e: Env, proposer: Address, action: AdminAction) -> u32 {
+        propose_action(&e, proposer, action)
+    }
+
+    pub fn approve_action(e: Env, signer: Address, action_id: u32) {
+        approve_action(&e, signer, action_id);
+    }
+
+    /// Applies an action once it has `threshold` approvals. Anyone can call this.
+    pub fn execute_action(e: Env, action_id: u32) {
+        match take_approved_action(&e, action_id) {
+            AdminAction::Mint(to, amount) => mint_to(&e, read_admin(&e), to, amount),
+            AdminAction::Clawback(from, amount) => clawback_from(&e, read_admin(&e), from, amount),
+            AdminAction::SetAdmin(new_admin) => write_admin(&e, &new_admin),
+            AdminAction::Freeze(target) => freeze_account(&e, target, FreezeMode::Outbound),
+            AdminAction::Unfreeze(target) => unfreeze_account(&e, target),
+            AdminAction::SetMultisig(signers, threshold) => write_multisig(&e, signers, threshold),
+        }
+    }
+
+    /// Admin-only. Renames the token; decimals cannot change.
+    pub fn update_metadata(e: Env, admin: Address, name: String, symbol: String) {
+        check_admin(&e, &admin);
+        require_no_timelock(&e);
+        update_metadata(&e, name, symbol);
+    }
+
+    /// Admin-only. Turns on the admin timelock: `clawback`, admin rotation
+    /// and metadata changes must then be queued and wait `delay_ledgers`,
+    /// and `reverse_mint`, `set_authorized`, `blacklist`, `unblacklist`, role
+    /// changes, `set_transfer_fee` and `admin_sweep` are unavailable.
+    /// Later delay changes are themselves queued actions. Exclusive with
+    /// multisig administration, so it cannot be enabled once signers are set.
+    /// Drops any pending admin handover.
+    pub fn set_admin_timelock(e: Env, admin: Address, delay_ledgers: u32) {
+        check_admin(&e, &admin);
+        require_no_multisig(&e);
+        require_no_timelock(&e);
+        clear_pending_admin(&e);
+        write_admin_delay(&e, delay_ledgers);
+    }
+
+    /// Admin-only. Queues a timelocked action; returns its id.
+    pub fn queue_admin_action(e: Env, admin: Address, action: TimelockedAction) -> u32 {
+        check_admin(&e, &admin);
+        require_no_multisig(&e);
+        queue_action(&e, action)
+    }
+
+    /// Admin-only. Drops a queued action before it runs.
+    pub fn cancel_admin_action(e: Env, admin: Address, action_id: u32) {
+        check_admin(&e, &admin);
+        cancel_action(&e, action_id);
+    }
+
+    /// Applies a queued action once its delay has passed. Anyone can call this.
+    /// Actions left queued when multisig administration began never run.
+    pub fn execute_admin_action(e: Env, action_id: u32) {
+        require_no_multisig(&e);
+        match take_ready_action(&e, action_id) {
+            TimelockedAction::Clawback(from, amount) => {
+                clawback_from(&e, read_admin(&e), from, amount)
+            }
+            TimelockedAction::SetAdmin(new_admin) => write_admin(&e, &new_admin),
+            TimelockedAction::SetMetadata(name, symbol) => update_metadata(&e, name, symbol),
+            TimelockedAction::SetDelay(delay_ledgers) => write_admin_delay(&e, delay_ledgers),
+        }
+    }
+
+    /// Admin-only. Sets the lifetime (in ledgers) applied to newly created
+    /// allowances that are approved without an explicit expiration.
+    pub fn set_default_allowance_window(e: Env, admin: Address, ledgers: u32) {
+        check_admin(&e, &admin);
+        write_default_allowance_window(&e, ledgers);
+    }
+
+    /// Admin-only. Replaces the whole fee schedule in one write.
+    pub fn set_fee_config(e: Env, admin: Address, config: FeeConfig) {
+        check_admin(&e, &admin);
+        write_fee_config(&e, &config);
+    }
+
+    /// Admin-only. Sets the higher-tier resolver that appealed disputes
+    /// escalate to. While set, resolutions wait out the appeal window.
+    pub fn set_appeal_resolver(e: Env, admin: Address, resolver: Address) {
+        check_admin(&e, &admin);
+        write_appeal_resolver(&e, &resolver);
+    }
+
+    /// Admin-only. Sets the senior panel that appealed disputes escalate to,
+    /// taking precedence over the appeal resolver. Empty removes it.
+    pub fn set_appeal_panel(e: Env, admin: Address, panel: Vec<Address>) {
+        check_admin(&e, &admin);
+        write_appeal_panel(&e, &panel);
+    }
+
+    /// Admin-only. Sets the bond claimants lock when opening a dispute.
+    pub fn set_dispute_bond(e: Env, admin: Address, bond: i128) {
+        check_admin(&e, &admin);
+        write_dispute_bond(&e, bond);
+    }
+
+    pub fn dispute_bond(e: Env) -> i128 {
+        read_dispute_bond(&e)
+    }
+
+    /// Admin-only. Caps how much `addr` can send via `transfer`/`transfer_from`
+    /// per `window_ledgers`. Both must be non-zero.
+    pub fn set_rate_limit(
+        e: Env,
+        admin: Address,
+        addr: Address,
+        max_per_window: i128,
+        window_ledgers: u32,
+    ) {
+        check_admin(&e, &admin);
+        write_rate_limit(&e, &addr, max_per_window, window_ledgers);
+    }
+
+    /// Admin-only. Lifts the outbound limit set by `set_rate_limit`.
+    pub fn clear_rate_limit(e: Env, admin: Address, addr: Address) {
+        check_admin(&e, &admin);
+        clear_rate_limit(&e, &addr);
+    }
+
+    /// Admin-only. Caps how much can be minted per day. Zero clears the cap.
+    pub fn set_mint_limit(e: Env, admin: Address, max_per_day: i128) {
+        check_admin(&e, &admin);
+        write_mint_limit(&e, max_per_day);
+    }
+
+    /// Admin-only. Sets the fee taken on every transfer; 0 bps disables it.
+    /// Without a collector the fee is burned.
+    pub fn set_transfer_fee(e: Env, admin: Address, fee: TransferFee) {
+        check_admin(&e, &admin);
+        require_no_multisig(&e);
+        require_no_timelock(&e);
+        write_transfer_fee(&e, &fee);
+    }
+
+    /// Admin-only. Recovers stranded contract funds that no escrow, split or
+    /// hashlock still owes.
+    pub fn admin_sweep(e: Env, admin: Address, token_address: Address, amount: i128, to: Address) {
+        check_admin(&e, &admin);
+        require_no_multisig(&e);
+        require_no_timelock(&e);
+        admin_sweep(&e, token_address, amount, to);
+    }
+
+    /// Admin-only. Records a balance snapshot for governance votes.
+    pub fn take_snapshot(e: Env, admin: Address) -> u32 {
+        check_admin(&e, &admin);
+        take_snapshot(&e)
+    }
+
+    /// Admin-only. Permanently disables minting to guarantee a fixed supply.
+    pub fn finalize_supply(e: Env, admin: Address) {
+        check_admin(&e, &admin);
+        finalize_supply(&e);
+    }
+
+    /// Admin-only. Lowers the maximum supply; it can never be raised.
+    pub fn lower_supply_cap(e: Env, admin: Address, new_cap: i128) {
+        check_admin(&e, &admin);
+        lower_supply_cap(&e, new_cap);
+    }
+
+    /// Minter role. Mints new tokens to a specific address.
+    pub fn mint(e: Env, minter: Address, to: Address, amount: i128) {
+        check_role(&e, Role::Minter, &minter);
+        require_no_multisig(&e);
+        mint_to(&e, minter, to, amount);
+    }
+
+    /// Minter role. Mints `total_amount` across recipients by bps share,
+    /// with the rounding dust going to the last recipient.
+    pub fn mint_split(e: Env, minter: Address, total_amount: i128, recipients: Vec<SplitRecipient>) {
+        check_role(&e, Role::Minter, &minter);
+        require_no_multisig(&e);
+        require_minting_enabled(&e);
+        validate_bps(&recipients);
+        require_within_cap(&e, total_amount);
+        consume_mint_limit(&e, total_amount);
+
+        let amounts = calculate_distribution(&e, total_amount, &recipients);
+        for (recipient, amount) in recipients.iter().zip(amounts.iter()) {
+            receive_balance(&e, recipient.address.clone(), amount);
+            e.events().publish((symbol_short!("mint"), minter.clone(), recipient.address), amount);
+        }
+        increase_supply(&e, total_amount); // Update global supply once
+    }
+
+    /// Minter role. Mints to up to `MAX_MINT_BATCH` recipients at once, for
+    /// airdrops and migrations. Supply is updated once for the whole batch.
+    pub fn mint_batch(e: Env, minter: Address, mints: Vec<(Address, i128)>) {
+        check_role(&e, Role::Minter, &minter);
+        require_no_multisig(&e);
+        require_minting_enabled(&e);
+        if mints.is_empty() {
+            panic_with_error!(e, FeatureError::EmptyBatch);
+        }
+        if mints.len() > MAX_MINT_BATCH {
+            panic_with_error!(e, FeatureError::BatchTooLarge);
+        }
+
+        let mut total_amount: i128 = 0;
+        for (_, amount) in mints.iter() {
+            if amount <= 0 {
+                panic_with_error!(e, Error::InvalidAmount);
+            }
+            total_amount = total_amount
+                .checked_add(amount)
+                .unwrap_or_else(|| panic_with_error!(e, Error::SupplyOverflow));
+        }
+        require_within_cap(&e, total_amount);
+        consume_mint_limit(&e, total_amount);
+
+        for (to, amount) in mints.iter() {
+            receive_balance(&e, to.clone(), amount);
+            e.events().publish((symbol_short!("mint"), minter.clone(), to), amount);
+        }
+        increase_supply(&e, total_amount); // Update global supply once
+    }
+
+    // --- Token Functions ---
+
+    /// Same as `transfer`, with a payment reference (e.g. an order id hash)
+    /// as a fourth topic of the transfer event for off-chain reconciliation.
+    pub fn transfer_with_memo(e: Env, from: Address, to: Address, amount: i128, memo: BytesN<32>) {
         from.require_auth();
-        spend_balance(&e, from.clone(), amount);
-        
-        // Emit Event
-        e.events().publish((symbol_short!("burn"), from), amount);
+        let received = move_funds(&e, &from, &to, amount);
+        e.events().publish((symbol_short!("transfer"), from, to, memo), received);
     }
 
-    /// Spender burns tokens from an account using their allowance.
-    pub fn burn_from(e: Env, spender: Address, from: Address, amount: i128) {
-        spender.require_auth();
-        let allowance = read_allowance(&e, from.clone(), spender.clone());
-        if allowance < amount {
-            panic!("insufficient allowance");
+    /// Same as `approve`, with the expiration given as ledgers from now.
+    pub fn approve_for(e: Env, from: Address, spender: Address, amount: i128, valid_for_ledgers: u32) {
+        let expiration_ledger = e
+            .ledger()
+            .sequence()
+            .checked_add(valid_for_ledgers)
+            .unwrap_or_else(|| panic_with_error!(e, FeatureError::InvalidExpiration));
+        Self::approve(e, from, spender, amount, expiration_ledger);
+    }
+
+    /// Same as `approve`, expiring after the admin-set default allowance
+    /// window (see `set_default_allowance_window`).
+    pub fn approve_default(e: Env, from: Address, spender: Address, amount: i128) {
+        let expiration_ledger = default_expiration_ledger(&e);
+        Self::approve(e, from, spender, amount, expiration_ledger);
+    }
+
+    /// Compare-and-set approve: only writes if the current allowance still
+    /// equals `expected_current`, closing the classic approve front-run.
+    pub fn approve_checked(
+        e: Env,
+        from: Address,
+        spender: Address,
+        expected_current: i128,
+        new_amount: i128,
+        expiration_ledger: u32,
+    ) {
+        from.require_auth();
+        if read_allowance(&e, from.clone(), spender.clone()).amount != expected_current {
+            panic_with_error!(e, FeatureError::AllowanceChanged);
         }
-        write_allowance(&e, from.clone(), spender, allowance - amount, e.ledger().sequence() + 100);
-        spend_balance(&e, from.clone(), amount);
-        
-        // Emit Event (burn_from also counts as a burn)
-        e.events().publish((symbol_short!("burn"), from), amount);
+        write_allowance(&e, from.clone(), spender.clone(), new_amount, expiration_ledger);
+
+        e.events().publish(
+            (symbol_short!("approve"), from, spender),
+            (new_amount, expiration_ledger)
+        );
     }
 
-    /// Standard token transfer between two addresses.
-    pub fn transfer(e: Env, from: Address, to: Address, amount: i128) {
+    /// Tops up an allowance. Pass `None` to keep the current expiration.
+    pub fn increase_allowance(
+        e: Env,
+        from: Address,
+        spender: Address,
+        amount: i128,
+        expiration_ledger: Option<u32>,
+    ) {
         from.require_auth();
-        spend_balance(&e, from.clone(), amount);
-        receive_balance(&e, to.clone(), amount);
-        
-        // Emit Event
-        e.events().publish((symbol_short!("transfer"), from, to), amount);
+        let allowance = increase_allowance(&e, from.clone(), spender.clone(), amount, expiration_ledger);
+
+        e.events().publish(
+            (symbol_short!("approve"), from, spender),
+            (allowance.amount, allowance.expiration_ledger)
+        );
     }
 
-    /// Transfer tokens on behalf of a user via allowance.
-    pub fn transfer_from(e: Env, spender: Address, from: Address, to: Address, amount: i128) {
-        spender.require_auth();
-        let allowance = read_allowance(&e, from.clone(), spender.clone());
-        if allowance < amount {
-            panic!("insufficient allowance");
+    /// Lowers an allowance without resetting it, keeping its expiration.
+    pub fn decrease_allowance(e: Env, from: Address, spender: Address, amount: i128) {
+        from.require_auth();
+        let allowance = decrease_allowance(&e, from.clone(), spender.clone(), amount);
+
+        e.events().publish(
+            (symbol_short!("approve"), from, spender),
+            (allowance.amount, allowance.expiration_ledger)
+        );
+    }
+
+    /// Deletes an expired allowance entry. Callable by anyone.
+    pub fn sweep_allowance(e: Env, from: Address, spender: Address) {
+        sweep_allowance(&e, from, spender);
+    }
+
+    /// Pays out the holder's accrued reflection rewards.
+    pub fn claim_rewards(e: Env, holder: Address) -> i128 {
+        claim_rewards(&e, holder)
+    }
+
+    /// Delegates the caller's voting weight to `delegatee` (self clears it).
+    pub fn delegate(e: Env, delegator: Address, delegatee: Address) {
+        delegate(&e, delegator, delegatee)
+    }
+
+    // --- Escrow Functions ---
+
+    pub fn create_escrow(
+        e: Env,
+        depositor: Address,
+        beneficiary: Address,
+        amount: i128,
+        expiration_ledger: u32,
+        release_after_ledger: u32,
+    ) -> u32 {
+        create_escrow(&e, depositor, beneficiary, amount, expiration_ledger, release_after_ledger)
+    }
+
+    /// Like `create_escrow`, with both ledgers given as offsets from now.
+    pub fn create_escrow_ttl(
+        e: Env,
+        depositor: Address,
+        beneficiary: Address,
+        amount: i128,
+        release_after_ledgers: u32,
+        expire_after_ledgers: u32,
+    ) -> u32 {
+        create_escrow_ttl(
+            &e,
+            depositor,
+            beneficiary,
+            amount,
+            release_after_ledgers,
+            expire_after_ledgers,
+        )
+    }
+
+    /// Like `create_escrow`, with a third-party `arbiter` who can release or
+    /// refund at any time before expiry.
+    pub fn create_escrow_with_arbiter(
+        e: Env,
+        depositor: Address,
+        beneficiary: Address,
+        arbiter: Address,
+        amount: i128,
+        expiration_ledger: u32,
+        release_after_ledger: u32,
+    ) -> u32 {
+        create_escrow_with_arbiter(
+            &e,
+            depositor,
+            beneficiary,
+            arbiter,
+            amount,
+            expiration_ledger,
+            release_after_ledger,
+        )
+    }
+
+    /// Like `create_escrow`, but holds `amount` of the SEP-41 token at
+    /// `token_address` (e.g. USDC) rather than this token.
+    pub fn create_token_escrow(
+        e: Env,
+        depositor: Address,
+        beneficiary: Address,
+        token_address: Address,
+        amount: i128,
+        expiration_ledger: u32,
+        release_after_ledger: u32,
+    ) -> u32 {
+        create_token_escrow(
+            &e,
+            depositor,
+            beneficiary,
+            token_address,
+            amount,
+            expiration_ledger,
+            release_after_ledger,
+        )
+    }
+
+    /// Like `create_escrow`, but refunds are only allowed from `expiration_ledger`.
+    pub fn create_binding_escrow(
+        e: Env,
+        depositor: Address,
+        beneficiary: Address,
+        amount: i128,
+        expiration_ledger: u32,
+        release_after_ledger: u32,
+    ) -> u32 {
+        create_binding_escrow(&e, depositor, beneficiary, amount, expiration_ledger, release_after_ledger)
+    }
+
+    /// Beneficiary once the timelock has passed; depositor or arbiter any time.
+    pub fn release_escrow(e: Env, caller: Address, escrow_id: u32) {
+        release_escrow(&e, caller, escrow_id);
+    }
+
+    /// Beneficiary-only. Releases the escrow across `recipients` by bps.
+    pub fn release_escrow_split(e: Env, escrow_id: u32, recipients: Vec<SplitRecipient>) {
+        release_escrow_split(&e, escrow_id, recipients);
+    }
+
+    /// Depositor-only. Releases the escrow by paying out in `external_token`.
+    pub fn settle_external(e: Env, escrow_id: u32, external_token: Address) {
+        settle_external(&e, escrow_id, external_token);
+    }
+
+    /// Depositor-only. Lets the escrow be released before its timelock.
+    pub fn approve_early_release(e: Env, escrow_id: u32) {
+        approve_early_release(&e, escrow_id);
+    }
+
+    /// Depositor-only, before `release_after_ledger`. Redirects the escrow.
+    pub fn change_escrow_beneficiary(e: Env, escrow_id: u32, new_beneficiary: Address) {
+        change_escrow_beneficiary(&e, escrow_id, new_beneficiary);
+    }
+
+    /// Admin-only. Releases a batch of escrows, e.g. end-of-event payouts.
+    pub fn release_escrows(e: Env, admin: Address, ids: Vec<u32>, strict: bool) -> Vec<u32> {
+        check_admin(&e, &admin);
+        release_escrows(&e, ids, strict)
+    }
+
+    /// Depositor (subject to a binding window) or arbiter.
+    pub fn refund_escrow(e: Env, caller: Address, escrow_id: u32) {
+        refund_escrow(&e, caller, escrow_id);
+    }
+
+    /// Refunds an escrow past its expiration ledger. Callable by anyone.
+    pub fn claim_expired(e: Env, escrow_id: u32) {
+        claim_expired(&e, escrow_id);
+    }
+
+    /// Mutual cancellation: depositor and beneficiary both authorize a refund.
+    pub fn cancel_escrow(e: Env, escrow_id: u32) {
+        cancel_escrow(&e, escrow_id);
+    }
+
+    /// Returns `amount` to the depositor and keeps the rest escrowed.
+    pub fn refund_escrow_partial(e: Env, escrow_id: u32, amount: i128) {
+        refund_escrow_partial(&e, escrow_id, amount);
+    }
+
+    pub fn get_escrow(e: Env, escrow_id: u32) -> EscrowRecord {
+        get_escrow(&e, escrow_id)
+    }
+
+    /// A page of the escrows `address` is depositor or beneficiary of,
+    /// oldest first.
+    pub fn get_escrows_for(e: Env, address: Address, start: u32, limit: u32) -> Vec<EscrowRecord> {
+        get_escrows_for(&e, address, start, limit)
+    }
+
+    /// Remaining locked amount and `active`/`released`/`refunded`/`expired`.
+    pub fn escrow_status(e: Env, escrow_id: u32) -> (i128, Symbol) {
+        escrow_status(&e, escrow_id)
+    }
+
+    /// Non-panicking lookup; `None` if the escrow does not exist.
+    /// (`try_get_escrow` on the client is the fallible form of `get_escrow`.)
+    pub fn find_escrow(e: Env, escrow_id: u32) -> Option<EscrowRecord> {
+        try_get_escrow(&e, escrow_id)
+    }
+
+    pub fn create_multi_escrow(
+        e: Env,
+        depositor: Address,
+        recipients: Vec<SplitRecipient>,
+        total_amount: i128,
+        expiration_ledger: u32,
+        release_after_ledger: u32,
+    ) -> u32 {
+        create_multi_escrow(
+            &e,
+            depositor,
+            recipients,
+            total_amount,
+            expiration_ledger,
+            release_after_ledger,
+        )
+    }
+
+    /// Multi-recipient escrow where recipient `i` vests at `release_after[i]`.
+    pub fn create_vesting_multi_escrow(
+        e: Env,
+        depositor: Address,
+        recipients: Vec<SplitRecipient>,
+        total_amount: i128,
+        expiration_ledger: u32,
+        release_after: Vec<u32>,
+    ) -> u32 {
+        create_vesting_multi_escrow(
+            &e,
+            depositor,
+            recipients,
+            total_amount,
+            expiration_ledger,
+            release_after,
+        )
+    }
+
+    /// Pays one recipient once their own timelock has passed.
+    pub fn release_multi_recipient(e: Env, escrow_id: u32, recipient_index: u32) {
+        release_multi_recipient(&e, escrow_id, recipient_index);
+    }
+
+    /// Pays only the chosen recipients of a multi-recipient escrow.
+    pub fn release_multi_recipients(e: Env, caller: Address, escrow_id: u32, indexes: Vec<u32>) {
+        release_multi_recipients(&e, caller, escrow_id, indexes);
+    }
+
+    /// Amount still locked for the unpaid recipients of a multi-escrow.
+    pub fn multi_escrow_remaining(e: Env, escrow_id: u32) -> i128 {
+        multi_escrow_remaining(&e, escrow_id)
+    }
+
+    pub fn release_multi_escrow(e: Env, caller: Address, escrow_id: u32) {
+        release_multi_escrow(&e, caller, escrow_id);
+    }
+
+    /// Depositor or admin. Allocates each share for the recipient to claim.
+    pub fn release_multi_escrow_to_claims(e: Env, caller: Address, escrow_id: u32) {
+        release_multi_escrow_to_claims(&e, caller, escrow_id);
+    }
+
+    pub fn claim_multi_escrow(e: Env, escrow_id: u32, recipient: Address) -> i128 {
+        claim_multi_escrow(&e, escrow_id, recipient)
+    }
+
+    pub fn multi_claimable(e: Env, escrow_id: u32, recipient: Address) -> i128 {
+        multi_claimable(&e, escrow_id, &recipient)
+    }
+
+    pub fn refund_multi_escrow(e: Env, caller: Address, escrow_id: u32) {
+        refund_multi_escrow(&e, caller, escrow_id);
+    }
+
+    pub fn get_multi_escrow(e: Env, escrow_id: u32) -> MultiEscrowRecord {
+        get_multi_escrow(&e, escrow_id)
+    }
+
+    // --- Dispute Functions ---
+
+    pub fn open_dispute(e: Env, claimant: Address, escrow_id: u32, resolver: Address) -> u32 {
+        open_dispute(&e, claimant, escrow_id, resolver)
+    }
+
+    /// Opens a dispute decided by a majority vote of `panel`.
+    pub fn open_panel_dispute(e: Env, claimant: Address, escrow_id: u32, panel: Vec<Address>) -> u32 {
+        open_panel_dispute(&e, claimant, escrow_id, panel)
+    }
+
+    pub fn cast_panel_vote(e: Env, arbiter: Address, dispute_id: u32, release_to_beneficiary: bool) {
+        cast_panel_vote(&e, arbiter, dispute_id, release_to_beneficiary);
+    }
+
+    /// `(for_beneficiary, for_depositor)` votes on a panel dispute.
+    pub fn panel_tally(e: Env, dispute_id: u32) -> (u32, u32) {
+        panel_tally(&e, dispute_id)
+    }
+
+    /// Decides a panel dispute by majority. Callable by anyone.
+    pub fn execute_panel_decision(e: Env, dispute_id: u32) {
+        execute_panel_decision(&e, dispute_id);
+    }
+
+    pub fn resolve_dispute(
+        e: Env,
+        resolver: Address,
+        dispute_id: u32,
+        release_to_beneficiary: bool,
+    ) {
+        resolve_dispute(&e, resolver, dispute_id, release_to_beneficiary);
+    }
+
+    /// Resolves with a penalty: `burn_bps` of the escrow is burned and the
+    /// winner receives the remainder.
+    pub fn resolve_dispute_with_burn(
+        e: Env,
+        resolver: Address,
+        dispute_id: u32,
+        release_to_beneficiary: bool,
+        burn_bps: u32,
+    ) {
+        resolve_dispute_with_burn(&e, resolver, dispute_id, release_to_beneficiary, burn_bps);
+    }
+
+    /// Resolves by paying `beneficiary_bps` of the escrow to the beneficiary
+    /// and the rest to the depositor.
+    pub fn resolve_dispute_split(e: Env, resolver: Address, dispute_id: u32, beneficiary_bps: u32) {
+        resolve_dispute_split(&e, resolver, dispute_id, beneficiary_bps);
+    }
+
+    pub fn appeal_dispute(e: Env, dispute_id: u32) {
+        appeal_dispute(&e, dispute_id);
+    }
+
+    pub fn finalize_dispute(e: Env, dispute_id: u32) {
+        finalize_dispute(&e, dispute_id);
+    }
+
+    pub fn submit_evidence(e: Env, dispute_id: u32, submitter: Address, evidence_hash: BytesN<32>) {
+        submit_evidence(&e, dispute_id, submitter, evidence_hash);
+    }
+
+    /// Chronological evidence timeline: `(submitter, evidence_hash, ledger)`.
+    pub fn get_evidence_with_meta(e: Env, dispute_id: u32) -> Vec<(Address, BytesN<32>, u32)> {
+        get_evidence_with_meta(&e, dispute_id)
+    }
+
+    pub fn get_dispute(e: Env, dispute_id: u32) -> DisputeRecord {
+        get_dispute(&e, dispute_id)
+    }
+
+    /// Decisions made by `resolver` and the total value it has adjudicated.
+    pub fn resolver_stats(e: Env, resolver: Address) -> ResolverStats {
+        resolver_stats(&e, &resolver)
+    }
+
+    // --- Milestone Escrow Functions ---
+
+    /// Locks one milestone per entry of `amounts`, each released separately.
+    pub fn create_milestone_escrow(
+        e: Env,
+        depositor: Address,
+        beneficiary: Address,
+        amounts: Vec<i128>,
+    ) -> u32 {
+        create_milestone_escrow(&e, depositor, beneficiary, amounts)
+    }
+
+    pub fn approve_milestone(e: Env, escrow_id: u32, index: u32) {
+        approve_milestone(&e, escrow_id, index);
+    }
+
+    pub fn release_milestone(e: Env, escrow_id: u32, index: u32) {
+        release_milestone(&e, escrow_id, index);
+    }
+
+    /// Returns the milestones not yet approved to the depositor.
+    pub fn refund_milestones(e: Env, escrow_id: u32) -> i128 {
+        refund_milestones(&e, escrow_id)
+    }
+
+    pub fn get_milestone_escrow(e: Env, escrow_id: u32) -> MilestoneEscrow {
+        get_milestone_escrow(&e, escrow_id)
+    }
+
+    // --- Hashlock Escrow Functions ---
+
+    pub fn create_hashlock_escrow(
+        e: Env,
+        depositor: Address,
+        beneficiary: Address,
+        amount: i128,
+        hashlock: BytesN<32>,
+        timeout_ledger: u32,
+    ) -> u32 {
+        create_hashlock_escrow(&e, depositor, beneficiary, amount, hashlock, timeout_ledger)
+    }
+
+    pub fn claim_hashlock(e: Env, escrow_id: u32, preimage: Bytes) {
+        claim_hashlock(&e, escrow_id, preimage);
+    }
+
+    pub fn refund_hashlock(e: Env, escrow_id: u32) {
+        refund_hashlock(&e, escrow_id);
+    }
+
+    /// Creates two linked hashlock escrows for an atomic swap, each leg in
+    /// its own token (this contract's address for this token). See
+    /// `htlc::create_swap` for the timeout ordering requirement.
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_swap(
+        e: Env,
+        initiator: Address,
+        counterparty: Address,
+        initiator_token: Address,
+        initiator_amount: i128,
+        counterparty_token: Address,
+        counterparty_amount: i128,
+        hashlock: BytesN<32>,
+        initiator_timeout: u32,
+        counterparty_timeout: u32,
+    ) -> (u32, u32) {
+        create_swap(
+            &e,
+            initiator,
+            counterparty,
+            initiator_token,
+            initiator_amount,
+            counterparty_token,
+            counterparty_amount,
+            hashlock,
+            initiator_timeout,
+            counterparty_timeout,
+        )
+    }
+
+    pub fn get_hashlock_escrow(e: Env, escrow_id: u32) -> HashlockEscrow {
+        get_hashlock_escrow(&e, escrow_id)
+    }
+
+    /// Ledger at which claims on the hashlock escrow close and the
+    /// depositor's refund opens. Not extended by a pause.
+    pub fn hashlock_timeout(e: Env, escrow_id: u32) -> u32 {
+        hashlock_timeout(&e, escrow_id)
+    }
+
+    // --- Recurring Payment Functions ---
+
+    pub fn setup_recurring(
+        e: Env,
+        payer: Address,
+        payee: Address,
+        amount: i128,
+        interval: u32,
+        iterations: u32,
+    ) -> u32 {
+        setup_recurring(&e, payer, payee, amount, interval, iterations)
+    }
+
+    /// Same as `setup_recurring`, but the first payment is taken immediately.
+    pub fn setup_recurring_charge_now(
+        e: Env,
+        payer: Address,
+        payee: Address,
+        amount: i128,
+        interval: u32,
+        iterations: u32,
+    ) -> u32 {
+        setup_recurring_charge_now(&e, payer, payee, amount, interval, iterations)
+    }
+
+    /// Recurring revenue share: each charge is split across `recipients` by bps.
+    pub fn setup_recurring_split(
+        e: Env,
+        payer: Address,
+        recipients: Vec<SplitRecipient>,
+        total_amount: i128,
+        interval: u32,
+        iterations: u32,
+    ) -> u32 {
+        setup_recurring_split(&e, payer, recipients, total_amount, interval, iterations)
+    }
+
+    pub fn execute_recurring(e: Env, recurring_id: u32) {
+        execute_recurring(&e, recurring_id);
+    }
+
+    /// Payer or payee stops the schedule; later executions fail.
+    pub fn cancel_recurring(e: Env, caller: Address, recurring_id: u32) {
+        cancel_recurring(&e, caller, recurring_id);
+    }
+
+    /// Recurring payments that can be executed now, up to `limit` ids,
+    /// scanning at most `MAX_DUE_SCAN` records from `start_id`.
+    pub fn list_due_payments(e: Env, start_id: u32, limit: u32) -> Vec<u32> {
+        list_due_payments(&e, start_id, limit)
+    }
+
+    pub fn next_payment_ledger(e: Env, recurring_id: u32) -> u32 {
+        next_payment_ledger(&e, recurring_id)
+    }
+
+    pub fn remaining_iterations(e: Env, recurring_id: u32) -> u32 {
+        remaining_iterations(&e, recurring_id)
+    }
+
+    pub fn get_recurring(e: Env, recurring_id: u32) -> RecurringRecord {
+        get_recurring(&e, recurring_id)
+    }
+
+    // --- Scheduled Transfer Functions ---
+
+    /// Locks funds now for a one-time payment at `execute_at_ledger`.
+    pub fn schedule_transfer(
+        e: Env,
+        from: Address,
+        to: Address,
+        amount: i128,
+        execute_at_ledger: u32,
+    ) -> u32 {
+        schedule_transfer(&e, from, to, amount, execute_at_ledger)
+    }
+
+    pub fn execute_scheduled(e: Env, id: u32) {
+        execute_scheduled(&e, id);
+    }
+
+    pub fn cancel_scheduled(e: Env, id: u32) {
+        cancel_scheduled(&e, id);
+    }
+
+    pub fn get_scheduled(e: Env, id: u32) -> ScheduledTransfer {
+        get_scheduled(&e, id)
+    }
+
+    // --- Split Functions ---
+
+    /// Admin-only. Caps recipients per split or multi-escrow, at most
+    /// `MAX_RECIPIENTS`.
+    pub fn set_max_recipients(e: Env, admin: Address, max: u32) {
+        check_admin(&e, &admin);
+        write_max_recipients(&e, max);
+    }
+
+    pub fn max_recipients(e: Env) -> u32 {
+        read_max_recipients(&e)
+    }
+
+    /// Locks `total_amount` for the recipients. Rounding dust goes to
+    /// `remainder_to` when set, otherwise to the last recipient.
+    pub fn create_split(
+        e: Env,
+        sender: Address,
+        recipients: Vec<SplitRecipient>,
+        total_amount: i128,
+        remainder_to: Option<Address>,
+    ) -> u32 {
+        create_split(&e, sender, recipients, total_amount, remainder_to)
+    }
+
+    /// Like `create_split`, choosing which recipient absorbs the dust.
+    pub fn create_split_with_strategy(
+        e: Env,
+        sender: Address,
+        recipients: Vec<SplitRecipient>,
+        total_amount: i128,
+        remainder_to: Option<Address>,
+        dust_strategy: DustStrategy,
+    ) -> u32 {
+        create_split_with_strategy(&e, sender, recipients, total_amount, remainder_to, dust_strategy)
+    }
+
+    /// Like `create_split`, but `distribute` opens the shares for recipients
+    /// to pull with `claim_split` rather than pushing them.
+    pub fn create_claimable_split(
+        e: Env,
+        sender: Address,
+        recipients: Vec<SplitRecipient>,
+        total_amount: i128,
+        remainder_to: Option<Address>,
+    ) -> u32 {
+        create_claimable_split(&e, sender, recipients, total_amount, remainder_to)
+    }
+
+    /// Split paying each recipient a fixed amount; the rest of `total_amount`
+    /// goes to `remainder_to` (or the last recipient).
+    pub fn create_fixed_split(
+        e: Env,
+        sender: Address,
+        recipients: Vec<Address>,
+        amounts: Vec<i128>,
+        total_amount: i128,
+        remainder_to: Option<Address>,
+    ) -> u32 {
+        create_fixed_split(&e, sender, recipients, amounts, total_amount, remainder_to)
+    }
+
+    /// Like `create_split`, paid out in the SEP-41 token at `token_address`.
+    pub fn create_token_split(
+        e: Env,
+        sender: Address,
+        recipients: Vec<SplitRecipient>,
+        total_amount: i128,
+        token_address: Address,
+        remainder_to: Option<Address>,
+    ) -> u32 {
+        create_token_split(&e, sender, recipients, total_amount, token_address, remainder_to)
+    }
+
+    pub fn distribute(e: Env, caller: Address, split_id: u32) {
+        distribute(&e, caller, split_id);
+    }
+
+    /// Non-panicking lookup; `None` if the split does not exist.
+    pub fn find_split(e: Env, split_id: u32) -> Option<SplitRecord> {
+        try_get_split(&e, split_id)
+    }
+
+    /// Recipient pulls their own share of an undistributed split.
+    pub fn claim_split(e: Env, split_id: u32, recipient: Address) -> i128 {
+        claim_split(&e, split_id, recipient)
+    }
+
+    pub fn get_split(e: Env, split_id: u32) -> SplitRecord {
+        get_split(&e, split_id)
+    }
+
+    // --- Read-Only Functions ---
+
+    /// Whether `role` was granted to `account`; false for the implicit admin.
+    pub fn has_role(e: Env, role: Role, account: Address) -> bool {
+        has_role(&e, role, &account)
+    }
+
+    pub fn multisig_config(e: Env) -> Option<MultisigConfig> {
+        read_multisig(&e)
+    }
+
+    pub fn get_action(e: Env, action_id: u32) -> ActionProposal {
+        get_action(&e, action_id)
+    }
+
+    /// Delay applied to queued admin actions; 0 when the timelock is off.
+    pub fn admin_timelock(e: Env) -> u32 {
+        read_admin_delay(&e)
+    }
+
+    pub fn get_queued_action(e: Env, action_id: u32) -> QueuedAction {
+        get_queued_action(&e, action_id)
+    }
+
+    pub fn pending_admin(e: Env) -> Option<Address> {
+        read_pending_admin(&e)
+    }
+
+    pub fn is_paused(e: Env) -> bool {
+        is_paused(&e)
+    }
+
+    /// False while the address is frozen or blacklisted.
+    pub fn authorized(e: Env, id: Address) -> bool {
+        !is_frozen(&e, &id) && !is_blacklisted(&e, &id)
+    }
+
+    /// Balance the address can currently move; 0 while it cannot send.
+    /// Funds locked in escrows and splits are never part of it, as they
+    /// are held by the contract (see `locked_balance`).
+    pub fn spendable_balance(e: Env, id: Address) -> i128 {
+        if is_frozen(&e, &id) || is_blacklisted(&e, &id) {
+            return 0;
         }
-        write_allowance(&e, from.clone(), spender, allowance - amount, e.ledger().sequence() + 100);
-        spend_balance(&e, from.clone(), amount);
-        receive_balance(&e, to.clone(), amount);
-        
-        // Emit Event
-        e.events().publish((symbol_short!("transfer"), from, to), amount);
+        peek_balance(&e, id)
+    }
+
+    /// Funds the address has locked in escrows and splits not yet settled.
+    pub fn locked_balance(e: Env, id: Address) -> i128 {
+        read_locked(&e, id)
+    }
+
+    /// False for an address that has never received tokens.
+    pub fn is_active_account(e: Env, addr: Address) -> bool {
+        is_active_account(&e, addr)
+    }
+
+    /// Escrows that are still neither released nor refunded.
+    pub fn active_escrow_count(e: Env) -> u32 {
+        active_escrow_count(&e)
+    }
+
+    /// Ids of every stored escrow, for migration scripts.
+    pub fn export_escrow_ids(e: Env) -> Vec<u32> {
+        export_escrow_ids(&e)
+    }
+
+    /// Ids of every stored split, for migration scripts.
+    pub fn export_split_ids(e: Env) -> Vec<u32> {
+        export_split_ids(&e)
+    }
+
+    /// Admin, metadata, fees and total supply in one read.
+    pub fn export_config(e: Env) -> ExportedConfig {
+        export_config(&e)
+    }
+
+    /// Maximum supply fixed at initialization; 0 means uncapped.
+    pub fn supply_cap(e: Env) -> i128 {
+        read_supply_cap(&e)
+    }
+
+    pub fn is_frozen(e: Env, addr: Address) -> bool {
+        is_frozen(&e, &addr)
+    }
+
+    pub fn frozen_accounts(e: Env) -> Vec<Address> {
+        read_frozen_accounts(&e)
+    }
+
+    pub fn is_blacklisted(e: Env, addr: Address) -> bool {
+        is_blacklisted(&e, &addr)
+    }
+
+    pub fn is_mint_finalized(e: Env) -> bool {
+        is_mint_finalized(&e)
+    }
+
+    pub fn total_supply(e: Env) -> i128 {
+        read_total_supply(&e)
+    }
+
+    /// Funds of this token custodied by the contract (escrows, splits, ...).
+    pub fn contract_balance(e: Env) -> i128 {
+        contract_balance(&e)
+    }
+
+    /// Contract holdings of any SEP-41 token, including this one.
+    pub fn contract_token_balance(e: Env, token_address: Address) -> i128 {
+        contract_token_balance(&e, token_address)
+    }
+
+    pub fn pending_rewards(e: Env, holder: Address) -> i128 {
+        pending_rewards(&e, holder)
+    }
+
+    /// Voting power of `addr` as of `snapshot_id`; unaffected by later transfers.
+    pub fn voting_power_at(e: Env, addr: Address, snapshot_id: u32) -> i128 {
+        balance_at(&e, addr, snapshot_id)
+    }
+
+    /// Current voting weight: own balance plus balances delegated in.
+    pub fn get_votes(e: Env, account: Address) -> i128 {
+        get_votes(&e, account)
+    }
+
+    pub fn default_allowance_window(e: Env) -> u32 {
+        read_default_allowance_window(&e)
+    }
+
+    pub fn get_fee_config(e: Env) -> FeeConfig {
+        read_fee_config(&e)
+    }
+
+    pub fn get_transfer_fee(e: Env) -> TransferFee {
+        read_transfer_fee(&e)
+    }
+
+    /// `(fee, net_to_beneficiary)` for releasing an escrow of `amount`.
+    pub fn quote_escrow_release(e: Env, amount: i128) -> (i128, i128) {
+        quote_escrow_release(&e, amount)
+    }
+
+    /// `(fee, net_to_recipient)` for transferring `amount`.
+    pub fn quote_transfer(e: Env, amount: i128) -> (i128, i128) {
+        quote_transfer(&e, amount)
+    }
+
+    /// `(whole, fraction)` of a raw amount at the token's decimals.
+    pub fn to_display_units(e: Env, raw: i128) -> (i128, i128) {
+        to_display_units(&e, raw)
+    }
+
+    pub fn from_display_units(e: Env, whole: i128, frac: i128) -> i128 {
+        from_display_units(&e, whole, frac)
+    }
+
+    /// Name, symbol and decimals in one call, for wallets rendering the token.
+    pub fn token_metadata(e: Env) -> TokenMetadata {
+        read_metadata(&e)
+    }
+}
+
+/// Shared body of `transfer` and `transfer_with_memo`; returns the amount
+/// received after fees. The caller checks auth and publishes the event.
+fn move_funds(e: &Env, from: &Address, to: &Address, amount: i128) -> i128 {
+    require_not_paused(e);
+    require_can_send(e, from);
+    consume_rate_limit(e, from, amount);
+    spend_balance(e, from.clone(), amount);
+    let received = amount - take_transfer_fee(e, from, amount);
+    receive_balance(e, to.clone(), received);
+    received
+}
+
+fn mint_to(e: &Env, minter: Address, to: Address, amount: i128) {
+    require_minting_enabled(e);
+    require_within_cap(e, amount);
+    consume_mint_limit(e, amount);
+    receive_balance(e, to.clone(), amount);
+    increase_supply(e, amount); // Update global supply
+    e.events().publish((symbol_short!("mint"), minter, to), amount);
+}
+
+fn clawback_from(e: &Env, admin: Address, from: Address, amount: i128) {
+    // Deduct balance without redistributing, effectively burning the tokens
+    spend_balance(e, from.clone(), amount);
+    decrease_supply(e, amount); // Update global supply
+
+    // Emit transparency event
+    e.events().publish(
+        (symbol_short!("clawback"), admin, from),
+        amount
+    );
+}
+
+// --- SEP-41 Token Interface ---
+
+#[contractimpl]
+impl token::Interface for VeritixToken {
+    fn balance(e: Env, id: Address) -> i128 {
+        peek_balance(&e, id)
+    }
+
+    fn allowance(e: Env, from: Address, spender: Address) -> i128 {
+        read_allowance(&e, from, spender).amount
     }
 
     /// Sets an allowance for a spender.
-    pub fn approve(e: Env, from: Address, spender: Address, amount: i128, expiration_ledger: u32) {
+    fn approve(e: Env, from: Address, spender: Address, amount: i128, expiration_ledger: u32) {
         from.require_auth();
         write_allowance(&e, from.clone(), spender.clone(), amount, expiration_ledger);
-        
-        // Emit Event
-        e.events().publish((symbol_short!("approve"), from, spender), amount);
+
+        // Emit Event (SEP-41: amount and expiration in the data)
+        e.events().publish(
+            (symbol_short!("approve"), from, spender),
+            (amount, expiration_ledger)
+        );
     }
 
-    pub fn mint(e: Env, to: Address, amount: i128) {
-        check_admin(&e);
-        receive_balance(&e, to.clone(), amount);
-        increase_supply(&e, amount); // Update global supply
-        e.events().publish((symbol_short!("mint"), to), amount);
+    /// Standard token transfer between two addresses.
+    fn transfer(e: Env, from: Address, to: Address, amount: i128) {
+        from.require_auth();
+        let received = move_funds(&e, &from, &to, amount);
+        e.events().publish((symbol_short!("transfer"), from, to), received);
     }
 
-    pub fn burn(e: Env, from: Address, amount: i128) {
+    /// Transfer tokens on behalf of a user via allowance.
+    fn transfer_from(e: Env, spender: Address, from: Address, to: Address, amount: i128) {
+        require_not_paused(&e);
+        require_can_send(&e, &from);
+        spender.require_auth();
+        let remaining = spend_allowance(&e, from.clone(), spender.clone(), amount);
+        consume_rate_limit(&e, &from, amount);
+        spend_balance(&e, from.clone(), amount);
+        let received = amount - take_transfer_fee(&e, &from, amount);
+        receive_balance(&e, to.clone(), received);
+        e.events().publish((symbol_short!("transfer"), from.clone(), to), received);
+
+        // Report the allowance left after spending, in the same shape as `approve`
+        e.events().publish(
+            (symbol_short!("approve"), from, spender),
+            (remaining.amount, remaining.expiration_ledger)
+        );
+    }
+
+    /// Caller burns their own tokens.
+    fn burn(e: Env, from: Address, amount: i128) {
+        require_not_paused(&e);
+        require_can_send(&e, &from);
         from.require_auth();
         spend_balance(&e, from.clone(), amount);
         decrease_supply(&e, amount); // Update global supply
         e.events().publish((symbol_short!("burn"), from), amount);
     }
 
-    pub fn burn_from(e: Env, spender: Address, from: Address, amount: i128) {
+    /// Spender burns tokens from an account using their allowance.
+    fn burn_from(e: Env, spender: Address, from: Address, amount: i128) {
+        require_not_paused(&e);
+        require_can_send(&e, &from);
         spender.require_auth();
-        let allowance = read_allowance(&e, from.clone(), spender.clone());
-        if allowance < amount {
-            panic!("insufficient allowance");
-        }
-        write_allowance(&e, from.clone(), spender, allowance - amount, e.ledger().sequence() + 100);
+        let remaining = spend_allowance(&e, from.clone(), spender.clone(), amount);
         spend_balance(&e, from.clone(), amount);
         decrease_supply(&e, amount); // Update global supply
-        e.events().publish((symbol_short!("burn"), from), amount);
-    }
-
-    // --- NEW PUBLIC FUNCTION ---
-    pub fn total_supply(e: Env) -> i128 {
-        read_total_supply(&e)
-    }
 
-    // --- Read-Only Functions ---
-
-    pub fn balance(e: Env, id: Address) -> i128 {
-        read_balance(&e, id)
-    }
-
-    pub fn allowance(e: Env, from: Address, spender: Address) -> i128 {
-        read_allowance(&e, from, spender)
+        // burn_from also counts as a burn
+        e.events().publish((symbol_short!("burn"), from.clone()), amount);
+        e.events().publish(
+            (symbol_short!("approve"), from, spender),
+            (remaining.amount, remaining.expiration_ledger)
+        );
     }
 
-    pub fn decimals(e: Env) -> u32 {
+    fn decimals(e: Env) -> u32 {
         read_decimal(&e)
     }
 
-    pub fn name(e: Env) -> String {
+    fn name(e: Env) -> String {
         read_name(&e)
     }
 
-    pub fn symbol(e: Env) -> String {
+    fn symbol(e: Env) -> String {
         read_symbol(&e)
     }
-}
\ No newline at end of file
+}
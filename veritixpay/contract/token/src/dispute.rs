@@ -1,6 +1,22 @@
-use crate::escrow::{get_escrow, release_escrow, refund_escrow};
-use crate::storage_types::DataKey;
-use soroban_sdk::{contracttype, Address, Env, Symbol};
+use crate::balance::{receive_balance, spend_balance};
+use crate::error::{Error, FeatureError};
+use crate::escrow::{
+    burn_escrow_portion, get_escrow, release_to_beneficiary, return_to_depositor, split_to_parties,
+};
+use crate::freeze::require_can_send;
+use crate::roles::{require_role, Role};
+use crate::storage_types::{DataKey, DisputeKey, DAY_IN_LEDGERS};
+use soroban_sdk::{contracttype, panic_with_error, Address, BytesN, Env, Symbol, Vec};
+
+/// Ledgers after a resolution during which the losing party may appeal.
+pub const APPEAL_WINDOW_LEDGERS: u32 = DAY_IN_LEDGERS;
+
+/// Most evidence hashes one party can attach to a single dispute.
+pub const MAX_EVIDENCE_PER_PARTY: u32 = 10;
+
+/// Upper bound on arbiters in a panel dispute, so a tally always fits
+/// within a single transaction's budget.
+pub const MAX_PANEL_SIZE: u32 = 9;
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -8,6 +24,8 @@ pub enum DisputeStatus {
     Open,
     ResolvedForBeneficiary,
     ResolvedForDepositor,
+    /// Both parties are paid, by `beneficiary_bps`.
+    ResolvedSplit,
 }
 
 #[contracttype]
@@ -18,49 +36,133 @@ pub struct DisputeRecord {
     pub claimant: Address,
     pub resolver: Address,
     pub status: DisputeStatus,
+    pub resolved_ledger: u32,
+    pub appealed: bool,
+    pub settled: bool,
+    /// Penalty share of the escrow, out of 10000, destroyed on settlement;
+    /// the winner receives the rest.
+    pub burn_bps: u32,
+    /// Locked by the claimant on opening. Returned if they win, paid to the
+    /// counterparty if they lose.
+    pub bond: i128,
+    /// Beneficiary's share of the escrow, out of 10000, in a split ruling.
+    pub beneficiary_bps: u32,
+    /// Arbiters deciding by majority vote; empty for a single-resolver
+    /// dispute. A panel dispute's `resolver` is the contract itself, so only
+    /// `execute_panel_decision` can decide it (until it is appealed).
+    pub panel: Vec<Address>,
+}
+
+/// Running totals of the decisions made by one resolver.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ResolverStats {
+    pub resolved: u32,
+    pub for_beneficiary: u32,
+    pub for_depositor: u32,
+    /// Sum of the escrowed amounts at the time of each decision.
+    pub total_value: i128,
 }
 
-/// Opens a dispute against an existing escrow.
+/// Opens a dispute against an existing escrow. The resolver must hold the
+/// `Resolver` role (or be the admin), and must still hold it to resolve.
+/// An appeal is decided by the admin-appointed appeal resolver instead.
+/// The claimant locks the configured dispute bond, if any.
 pub fn open_dispute(
     e: &Env,
     claimant: Address,
     escrow_id: u32,
     resolver: Address,
+) -> u32 {
+    require_role(e, Role::Resolver, &resolver);
+    file_dispute(e, claimant, escrow_id, resolver, Vec::new(e))
+}
+
+/// Opens a dispute decided by a majority of `panel`, for high-value
+/// escrows. Every arbiter must hold the `Resolver` role.
+pub fn open_panel_dispute(e: &Env, claimant: Address, escrow_id: u32, panel: Vec<Address>) -> u32 {
+    validate_panel(&panel);
+    for arbiter in panel.iter() {
+        require_role(e, Role::Resolver, &arbiter);
+    }
+    file_dispute(e, claimant, escrow_id, e.current_contract_address(), panel)
+}
+
+/// Panics unless the panel has between 1 and `MAX_PANEL_SIZE` distinct
+/// arbiters.
+fn validate_panel(panel: &Vec<Address>) {
+    if panel.is_empty() || panel.len() > MAX_PANEL_SIZE {
+        panic_with_error!(panel.env(), FeatureError::InvalidPanel);
+    }
+    for (i, arbiter) in panel.iter().enumerate() {
+        if panel.first_index_of(&arbiter) != Some(i as u32) {
+            panic_with_error!(panel.env(), FeatureError::InvalidPanel);
+        }
+    }
+}
+
+fn file_dispute(
+    e: &Env,
+    claimant: Address,
+    escrow_id: u32,
+    resolver: Address,
+    panel: Vec<Address>,
 ) -> u32 {
     // 1. Authorization: Only the claimant can initiate this call
     claimant.require_auth();
 
     // 2. Fetch escrow and validate current state
     let escrow = get_escrow(e, escrow_id);
-    
-    // Check if the escrow is already finalized
+
+    // Check if the escrow is already finalized or under another dispute
     if escrow.released || escrow.refunded {
-        panic!("InvalidState: Cannot open dispute on a settled escrow");
+        panic_with_error!(e, Error::AlreadySettled);
+    }
+    if is_escrow_disputed(e, escrow_id) {
+        panic_with_error!(e, FeatureError::EscrowDisputed);
     }
 
     // 3. Authorization check: Claimant must be a party involved in the escrow
     if claimant != escrow.depositor && claimant != escrow.beneficiary {
-        panic!("Unauthorized: Only depositor or beneficiary can open a dispute");
+        panic_with_error!(e, Error::Unauthorized);
     }
 
-    // 4. Generate a new Dispute ID using the counter in storage
+    // 4. Lock the claimant's bond in the contract
+    let bond = read_dispute_bond(e);
+    if bond > 0 {
+        require_can_send(e, &claimant);
+        spend_balance(e, claimant.clone(), bond);
+        receive_balance(e, e.current_contract_address(), bond);
+        write_held_bonds(e, held_bonds(e) + bond);
+    }
+
+    // 5. Generate a new Dispute ID using the counter in storage
     let mut count: u32 = e.storage().instance().get(&DataKey::DisputeCount).unwrap_or(0);
     count += 1;
     e.storage().instance().set(&DataKey::DisputeCount, &count);
 
-    // 5. Create and store the dispute record
+    // 6. Create and store the dispute record
     let record = DisputeRecord {
         id: count,
         escrow_id,
         claimant: claimant.clone(),
         resolver,
         status: DisputeStatus::Open,
+        resolved_ledger: 0,
+        appealed: false,
+        settled: false,
+        burn_bps: 0,
+        bond,
+        beneficiary_bps: 0,
+        panel,
     };
-    
-    // Store in persistent storage as disputes may last longer than instance TTL
+
+    // Store in persistent storage as disputes may last longer than instance TTL,
+    // and hold the escrow until the dispute settles
     e.storage().persistent().set(&DataKey::Dispute(count), &record);
+    e.storage().persistent().set(&DisputeKey::EscrowDispute(escrow_id), &count);
 
-    // 6. Emit Observability Event
+    // 7. Emit Observability Event
     e.events().publish(
         (Symbol::new(e, "dispute"), Symbol::new(e, "opened"), escrow_id),
         claimant
@@ -70,57 +172,462 @@ pub fn open_dispute(
 }
 
 /// Resolves an open dispute.
+///
+/// When an appeal resolver is configured and the dispute has not been
+/// appealed yet, funds stay escrowed until `finalize_dispute` runs after the
+/// appeal window. Otherwise the decision settles the escrow immediately.
 pub fn resolve_dispute(
     e: &Env,
     resolver: Address,
     dispute_id: u32,
     release_to_beneficiary: bool,
+) {
+    resolve_dispute_with_burn(e, resolver, dispute_id, release_to_beneficiary, 0);
+}
+
+/// Resolves an open dispute under a penalty clause: `burn_bps` of the
+/// escrowed amount is destroyed and the winning party receives the rest.
+pub fn resolve_dispute_with_burn(
+    e: &Env,
+    resolver: Address,
+    dispute_id: u32,
+    release_to_beneficiary: bool,
+    burn_bps: u32,
+) {
+    if burn_bps > 10000 {
+        panic_with_error!(e, Error::InvalidBps);
+    }
+    let status = if release_to_beneficiary {
+        DisputeStatus::ResolvedForBeneficiary
+    } else {
+        DisputeStatus::ResolvedForDepositor
+    };
+    decide_dispute(e, resolver, dispute_id, status, burn_bps, 0);
+}
+
+/// Resolves an open dispute by paying `beneficiary_bps` of the escrow to
+/// the beneficiary and the rest to the depositor (dust to the depositor).
+/// The escrow fee only applies to the beneficiary's share.
+pub fn resolve_dispute_split(e: &Env, resolver: Address, dispute_id: u32, beneficiary_bps: u32) {
+    if beneficiary_bps > 10000 {
+        panic_with_error!(e, Error::InvalidBps);
+    }
+    decide_dispute(e, resolver, dispute_id, DisputeStatus::ResolvedSplit, 0, beneficiary_bps);
+}
+
+fn decide_dispute(
+    e: &Env,
+    resolver: Address,
+    dispute_id: u32,
+    status: DisputeStatus,
+    burn_bps: u32,
+    beneficiary_bps: u32,
 ) {
     // 1. Authorization: Only the designated resolver can resolve the dispute
     resolver.require_auth();
 
     // 2. Fetch the dispute record
-    let mut dispute: DisputeRecord = e
-        .storage()
-        .persistent()
-        .get(&DataKey::Dispute(dispute_id))
-        .expect("Dispute not found");
+    let dispute = get_dispute(e, dispute_id);
 
     // 3. Validation: Check if already resolved (Double-resolution panic)
     if dispute.status != DisputeStatus::Open {
-        panic!("AlreadyResolved: This dispute has already been resolved");
+        panic_with_error!(e, FeatureError::AlreadyResolved);
     }
 
     // 4. Validation: Verify the resolver matches the record
     if dispute.resolver != resolver {
-        panic!("UnauthorizedResolver: Only the designated resolver can resolve this");
+        panic_with_error!(e, FeatureError::UnauthorizedResolver);
+    }
+    // The appeal resolver is appointed by the admin directly
+    if !dispute.appealed {
+        require_role(e, Role::Resolver, &resolver);
     }
 
-    // 5. Execute resolution by calling the core escrow logic
-    if release_to_beneficiary {
-        // Triggers the standard release logic from escrow.rs
-        release_escrow(e, dispute.escrow_id);
-        dispute.status = DisputeStatus::ResolvedForBeneficiary;
-    } else {
-        // Triggers the standard refund logic from escrow.rs
-        refund_escrow(e, dispute.escrow_id);
-        dispute.status = DisputeStatus::ResolvedForDepositor;
+    let amount = get_escrow(e, dispute.escrow_id).amount;
+    record_resolution(e, &resolver, amount, &status);
+    apply_decision(e, &resolver, dispute, status, burn_bps, beneficiary_bps);
+}
+
+/// Records a validated decision, settles it unless it can still be
+/// appealed, and emits the resolution event.
+fn apply_decision(
+    e: &Env,
+    resolver: &Address,
+    mut dispute: DisputeRecord,
+    status: DisputeStatus,
+    burn_bps: u32,
+    beneficiary_bps: u32,
+) {
+    let dispute_id = dispute.id;
+
+    // 5. Record the decision
+    dispute.status = status.clone();
+    dispute.resolved_ledger = e.ledger().sequence();
+    dispute.burn_bps = burn_bps;
+    dispute.beneficiary_bps = beneficiary_bps;
+    let escrow = get_escrow(e, dispute.escrow_id);
+
+    // 6. Settle now unless the decision can still be appealed
+    if dispute.appealed || !appeals_enabled(e) {
+        settle_dispute(e, &mut dispute);
     }
 
-    // 6. Persist the updated dispute status
+    // 7. Persist the updated dispute status
     e.storage().persistent().set(&DataKey::Dispute(dispute_id), &dispute);
 
-    // 7. Emit Observability Event
+    // 8. Emit Observability Event: (resolver, outcome, winner, amount), or
+    // (resolver, beneficiary_bps, amount) for a split
+    let winner = match status {
+        DisputeStatus::ResolvedForBeneficiary => escrow.beneficiary,
+        DisputeStatus::ResolvedForDepositor => escrow.depositor,
+        _ => {
+            e.events().publish(
+                (Symbol::new(e, "dispute"), Symbol::new(e, "resolved_split"), dispute_id),
+                (resolver.clone(), beneficiary_bps, escrow.amount)
+            );
+            return;
+        }
+    };
     e.events().publish(
         (Symbol::new(e, "dispute"), Symbol::new(e, "resolved"), dispute_id),
-        release_to_beneficiary
+        (resolver.clone(), dispute.status, winner, escrow.amount)
     );
 }
 
+/// Records a panel arbiter's vote on an open panel dispute. Each arbiter
+/// votes once and must still hold the `Resolver` role, except on the
+/// admin-appointed appeal panel.
+pub fn cast_panel_vote(e: &Env, arbiter: Address, dispute_id: u32, release_to_beneficiary: bool) {
+    arbiter.require_auth();
+
+    let dispute = get_open_panel_dispute(e, dispute_id);
+    if !dispute.panel.contains(&arbiter) {
+        panic_with_error!(e, Error::Unauthorized);
+    }
+    if !dispute.appealed {
+        require_role(e, Role::Resolver, &arbiter);
+    }
+
+    let key = DisputeKey::PanelVote(dispute_id, arbiter.clone());
+    if e.storage().persistent().has(&key) {
+        panic_with_error!(e, FeatureError::AlreadyVoted);
+    }
+    e.storage().persistent().set(&key, &release_to_beneficiary);
+
+    e.events().publish(
+        (Symbol::new(e, "dispute"), Symbol::new(e, "panel_vote"), dispute_id),
+        (arbiter, release_to_beneficiary)
+    );
+}
+
+/// Votes cast so far on a panel dispute, as
+/// `(for_beneficiary, for_depositor)`.
+pub fn panel_tally(e: &Env, dispute_id: u32) -> (u32, u32) {
+    let dispute = get_dispute(e, dispute_id);
+    let mut for_beneficiary = 0;
+    let mut for_depositor = 0;
+    for arbiter in dispute.panel.iter() {
+        match read_panel_vote(e, dispute_id, &arbiter) {
+            Some(true) => for_beneficiary += 1,
+            Some(false) => for_depositor += 1,
+            None => {}
+        }
+    }
+    (for_beneficiary, for_depositor)
+}
+
+/// Decides a panel dispute once a majority of its arbiters agree.
+/// Anyone can call this. Each arbiter in the majority is credited in
+/// their resolver stats.
+pub fn execute_panel_decision(e: &Env, dispute_id: u32) {
+    let dispute = get_open_panel_dispute(e, dispute_id);
+
+    let majority = dispute.panel.len() / 2 + 1;
+    let (for_beneficiary, for_depositor) = panel_tally(e, dispute_id);
+    let release_to_beneficiary = if for_beneficiary >= majority {
+        true
+    } else if for_depositor >= majority {
+        false
+    } else {
+        panic_with_error!(e, FeatureError::NoMajority);
+    };
+    let status = if release_to_beneficiary {
+        DisputeStatus::ResolvedForBeneficiary
+    } else {
+        DisputeStatus::ResolvedForDepositor
+    };
+
+    let amount = get_escrow(e, dispute.escrow_id).amount;
+    for arbiter in dispute.panel.iter() {
+        if read_panel_vote(e, dispute_id, &arbiter) == Some(release_to_beneficiary) {
+            record_resolution(e, &arbiter, amount, &status);
+        }
+    }
+    apply_decision(e, &e.current_contract_address(), dispute, status, 0, 0);
+}
+
+fn read_panel_vote(e: &Env, dispute_id: u32, arbiter: &Address) -> Option<bool> {
+    e.storage()
+        .persistent()
+        .get(&DisputeKey::PanelVote(dispute_id, arbiter.clone()))
+}
+
+fn get_open_panel_dispute(e: &Env, dispute_id: u32) -> DisputeRecord {
+    let dispute = get_dispute(e, dispute_id);
+    if dispute.status != DisputeStatus::Open {
+        panic_with_error!(e, FeatureError::AlreadyResolved);
+    }
+    if dispute.panel.is_empty() {
+        panic_with_error!(e, FeatureError::InvalidState);
+    }
+    dispute
+}
+
+/// Escalates a resolved dispute to the appeal panel if one is set, or else
+/// to the appeal resolver. Only the losing party may appeal, once, within
+/// `APPEAL_WINDOW_LEDGERS` of the resolution. After a split ruling the
+/// claimant is the one who may appeal.
+pub fn appeal_dispute(e: &Env, dispute_id: u32) {
+    let mut dispute = get_dispute(e, dispute_id);
+    let escrow = get_escrow(e, dispute.escrow_id);
+
+    // 1. Authorization: Only the losing party can appeal
+    let appellant = match dispute.status {
+        DisputeStatus::Open => panic_with_error!(e, FeatureError::InvalidState),
+        DisputeStatus::ResolvedForBeneficiary => escrow.depositor,
+        DisputeStatus::ResolvedForDepositor => escrow.beneficiary,
+        DisputeStatus::ResolvedSplit => dispute.claimant.clone(),
+    };
+    appellant.require_auth();
+
+    // 2. Validation: One appeal, inside the window, before settlement
+    if dispute.appealed {
+        panic_with_error!(e, FeatureError::AlreadyAppealed);
+    }
+    if dispute.settled {
+        panic_with_error!(e, FeatureError::InvalidState);
+    }
+    if e.ledger().sequence() >= dispute.resolved_ledger + APPEAL_WINDOW_LEDGERS {
+        panic_with_error!(e, FeatureError::AppealWindowClosed);
+    }
+    let appeal_panel = read_appeal_panel(e);
+    let appeal_resolver = if appeal_panel.is_empty() {
+        read_appeal_resolver(e).unwrap_or_else(|| panic_with_error!(e, FeatureError::NoAppealResolver))
+    } else {
+        e.current_contract_address()
+    };
+
+    // 3. Escalate and reopen, discarding any first-round panel votes
+    for arbiter in dispute.panel.iter() {
+        e.storage()
+            .persistent()
+            .remove(&DisputeKey::PanelVote(dispute_id, arbiter));
+    }
+    dispute.panel = appeal_panel;
+    dispute.resolver = appeal_resolver.clone();
+    dispute.status = DisputeStatus::Open;
+    dispute.appealed = true;
+    e.storage().persistent().set(&DataKey::Dispute(dispute_id), &dispute);
+
+    e.events().publish(
+        (Symbol::new(e, "dispute"), Symbol::new(e, "appealed"), dispute_id),
+        (appellant, appeal_resolver)
+    );
+}
+
+/// Settles a resolved dispute once its appeal window has closed.
+/// Anyone can call this.
+pub fn finalize_dispute(e: &Env, dispute_id: u32) {
+    let mut dispute = get_dispute(e, dispute_id);
+
+    if dispute.status == DisputeStatus::Open {
+        panic_with_error!(e, FeatureError::InvalidState);
+    }
+    if dispute.settled {
+        panic_with_error!(e, FeatureError::InvalidState);
+    }
+    if e.ledger().sequence() < dispute.resolved_ledger + APPEAL_WINDOW_LEDGERS {
+        panic_with_error!(e, FeatureError::AppealWindowOpen);
+    }
+
+    settle_dispute(e, &mut dispute);
+    e.storage().persistent().set(&DataKey::Dispute(dispute_id), &dispute);
+}
+
+fn record_resolution(e: &Env, resolver: &Address, amount: i128, status: &DisputeStatus) {
+    let mut stats = resolver_stats(e, resolver);
+    stats.resolved += 1;
+    match status {
+        DisputeStatus::ResolvedForBeneficiary => stats.for_beneficiary += 1,
+        DisputeStatus::ResolvedForDepositor => stats.for_depositor += 1,
+        _ => {}
+    }
+    stats.total_value += amount;
+    e.storage()
+        .persistent()
+        .set(&DataKey::ResolverStats(resolver.clone()), &stats);
+}
+
+/// Decision counts for a resolver; all zero if it has never resolved one.
+pub fn resolver_stats(e: &Env, resolver: &Address) -> ResolverStats {
+    e.storage()
+        .persistent()
+        .get(&DataKey::ResolverStats(resolver.clone()))
+        .unwrap_or_default()
+}
+
+/// Moves the escrowed funds according to the recorded decision.
+fn settle_dispute(e: &Env, dispute: &mut DisputeRecord) {
+    if dispute.burn_bps > 0 {
+        let amount = get_escrow(e, dispute.escrow_id).amount;
+        burn_escrow_portion(e, dispute.escrow_id, amount * dispute.burn_bps as i128 / 10000);
+    }
+
+    match dispute.status {
+        // Triggers the standard release logic from escrow.rs, even past expiry
+        DisputeStatus::ResolvedForBeneficiary => release_to_beneficiary(e, dispute.escrow_id),
+        // Returns funds even inside a binding escrow's active window
+        DisputeStatus::ResolvedForDepositor => {
+            return_to_depositor(e, dispute.escrow_id, "refunded")
+        }
+        DisputeStatus::ResolvedSplit => {
+            split_to_parties(e, dispute.escrow_id, dispute.beneficiary_bps)
+        }
+        DisputeStatus::Open => panic_with_error!(e, FeatureError::InvalidState),
+    }
+    settle_bond(e, dispute);
+    dispute.settled = true;
+    e.storage().persistent().remove(&DisputeKey::EscrowDispute(dispute.escrow_id));
+}
+
+/// Returns the bond to a claimant who won, or pays it to the counterparty.
+/// A split ruling returns it, as neither side lost outright.
+fn settle_bond(e: &Env, dispute: &DisputeRecord) {
+    if dispute.bond == 0 {
+        return;
+    }
+    // Either way the bond goes to the winning party
+    let escrow = get_escrow(e, dispute.escrow_id);
+    let winner = match dispute.status {
+        DisputeStatus::ResolvedForDepositor => escrow.depositor,
+        DisputeStatus::ResolvedSplit => dispute.claimant.clone(),
+        _ => escrow.beneficiary,
+    };
+    let action = if dispute.claimant == winner { "bond_returned" } else { "bond_forfeited" };
+
+    spend_balance(e, e.current_contract_address(), dispute.bond);
+    receive_balance(e, winner.clone(), dispute.bond);
+    write_held_bonds(e, held_bonds(e) - dispute.bond);
+    e.events().publish(
+        (Symbol::new(e, "dispute"), Symbol::new(e, action), dispute.id),
+        (winner, dispute.bond)
+    );
+}
+
+/// Attaches the hash of an off-chain document to an unsettled dispute.
+/// Only the escrow's depositor or beneficiary may submit, each up to
+/// `MAX_EVIDENCE_PER_PARTY` times.
+pub fn submit_evidence(e: &Env, dispute_id: u32, submitter: Address, evidence_hash: BytesN<32>) {
+    submitter.require_auth();
+
+    let dispute = get_dispute(e, dispute_id);
+    if dispute.settled {
+        panic_with_error!(e, FeatureError::InvalidState);
+    }
+    let escrow = get_escrow(e, dispute.escrow_id);
+    if submitter != escrow.depositor && submitter != escrow.beneficiary {
+        panic_with_error!(e, Error::Unauthorized);
+    }
+
+    let mut evidence = get_evidence_with_meta(e, dispute_id);
+    let submitted = evidence.iter().filter(|(party, _, _)| *party == submitter).count();
+    if submitted as u32 >= MAX_EVIDENCE_PER_PARTY {
+        panic_with_error!(e, FeatureError::EvidenceLimit);
+    }
+    evidence.push_back((submitter.clone(), evidence_hash.clone(), e.ledger().sequence()));
+    e.storage().persistent().set(&DataKey::Evidence(dispute_id), &evidence);
+
+    e.events().publish(
+        (Symbol::new(e, "dispute"), Symbol::new(e, "evidence"), dispute_id),
+        (submitter, evidence_hash)
+    );
+}
+
+/// Evidence for a dispute in submission order, as
+/// `(submitter, evidence_hash, ledger)` tuples.
+pub fn get_evidence_with_meta(e: &Env, dispute_id: u32) -> Vec<(Address, BytesN<32>, u32)> {
+    e.storage()
+        .persistent()
+        .get(&DataKey::Evidence(dispute_id))
+        .unwrap_or(Vec::new(e))
+}
+
+/// Bond a claimant must lock to open a dispute; 0 if none is set.
+pub fn read_dispute_bond(e: &Env) -> i128 {
+    e.storage().instance().get(&DisputeKey::DisputeBond).unwrap_or(0)
+}
+
+/// Stores the dispute bond. Panics on a negative amount.
+/// Caller is responsible for admin authorization (see `check_admin`).
+pub fn write_dispute_bond(e: &Env, bond: i128) {
+    if bond < 0 {
+        panic_with_error!(e, Error::InvalidAmount);
+    }
+    e.storage().instance().set(&DisputeKey::DisputeBond, &bond);
+}
+
+/// Sum of the bonds still locked by unsettled disputes.
+pub fn held_bonds(e: &Env) -> i128 {
+    e.storage().instance().get(&DisputeKey::HeldBonds).unwrap_or(0)
+}
+
+fn write_held_bonds(e: &Env, amount: i128) {
+    e.storage().instance().set(&DisputeKey::HeldBonds, &amount);
+}
+
+/// Senior panel that appealed disputes escalate to; empty if none is set.
+pub fn read_appeal_panel(e: &Env) -> Vec<Address> {
+    e.storage()
+        .instance()
+        .get(&DisputeKey::AppealPanel)
+        .unwrap_or(Vec::new(e))
+}
+
+/// Stores the appeal panel. An empty panel removes it, so appeals go to
+/// the appeal resolver again.
+/// Caller is responsible for admin authorization (see `check_admin`).
+pub fn write_appeal_panel(e: &Env, panel: &Vec<Address>) {
+    if panel.is_empty() {
+        e.storage().instance().remove(&DisputeKey::AppealPanel);
+        return;
+    }
+    validate_panel(panel);
+    e.storage().instance().set(&DisputeKey::AppealPanel, panel);
+}
+
+/// Whether resolutions can be appealed, so they must wait out the window.
+fn appeals_enabled(e: &Env) -> bool {
+    read_appeal_resolver(e).is_some() || !read_appeal_panel(e).is_empty()
+}
+
+pub fn read_appeal_resolver(e: &Env) -> Option<Address> {
+    e.storage().instance().get(&DataKey::AppealResolver)
+}
+
+pub fn write_appeal_resolver(e: &Env, resolver: &Address) {
+    e.storage().instance().set(&DataKey::AppealResolver, resolver);
+}
+
+/// Whether an unsettled dispute holds the escrow. While it does, only the
+/// dispute's ruling can move the escrowed funds.
+pub fn is_escrow_disputed(e: &Env, escrow_id: u32) -> bool {
+    e.storage().persistent().has(&DisputeKey::EscrowDispute(escrow_id))
+}
+
 /// Helper to read a dispute record
 pub fn get_dispute(e: &Env, dispute_id: u32) -> DisputeRecord {
     e.storage()
         .persistent()
         .get(&DataKey::Dispute(dispute_id))
-        .expect("Dispute not found")
-}
\ No newline at end of file
+        .unwrap_or_else(|| panic_with_error!(e, FeatureError::DisputeNotFound))
+}
@@ -0,0 +1,571 @@
+#[cfg(test)]
+mod dispute_tests {
+    use crate::dispute::{DisputeStatus, APPEAL_WINDOW_LEDGERS, MAX_EVIDENCE_PER_PARTY};
+    use crate::error::FeatureError;
+    use crate::roles::Role;
+    use crate::splitter::SplitRecipient;
+    use crate::{VeritixToken, VeritixTokenClient};
+    use soroban_sdk::{
+        testutils::{Address as _, Events, Ledger},
+        vec, Address, BytesN, Env, IntoVal, String, Symbol, Val,
+    };
+
+    struct Setup<'a> {
+        client: VeritixTokenClient<'a>,
+        admin: Address,
+        depositor: Address,
+        beneficiary: Address,
+        resolver: Address,
+        escrow_id: u32,
+    }
+
+    fn setup_test(e: &Env) -> Setup<'_> {
+        e.mock_all_auths();
+        // Keep entries live across appeal-window-sized ledger jumps
+        e.ledger().with_mut(|li| {
+            li.min_persistent_entry_ttl = 2 * APPEAL_WINDOW_LEDGERS;
+            li.min_temp_entry_ttl = 2 * APPEAL_WINDOW_LEDGERS;
+        });
+
+        let contract_id = e.register_contract(None, VeritixToken);
+        let client = VeritixTokenClient::new(e, &contract_id);
+
+        let admin = Address::generate(e);
+        let depositor = Address::generate(e);
+        let beneficiary = Address::generate(e);
+        let resolver = Address::generate(e);
+
+        client.initialize(
+            &admin,
+            &String::from_str(e, "Veritix"),
+            &String::from_str(e, "VTX"),
+            &7u32,
+        );
+        client.mint(&admin, &depositor, &10_000i128);
+        client.grant_role(&admin, &Role::Resolver, &resolver);
+        let escrow_id = client.create_escrow(&depositor, &beneficiary, &1000, &100_000, &0);
+
+        Setup { client, admin, depositor, beneficiary, resolver, escrow_id }
+    }
+
+    #[test]
+    fn test_open_dispute() {
+        let e = Env::default();
+        let s = setup_test(&e);
+
+        let id = s.client.open_dispute(&s.depositor, &s.escrow_id, &s.resolver);
+
+        let dispute = s.client.get_dispute(&id);
+        assert_eq!(dispute.escrow_id, s.escrow_id);
+        assert_eq!(dispute.claimant, s.depositor);
+        assert_eq!(dispute.status, DisputeStatus::Open);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #21)")]
+    fn test_open_dispute_by_outsider_panics() {
+        let e = Env::default();
+        let s = setup_test(&e);
+
+        s.client.open_dispute(&Address::generate(&e), &s.escrow_id, &s.resolver);
+    }
+
+    #[test]
+    fn test_resolve_without_appeal_resolver_settles_immediately() {
+        let e = Env::default();
+        let s = setup_test(&e);
+        let id = s.client.open_dispute(&s.depositor, &s.escrow_id, &s.resolver);
+
+        s.client.resolve_dispute(&s.resolver, &id, &true);
+
+        assert!(s.client.get_dispute(&id).settled);
+        assert_eq!(s.client.balance(&s.beneficiary), 1000);
+    }
+
+    #[test]
+    fn test_dispute_bond_returned_to_winner_and_forfeited_by_loser() {
+        let e = Env::default();
+        let s = setup_test(&e);
+        s.client.set_dispute_bond(&s.admin, &100);
+
+        // Depositor claims and wins: the bond comes back
+        let won = s.client.open_dispute(&s.depositor, &s.escrow_id, &s.resolver);
+        assert_eq!(s.client.get_dispute(&won).bond, 100);
+        assert_eq!(s.client.balance(&s.depositor), 8900);
+        s.client.resolve_dispute(&s.resolver, &won, &false);
+        assert_eq!(s.client.balance(&s.depositor), 10_000);
+
+        // Beneficiary claims and loses: the bond goes to the depositor
+        s.client.mint(&s.admin, &s.beneficiary, &100);
+        let escrow_id = s.client.create_escrow(&s.depositor, &s.beneficiary, &1000, &100_000, &0);
+        let lost = s.client.open_dispute(&s.beneficiary, &escrow_id, &s.resolver);
+        assert_eq!(s.client.balance(&s.beneficiary), 0);
+        s.client.resolve_dispute(&s.resolver, &lost, &false);
+        assert_eq!(s.client.balance(&s.depositor), 10_100);
+        assert_eq!(s.client.balance(&s.client.address), 0);
+    }
+
+    #[test]
+    fn test_split_resolution_pays_both_parties() {
+        let e = Env::default();
+        let s = setup_test(&e);
+        s.client.set_dispute_bond(&s.admin, &100);
+        let id = s.client.open_dispute(&s.depositor, &s.escrow_id, &s.resolver);
+
+        s.client.resolve_dispute_split(&s.resolver, &id, &3333);
+
+        let dispute = s.client.get_dispute(&id);
+        assert_eq!(dispute.status, DisputeStatus::ResolvedSplit);
+        assert!(dispute.settled);
+        // 333 to the beneficiary; the depositor gets 667 plus the bond back
+        assert_eq!(s.client.balance(&s.beneficiary), 333);
+        assert_eq!(s.client.balance(&s.depositor), 9667);
+        assert_eq!(s.client.balance(&s.client.address), 0);
+
+        let stats = s.client.resolver_stats(&s.resolver);
+        assert_eq!(stats.resolved, 1);
+        assert_eq!(stats.for_beneficiary + stats.for_depositor, 0);
+    }
+
+    fn panel_of_three(e: &Env, s: &Setup) -> soroban_sdk::Vec<Address> {
+        let panel = vec![e, Address::generate(e), Address::generate(e), Address::generate(e)];
+        for arbiter in panel.iter() {
+            s.client.grant_role(&s.admin, &Role::Resolver, &arbiter);
+        }
+        panel
+    }
+
+    #[test]
+    fn test_panel_majority_decides_dispute() {
+        let e = Env::default();
+        let s = setup_test(&e);
+        let panel = panel_of_three(&e, &s);
+        let id = s.client.open_panel_dispute(&s.depositor, &s.escrow_id, &panel);
+
+        s.client.cast_panel_vote(&panel.get(0).unwrap(), &id, &true);
+        assert!(s.client.try_execute_panel_decision(&id).is_err());
+
+        s.client.cast_panel_vote(&panel.get(1).unwrap(), &id, &false);
+        s.client.cast_panel_vote(&panel.get(2).unwrap(), &id, &true);
+        assert_eq!(s.client.panel_tally(&id), (2, 1));
+        s.client.execute_panel_decision(&id);
+
+        let dispute = s.client.get_dispute(&id);
+        assert_eq!(dispute.status, DisputeStatus::ResolvedForBeneficiary);
+        assert!(dispute.settled);
+        assert_eq!(s.client.balance(&s.beneficiary), 1000);
+        assert_eq!(s.client.resolver_stats(&panel.get(0).unwrap()).resolved, 1);
+        assert_eq!(s.client.resolver_stats(&panel.get(1).unwrap()).resolved, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #124)")]
+    fn test_panel_member_cannot_resolve_alone() {
+        let e = Env::default();
+        let s = setup_test(&e);
+        let panel = panel_of_three(&e, &s);
+        let id = s.client.open_panel_dispute(&s.depositor, &s.escrow_id, &panel);
+
+        s.client.resolve_dispute(&panel.get(0).unwrap(), &id, &true);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #125)")]
+    fn test_panel_vote_once() {
+        let e = Env::default();
+        let s = setup_test(&e);
+        let panel = panel_of_three(&e, &s);
+        let id = s.client.open_panel_dispute(&s.depositor, &s.escrow_id, &panel);
+
+        s.client.cast_panel_vote(&panel.get(0).unwrap(), &id, &true);
+        s.client.cast_panel_vote(&panel.get(0).unwrap(), &id, &false);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #123)")]
+    fn test_double_resolve_panics() {
+        let e = Env::default();
+        let s = setup_test(&e);
+        let id = s.client.open_dispute(&s.depositor, &s.escrow_id, &s.resolver);
+
+        s.client.resolve_dispute(&s.resolver, &id, &true);
+        s.client.resolve_dispute(&s.resolver, &id, &false);
+    }
+
+    #[test]
+    fn test_appeal_and_re_resolve_under_tier_two() {
+        let e = Env::default();
+        let s = setup_test(&e);
+        let senior = Address::generate(&e);
+        s.client.set_appeal_resolver(&s.admin, &senior);
+        let id = s.client.open_dispute(&s.beneficiary, &s.escrow_id, &s.resolver);
+
+        // Tier one rules for the depositor; funds stay locked during the window
+        s.client.resolve_dispute(&s.resolver, &id, &false);
+        assert!(!s.client.get_dispute(&id).settled);
+        assert_eq!(s.client.balance(&s.client.address), 1000);
+
+        // The beneficiary lost and appeals within the window
+        e.ledger().set_sequence_number(APPEAL_WINDOW_LEDGERS - 1);
+        s.client.appeal_dispute(&id);
+        let dispute = s.client.get_dispute(&id);
+        assert_eq!(dispute.status, DisputeStatus::Open);
+        assert_eq!(dispute.resolver, senior);
+        assert!(dispute.appealed);
+
+        // Tier two overturns the decision and it settles at once
+        s.client.resolve_dispute(&senior, &id, &true);
+        let dispute = s.client.get_dispute(&id);
+        assert_eq!(dispute.status, DisputeStatus::ResolvedForBeneficiary);
+        assert!(dispute.settled);
+        assert_eq!(s.client.balance(&s.beneficiary), 1000);
+    }
+
+    #[test]
+    fn test_appeal_escalates_to_senior_panel() {
+        let e = Env::default();
+        let s = setup_test(&e);
+        let seniors = vec![&e, Address::generate(&e), Address::generate(&e), Address::generate(&e)];
+        s.client.set_appeal_panel(&s.admin, &seniors);
+        let id = s.client.open_dispute(&s.beneficiary, &s.escrow_id, &s.resolver);
+
+        // The ruling waits out the appeal window, then the loser appeals
+        s.client.resolve_dispute(&s.resolver, &id, &false);
+        assert!(!s.client.get_dispute(&id).settled);
+        s.client.appeal_dispute(&id);
+        let dispute = s.client.get_dispute(&id);
+        assert_eq!(dispute.panel, seniors);
+        assert_eq!(dispute.resolver, s.client.address);
+
+        // The senior panel needs no Resolver role and settles at once
+        s.client.cast_panel_vote(&seniors.get(0).unwrap(), &id, &true);
+        s.client.cast_panel_vote(&seniors.get(2).unwrap(), &id, &true);
+        s.client.execute_panel_decision(&id);
+        let dispute = s.client.get_dispute(&id);
+        assert_eq!(dispute.status, DisputeStatus::ResolvedForBeneficiary);
+        assert!(dispute.settled);
+        assert_eq!(s.client.balance(&s.beneficiary), 1000);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #124)")]
+    fn test_original_resolver_cannot_decide_appeal() {
+        let e = Env::default();
+        let s = setup_test(&e);
+        s.client.set_appeal_resolver(&s.admin, &Address::generate(&e));
+        let id = s.client.open_dispute(&s.beneficiary, &s.escrow_id, &s.resolver);
+
+        s.client.resolve_dispute(&s.resolver, &id, &false);
+        s.client.appeal_dispute(&id);
+        s.client.resolve_dispute(&s.resolver, &id, &false);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #127)")]
+    fn test_second_appeal_panics() {
+        let e = Env::default();
+        let s = setup_test(&e);
+        let senior = Address::generate(&e);
+        s.client.set_appeal_resolver(&s.admin, &senior);
+        let id = s.client.open_dispute(&s.beneficiary, &s.escrow_id, &s.resolver);
+
+        s.client.resolve_dispute(&s.resolver, &id, &false);
+        s.client.appeal_dispute(&id);
+        s.client.resolve_dispute(&senior, &id, &false);
+        s.client.appeal_dispute(&id);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #128)")]
+    fn test_appeal_after_window_panics() {
+        let e = Env::default();
+        let s = setup_test(&e);
+        s.client.set_appeal_resolver(&s.admin, &Address::generate(&e));
+        let id = s.client.open_dispute(&s.beneficiary, &s.escrow_id, &s.resolver);
+
+        s.client.resolve_dispute(&s.resolver, &id, &false);
+        e.ledger().set_sequence_number(APPEAL_WINDOW_LEDGERS);
+        s.client.appeal_dispute(&id);
+    }
+
+    #[test]
+    fn test_finalize_after_window() {
+        let e = Env::default();
+        let s = setup_test(&e);
+        s.client.set_appeal_resolver(&s.admin, &Address::generate(&e));
+        let id = s.client.open_dispute(&s.beneficiary, &s.escrow_id, &s.resolver);
+
+        s.client.resolve_dispute(&s.resolver, &id, &false);
+        e.ledger().set_sequence_number(APPEAL_WINDOW_LEDGERS);
+        s.client.finalize_dispute(&id);
+
+        assert!(s.client.get_dispute(&id).settled);
+        assert_eq!(s.client.balance(&s.depositor), 10_000);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #129)")]
+    fn test_finalize_during_window_panics() {
+        let e = Env::default();
+        let s = setup_test(&e);
+        s.client.set_appeal_resolver(&s.admin, &Address::generate(&e));
+        let id = s.client.open_dispute(&s.beneficiary, &s.escrow_id, &s.resolver);
+
+        s.client.resolve_dispute(&s.resolver, &id, &false);
+        s.client.finalize_dispute(&id);
+    }
+
+    #[test]
+    fn test_evidence_records_submitter_and_ledger() {
+        let e = Env::default();
+        let s = setup_test(&e);
+        let id = s.client.open_dispute(&s.depositor, &s.escrow_id, &s.resolver);
+        let receipt = BytesN::from_array(&e, &[1u8; 32]);
+        let delivery_photo = BytesN::from_array(&e, &[2u8; 32]);
+
+        e.ledger().set_sequence_number(10);
+        s.client.submit_evidence(&id, &s.depositor, &receipt);
+        e.ledger().set_sequence_number(25);
+        s.client.submit_evidence(&id, &s.beneficiary, &delivery_photo);
+
+        let evidence = s.client.get_evidence_with_meta(&id);
+        assert_eq!(evidence.len(), 2);
+        assert_eq!(evidence.get(0).unwrap(), (s.depositor.clone(), receipt, 10));
+        assert_eq!(evidence.get(1).unwrap(), (s.beneficiary.clone(), delivery_photo, 25));
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #21)")]
+    fn test_evidence_by_outsider_panics() {
+        let e = Env::default();
+        let s = setup_test(&e);
+        let id = s.client.open_dispute(&s.depositor, &s.escrow_id, &s.resolver);
+
+        s.client.submit_evidence(&id, &Address::generate(&e), &BytesN::from_array(&e, &[1u8; 32]));
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #131)")]
+    fn test_evidence_is_bounded_per_party() {
+        let e = Env::default();
+        let s = setup_test(&e);
+        let id = s.client.open_dispute(&s.depositor, &s.escrow_id, &s.resolver);
+
+        for i in 0..MAX_EVIDENCE_PER_PARTY {
+            s.client.submit_evidence(&id, &s.depositor, &BytesN::from_array(&e, &[i as u8; 32]));
+        }
+        // The other party's allowance is separate
+        s.client.submit_evidence(&id, &s.beneficiary, &BytesN::from_array(&e, &[0u8; 32]));
+        assert_eq!(s.client.get_evidence_with_meta(&id).len(), MAX_EVIDENCE_PER_PARTY + 1);
+
+        s.client.submit_evidence(&id, &s.depositor, &BytesN::from_array(&e, &[99u8; 32]));
+    }
+
+    #[test]
+    fn test_resolver_stats_aggregate() {
+        let e = Env::default();
+        let s = setup_test(&e);
+        let second_escrow = s.client.create_escrow(&s.depositor, &s.beneficiary, &500, &100_000, &0);
+
+        let first = s.client.open_dispute(&s.depositor, &s.escrow_id, &s.resolver);
+        let second = s.client.open_dispute(&s.beneficiary, &second_escrow, &s.resolver);
+        s.client.resolve_dispute(&s.resolver, &first, &true);
+        s.client.resolve_dispute(&s.resolver, &second, &false);
+
+        let stats = s.client.resolver_stats(&s.resolver);
+        assert_eq!(stats.resolved, 2);
+        assert_eq!(stats.for_beneficiary, 1);
+        assert_eq!(stats.for_depositor, 1);
+        assert_eq!(stats.total_value, 1500);
+
+        let other = Address::generate(&e);
+        assert_eq!(s.client.resolver_stats(&other).resolved, 0);
+    }
+
+    fn resolved_event(
+        e: &Env,
+        s: &Setup,
+        dispute_id: u32,
+        outcome: DisputeStatus,
+        winner: &Address,
+    ) -> (Address, soroban_sdk::Vec<Val>, Val) {
+        (
+            s.client.address.clone(),
+            (Symbol::new(e, "dispute"), Symbol::new(e, "resolved"), dispute_id).into_val(e),
+            (s.resolver.clone(), outcome, winner.clone(), 1000i128).into_val(e),
+        )
+    }
+
+    #[test]
+    fn test_resolved_event_for_beneficiary() {
+        let e = Env::default();
+        let s = setup_test(&e);
+        let id = s.client.open_dispute(&s.depositor, &s.escrow_id, &s.resolver);
+
+        s.client.resolve_dispute(&s.resolver, &id, &true);
+
+        let events = e.events().all();
+        assert_eq!(
+            events.slice(events.len() - 1..),
+            vec![&e, resolved_event(&e, &s, id, DisputeStatus::ResolvedForBeneficiary, &s.beneficiary)]
+        );
+    }
+
+    #[test]
+    fn test_resolved_event_for_depositor() {
+        let e = Env::default();
+        let s = setup_test(&e);
+        let id = s.client.open_dispute(&s.beneficiary, &s.escrow_id, &s.resolver);
+
+        s.client.resolve_dispute(&s.resolver, &id, &false);
+
+        let events = e.events().all();
+        assert_eq!(
+            events.slice(events.len() - 1..),
+            vec![&e, resolved_event(&e, &s, id, DisputeStatus::ResolvedForDepositor, &s.depositor)]
+        );
+    }
+
+    #[test]
+    fn test_resolve_with_burn_awards_remainder() {
+        let e = Env::default();
+        let s = setup_test(&e);
+        let id = s.client.open_dispute(&s.depositor, &s.escrow_id, &s.resolver);
+
+        s.client.resolve_dispute_with_burn(&s.resolver, &id, &true, &1000);
+
+        assert_eq!(s.client.total_supply(), 10_000 - 100);
+        assert_eq!(s.client.balance(&s.beneficiary), 900);
+        assert_eq!(s.client.balance(&s.depositor), 9000);
+        assert_eq!(s.client.balance(&s.client.address), 0);
+        assert!(s.client.get_escrow(&s.escrow_id).released);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #52)")]
+    fn test_resolve_with_burn_invalid_bps_panics() {
+        let e = Env::default();
+        let s = setup_test(&e);
+        let id = s.client.open_dispute(&s.depositor, &s.escrow_id, &s.resolver);
+
+        s.client.resolve_dispute_with_burn(&s.resolver, &id, &false, &10_001);
+    }
+
+    #[test]
+    fn test_resolver_needs_role() {
+        let e = Env::default();
+        let s = setup_test(&e);
+        let outsider = Address::generate(&e);
+
+        assert!(s.client.try_open_dispute(&s.depositor, &s.escrow_id, &outsider).is_err());
+
+        // Revoking the role after the dispute opened blocks the decision
+        let id = s.client.open_dispute(&s.depositor, &s.escrow_id, &s.resolver);
+        s.client.revoke_role(&s.admin, &Role::Resolver, &s.resolver);
+        assert!(!s.client.has_role(&Role::Resolver, &s.resolver));
+        assert!(s.client.try_resolve_dispute(&s.resolver, &id, &true).is_err());
+    }
+
+    #[test]
+    fn test_open_dispute_locks_escrow() {
+        let e = Env::default();
+        let s = setup_test(&e);
+        let arbiter = Address::generate(&e);
+        let escrow_id =
+            s.client.create_escrow_with_arbiter(&s.depositor, &s.beneficiary, &arbiter, &1000, &10_000, &0);
+        s.client.open_dispute(&s.beneficiary, &escrow_id, &s.resolver);
+        let disputed = Err(Ok(FeatureError::EscrowDisputed.into()));
+
+        assert_eq!(s.client.try_release_escrow(&s.depositor, &escrow_id), disputed);
+        assert_eq!(s.client.try_refund_escrow(&arbiter, &escrow_id), disputed);
+        assert_eq!(s.client.try_refund_escrow_partial(&escrow_id, &100), disputed);
+        assert_eq!(s.client.try_cancel_escrow(&escrow_id), disputed);
+        assert_eq!(
+            s.client.try_release_escrow_split(
+                &escrow_id,
+                &vec![&e, SplitRecipient { address: s.beneficiary.clone(), share_bps: 10000 }],
+            ),
+            disputed
+        );
+        assert_eq!(s.client.try_settle_external(&escrow_id, &Address::generate(&e)), disputed);
+        assert_eq!(
+            s.client.try_open_dispute(&s.depositor, &escrow_id, &s.resolver),
+            Err(Ok(FeatureError::EscrowDisputed.into()))
+        );
+        e.ledger().set_sequence_number(10_000);
+        assert_eq!(s.client.try_claim_expired(&escrow_id), disputed);
+        assert_eq!(s.client.balance(&s.client.address), 2000);
+    }
+
+    #[test]
+    fn test_refund_blocked_during_appeal_window() {
+        let e = Env::default();
+        let s = setup_test(&e);
+        s.client.set_appeal_resolver(&s.admin, &Address::generate(&e));
+        let id = s.client.open_dispute(&s.depositor, &s.escrow_id, &s.resolver);
+
+        // The depositor loses, then tries to pull the funds before finalize
+        s.client.resolve_dispute(&s.resolver, &id, &true);
+        assert_eq!(
+            s.client.try_refund_escrow(&s.depositor, &s.escrow_id),
+            Err(Ok(FeatureError::EscrowDisputed.into()))
+        );
+
+        e.ledger().set_sequence_number(APPEAL_WINDOW_LEDGERS);
+        s.client.finalize_dispute(&id);
+        assert_eq!(s.client.balance(&s.beneficiary), 1000);
+        assert_eq!(s.client.balance(&s.client.address), 0);
+
+        // Settling releases the lock
+        assert_eq!(
+            s.client.try_refund_escrow(&s.depositor, &s.escrow_id),
+            Err(Ok(crate::error::Error::AlreadySettled.into()))
+        );
+    }
+
+    #[test]
+    fn test_refund_blocked_during_panel_appeal() {
+        let e = Env::default();
+        let s = setup_test(&e);
+        let seniors = vec![&e, Address::generate(&e), Address::generate(&e), Address::generate(&e)];
+        s.client.set_appeal_panel(&s.admin, &seniors);
+        let id = s.client.open_dispute(&s.depositor, &s.escrow_id, &s.resolver);
+
+        // The depositor loses, appeals, and tries to refund while the panel sits
+        s.client.resolve_dispute(&s.resolver, &id, &true);
+        s.client.appeal_dispute(&id);
+        assert_eq!(
+            s.client.try_refund_escrow(&s.depositor, &s.escrow_id),
+            Err(Ok(FeatureError::EscrowDisputed.into()))
+        );
+
+        s.client.cast_panel_vote(&seniors.get(0).unwrap(), &id, &true);
+        s.client.cast_panel_vote(&seniors.get(1).unwrap(), &id, &true);
+        s.client.execute_panel_decision(&id);
+        assert!(s.client.get_dispute(&id).settled);
+        assert_eq!(s.client.balance(&s.beneficiary), 1000);
+        assert_eq!(s.client.balance(&s.client.address), 0);
+    }
+
+    #[test]
+    fn test_bond_settles_after_blocked_refund() {
+        let e = Env::default();
+        let s = setup_test(&e);
+        s.client.set_dispute_bond(&s.admin, &100);
+        s.client.set_appeal_resolver(&s.admin, &Address::generate(&e));
+        let id = s.client.open_dispute(&s.depositor, &s.escrow_id, &s.resolver);
+
+        // The losing claimant cannot refund, so the bond is never stranded
+        s.client.resolve_dispute(&s.resolver, &id, &true);
+        assert_eq!(
+            s.client.try_refund_escrow(&s.depositor, &s.escrow_id),
+            Err(Ok(FeatureError::EscrowDisputed.into()))
+        );
+
+        e.ledger().set_sequence_number(APPEAL_WINDOW_LEDGERS);
+        s.client.finalize_dispute(&id);
+        assert_eq!(s.client.balance(&s.depositor), 8900);
+        assert_eq!(s.client.balance(&s.beneficiary), 1100);
+        assert_eq!(s.client.balance(&s.client.address), 0);
+    }
+}
@@ -0,0 +1,141 @@
+use soroban_sdk::contracterror;
+
+/// Typed failures raised by the core token and payment modules.
+/// Clients can match on these through the `try_` client methods instead of
+/// parsing panic strings.
+///
+/// `contracterror` caps the enum at 50 variants and it is full; further
+/// failures go in `FeatureError`.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    // Balances and supply
+    InsufficientBalance = 1,
+    BalanceOverflow = 2,
+    SupplyOverflow = 3,
+    NegativeSupply = 4,
+    RecipientFrozen = 5,
+
+    // Allowances
+    ExpirationInPast = 10,
+    AllowanceNotFound = 11,
+    AllowanceLive = 12,
+    AllowanceExpired = 13,
+    InsufficientAllowance = 14,
+    AllowanceOverflow = 15,
+
+    // Shared
+    InvalidAmount = 20,
+    Unauthorized = 21,
+
+    // Escrow
+    EscrowNotFound = 30,
+    AlreadySettled = 31,
+    TimelockActive = 32,
+    TimelockElapsed = 33,
+    EscrowActive = 34,
+    InvalidBeneficiary = 35,
+    InvalidOffsets = 36,
+    InvalidToken = 37,
+    LengthMismatch = 38,
+    NothingToClaim = 39,
+    RecipientNotFound = 40,
+    AlreadyPaid = 41,
+    MilestoneNotFound = 42,
+    MilestoneNotApproved = 43,
+    InvalidMilestones = 44,
+    EscrowExpired = 45,
+    EscrowNotExpired = 46,
+    InvalidArbiter = 47,
+
+    // Splitter
+    SplitNotFound = 50,
+    TooManyRecipients = 51,
+    InvalidBps = 52,
+    AlreadyDistributed = 53,
+    NothingToDistribute = 54,
+    ClaimsNotOpen = 55,
+    FixedSharesExceedTotal = 56,
+
+    // Contract-wide
+    ContractPaused = 60,
+    MissingRole = 61,
+
+    // Multisig admin
+    MultisigRequired = 70,
+    NotSigner = 71,
+    InvalidThreshold = 72,
+    ActionNotFound = 73,
+    AlreadyApproved = 74,
+    AlreadyExecuted = 75,
+    InsufficientApprovals = 76,
+
+    // Admin timelock
+    TimelockRequired = 80,
+    ActionNotReady = 81,
+    ActionCancelled = 82,
+}
+
+/// Typed failures for initialization, admin controls and the feature
+/// modules, added once `Error` reached the `contracterror` variant cap.
+/// Codes start at 100 so they never collide with `Error`.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum FeatureError {
+    // Initialization and admin
+    AlreadyInitialized = 100,
+    InvalidDecimals = 101,
+    InvalidMetadata = 102,
+    NoPendingAdmin = 103,
+    AllowanceChanged = 104,
+    AccountFrozen = 105,
+    Blacklisted = 106,
+    EmptyBatch = 107,
+    BatchTooLarge = 108,
+    InvalidExpiration = 109,
+
+    // Supply and limits
+    MintingDisabled = 110,
+    InvalidSupplyCap = 111,
+    SupplyCapExceeded = 112,
+    RateLimited = 113,
+    MintLimitExceeded = 114,
+    SweepExceedsSurplus = 115,
+    InvalidSnapshot = 116,
+    SettlementExceedsSurplus = 117,
+    TransferFeeTooHigh = 118,
+    InvalidRateLimit = 119,
+
+    // Disputes
+    DisputeNotFound = 120,
+    InvalidState = 121,
+    InvalidPanel = 122,
+    AlreadyResolved = 123,
+    UnauthorizedResolver = 124,
+    AlreadyVoted = 125,
+    NoMajority = 126,
+    AlreadyAppealed = 127,
+    AppealWindowClosed = 128,
+    AppealWindowOpen = 129,
+    NoAppealResolver = 130,
+    EvidenceLimit = 131,
+    EscrowDisputed = 132,
+
+    // Hashlock escrows
+    HashlockNotFound = 140,
+    InvalidTimeout = 141,
+    TimeoutReached = 142,
+    TimeoutActive = 143,
+    InvalidPreimage = 144,
+
+    // Recurring and scheduled transfers
+    RecurringNotFound = 150,
+    InvalidIterations = 151,
+    AllPaymentsCompleted = 152,
+    IntervalNotElapsed = 153,
+    ScheduledNotFound = 154,
+    InvalidSchedule = 155,
+    TooEarly = 156,
+}
@@ -1,8 +1,14 @@
-use crate::balance::{receive_balance, spend_balance};
-use crate::storage_types::DataKey;
-use soroban_sdk::{contracttype, Address, Env, Symbol};
+use crate::balance::{decrease_supply, lock_balance, receive_balance, spend_balance, unlock_balance};
+use crate::dispute::is_escrow_disputed;
+use crate::error::{Error, FeatureError};
+use crate::fee::take_escrow_fee;
+use crate::freeze::{require_can_send, require_not_blacklisted};
+use crate::pause::require_not_paused;
+use crate::storage_types::{DataKey, EscrowIndexKey};
+use crate::sweep;
+use soroban_sdk::{contracttype, panic_with_error, symbol_short, token, Address, Env, Symbol};
 
-use crate::splitter::SplitRecipient;
+use crate::splitter::{calculate_distribution, validate_bps, SplitRecipient};
 use crate::admin::read_admin; // Assuming read_admin returns the Admin Address
 use soroban_sdk::Vec;
 
@@ -17,6 +23,16 @@ pub struct EscrowRecord {
     pub refunded: bool,
     pub expiration_ledger: u32,
     pub release_after_ledger: u32,
+    /// When set, the depositor can only refund once `expiration_ledger` is
+    /// reached. Mutual cancellation via `cancel_escrow` is still possible.
+    pub binding: bool,
+    /// Set by the depositor (e.g. on confirming receipt) to allow release
+    /// before `release_after_ledger`.
+    pub early_release_approved: bool,
+    /// Third party who can release or refund at any time before expiry.
+    pub arbiter: Option<Address>,
+    /// SEP-41 token the escrow holds, or `None` for this token.
+    pub token: Option<Address>,
 }
 
 /// Creates a new escrow record and locks the funds in the contract.
@@ -27,17 +43,153 @@ pub fn create_escrow(
     amount: i128,
     expiration_ledger: u32,
     release_after_ledger: u32,
+) -> u32 {
+    lock_escrow(e, depositor, beneficiary, None, None, amount, expiration_ledger, release_after_ledger, false)
+}
+
+/// Like `create_escrow`, with an `arbiter` who can settle the escrow either
+/// way regardless of the timelock or binding window.
+pub fn create_escrow_with_arbiter(
+    e: &Env,
+    depositor: Address,
+    beneficiary: Address,
+    arbiter: Address,
+    amount: i128,
+    expiration_ledger: u32,
+    release_after_ledger: u32,
+) -> u32 {
+    if arbiter == depositor || arbiter == beneficiary {
+        panic_with_error!(e, Error::InvalidArbiter);
+    }
+    require_not_blacklisted(e, &arbiter);
+    lock_escrow(
+        e,
+        depositor,
+        beneficiary,
+        Some(arbiter),
+        None,
+        amount,
+        expiration_ledger,
+        release_after_ledger,
+        false,
+    )
+}
+
+/// Like `create_escrow`, but the timelock and expiration are offsets from
+/// the current ledger rather than absolute sequence numbers.
+pub fn create_escrow_ttl(
+    e: &Env,
+    depositor: Address,
+    beneficiary: Address,
+    amount: i128,
+    release_after_ledgers: u32,
+    expire_after_ledgers: u32,
+) -> u32 {
+    if release_after_ledgers > expire_after_ledgers {
+        panic_with_error!(e, Error::InvalidOffsets);
+    }
+    let now = e.ledger().sequence();
+    let offset = |ledgers: u32| {
+        now.checked_add(ledgers)
+            .unwrap_or_else(|| panic_with_error!(e, FeatureError::InvalidExpiration))
+    };
+    create_escrow(
+        e,
+        depositor,
+        beneficiary,
+        amount,
+        offset(expire_after_ledgers),
+        offset(release_after_ledgers),
+    )
+}
+
+/// Like `create_escrow`, but locks `amount` of the SEP-41 token at
+/// `token_address` (e.g. USDC) instead of this token. Settlement pays out in
+/// that token and no escrow fee is taken.
+pub fn create_token_escrow(
+    e: &Env,
+    depositor: Address,
+    beneficiary: Address,
+    token_address: Address,
+    amount: i128,
+    expiration_ledger: u32,
+    release_after_ledger: u32,
+) -> u32 {
+    if token_address == e.current_contract_address() {
+        panic_with_error!(e, Error::InvalidToken);
+    }
+    lock_escrow(
+        e,
+        depositor,
+        beneficiary,
+        None,
+        Some(token_address),
+        amount,
+        expiration_ledger,
+        release_after_ledger,
+        false,
+    )
+}
+
+/// Creates an escrow the depositor cannot refund before `expiration_ledger`.
+pub fn create_binding_escrow(
+    e: &Env,
+    depositor: Address,
+    beneficiary: Address,
+    amount: i128,
+    expiration_ledger: u32,
+    release_after_ledger: u32,
+) -> u32 {
+    lock_escrow(e, depositor, beneficiary, None, None, amount, expiration_ledger, release_after_ledger, true)
+}
+
+#[allow(clippy::too_many_arguments)]
+fn lock_escrow(
+    e: &Env,
+    depositor: Address,
+    beneficiary: Address,
+    arbiter: Option<Address>,
+    token: Option<Address>,
+    amount: i128,
+    expiration_ledger: u32,
+    release_after_ledger: u32,
+    binding: bool,
 ) -> u32 {
     depositor.require_auth();
+    require_not_paused(e);
+    if amount <= 0 {
+        panic_with_error!(e, Error::InvalidAmount);
+    }
+    if depositor == beneficiary {
+        panic_with_error!(e, Error::InvalidBeneficiary);
+    }
+    require_can_send(e, &depositor);
+    require_not_blacklisted(e, &beneficiary);
 
     // 1. Move funds from the depositor to the contract itself
-    spend_balance(e, depositor.clone(), amount);
-    receive_balance(e, e.current_contract_address(), amount);
+    match &token {
+        Some(token_address) => {
+            token::Client::new(e, token_address).transfer(
+                &depositor,
+                &e.current_contract_address(),
+                &amount,
+            );
+            sweep::hold_in_token(e, token_address, amount);
+        }
+        None => {
+            spend_balance(e, depositor.clone(), amount);
+            receive_balance(e, e.current_contract_address(), amount);
+            sweep::hold_native(e, amount);
+            lock_balance(e, depositor.clone(), amount);
+        }
+    }
 
     // 2. Increment and fetch the new Escrow ID
     let mut count: u32 = e.storage().instance().get(&DataKey::EscrowCount).unwrap_or(0);
     count += 1;
     e.storage().instance().set(&DataKey::EscrowCount, &count);
+    let active = active_escrow_count(e);
+    e.storage().instance().set(&DataKey::ActiveEscrowCount, &(active + 1));
 
     // 3. Store the record
     let record = EscrowRecord {
@@ -49,8 +201,14 @@ pub fn create_escrow(
         refunded: false,
         expiration_ledger,
         release_after_ledger,
+        binding,
+        early_release_approved: false,
+        arbiter,
+        token,
     };
     e.storage().persistent().set(&DataKey::Escrow(count), &record);
+    index_escrow(e, &depositor, count);
+    index_escrow(e, &beneficiary, count);
 
     // 4. Emit Event
     e.events().publish(
@@ -61,25 +219,54 @@ pub fn create_escrow(
     count
 }
 
-/// Releases the escrowed funds to the beneficiary.
-pub fn release_escrow(e: &Env, escrow_id: u32) {
+/// Releases the escrowed funds to the beneficiary. The beneficiary can
+/// release once the timelock has passed (or the depositor approved early
+/// release); the depositor and the arbiter can release at any time. Once the
+/// escrow has reached its `expiration_ledger` it can only be refunded.
+pub fn release_escrow(e: &Env, caller: Address, escrow_id: u32) {
+    caller.require_auth();
+    let escrow = get_escrow(e, escrow_id);
+    require_not_expired(e, &escrow);
+    require_not_disputed(e, escrow_id);
+
+    let waive_timelock = if caller == escrow.beneficiary {
+        false
+    } else if caller == escrow.depositor || escrow.arbiter == Some(caller) {
+        true
+    } else {
+        panic_with_error!(e, Error::Unauthorized);
+    };
+    pay_beneficiary(e, escrow_id, waive_timelock);
+}
+
+/// Settles the escrow to the beneficiary without the caller or expiration
+/// checks. Used directly by dispute rulings, which override expiry.
+pub(crate) fn release_to_beneficiary(e: &Env, escrow_id: u32) {
+    pay_beneficiary(e, escrow_id, false);
+}
+
+fn pay_beneficiary(e: &Env, escrow_id: u32, waive_timelock: bool) {
     let mut escrow = get_escrow(e, escrow_id);
 
     // State & Timelock Validation
-    if e.ledger().sequence() < escrow.release_after_ledger {
-        panic!("TimelockActive: Cannot release funds before the release_after_ledger");
+    let timelocked =
+        !escrow.early_release_approved && e.ledger().sequence() < escrow.release_after_ledger;
+    if timelocked && !waive_timelock {
+        panic_with_error!(e, Error::TimelockActive);
     }
     if escrow.released || escrow.refunded {
-        panic!("InvalidState: Escrow is already settled");
+        panic_with_error!(e, Error::AlreadySettled);
     }
 
     // Update state
     escrow.released = true;
+    decrement_active_escrows(e);
     e.storage().persistent().set(&DataKey::Escrow(escrow_id), &escrow);
 
-    // Move funds from contract to beneficiary
-    spend_balance(e, e.current_contract_address(), escrow.amount);
-    receive_balance(e, escrow.beneficiary.clone(), escrow.amount);
+    // Move funds from contract to beneficiary, less the escrow fee
+    withdraw_escrowed(e, &escrow, escrow.amount);
+    let fee = escrow_fee(e, &escrow, escrow.amount);
+    pay_from_escrow(e, &escrow, &escrow.beneficiary, escrow.amount - fee);
 
     // Emit Event
     e.events().publish(
@@ -88,36 +275,468 @@ pub fn release_escrow(e: &Env, escrow_id: u32) {
     );
 }
 
-/// Refunds the escrowed funds back to the depositor.
-pub fn refund_escrow(e: &Env, escrow_id: u32) {
+/// Releases the escrow across `recipients` by bps (dust to the last) instead
+/// of paying the stored beneficiary, for splits only known at settlement.
+/// Beneficiary-only, as it redirects the beneficiary's funds.
+pub fn release_escrow_split(e: &Env, escrow_id: u32, recipients: Vec<SplitRecipient>) {
+    let mut escrow = get_escrow(e, escrow_id);
+    escrow.beneficiary.require_auth();
+    validate_bps(&recipients);
+    require_not_expired(e, &escrow);
+    require_not_disputed(e, escrow_id);
+
+    // State & Timelock Validation
+    if !escrow.early_release_approved && e.ledger().sequence() < escrow.release_after_ledger {
+        panic_with_error!(e, Error::TimelockActive);
+    }
+    if escrow.released || escrow.refunded {
+        panic_with_error!(e, Error::AlreadySettled);
+    }
+
+    // Update state
+    escrow.released = true;
+    decrement_active_escrows(e);
+    e.storage().persistent().set(&DataKey::Escrow(escrow_id), &escrow);
+
+    // Move funds from contract to the recipients, less the escrow fee
+    withdraw_escrowed(e, &escrow, escrow.amount);
+    let fee = escrow_fee(e, &escrow, escrow.amount);
+    let amounts = calculate_distribution(e, escrow.amount - fee, &recipients);
+    for (recipient, amount) in recipients.iter().zip(amounts.iter()) {
+        pay_from_escrow(e, &escrow, &recipient.address, amount);
+    }
+
+    // Emit Event
+    e.events().publish(
+        (Symbol::new(e, "escrow"), Symbol::new(e, "released_split"), escrow_id),
+        recipients
+    );
+}
+
+/// Releases the escrow by paying the beneficiary in `external_token` (e.g. a
+/// SAC-wrapped asset the contract holds) rather than in this token. The
+/// internally locked amount, less the escrow fee, is burned, since the
+/// obligation it tracked has been met off-ledger. Depositor-only, as it
+/// chooses the asset paid out; the payout must come from the contract's
+/// unallocated balance of `external_token`, never from funds other escrows
+/// or splits hold in it.
+pub fn settle_external(e: &Env, escrow_id: u32, external_token: Address) {
+    let mut escrow = get_escrow(e, escrow_id);
+    escrow.depositor.require_auth();
+    require_not_paused(e);
+    require_not_expired(e, &escrow);
+    require_not_disputed(e, escrow_id);
+
+    // State & Timelock Validation
+    if external_token == e.current_contract_address() || escrow.token.is_some() {
+        panic_with_error!(e, Error::InvalidToken);
+    }
+    if e.ledger().sequence() < escrow.release_after_ledger {
+        panic_with_error!(e, Error::TimelockActive);
+    }
+    if escrow.released || escrow.refunded {
+        panic_with_error!(e, Error::AlreadySettled);
+    }
+
+    let fee = escrow_fee(e, &escrow, escrow.amount);
+    let payout = escrow.amount - fee;
+    if payout > sweep::external_surplus(e, &external_token) {
+        panic_with_error!(e, FeatureError::SettlementExceedsSurplus);
+    }
+
+    // Update state
+    escrow.released = true;
+    decrement_active_escrows(e);
+    e.storage().persistent().set(&DataKey::Escrow(escrow_id), &escrow);
+
+    // Retire the internal balance and pay out in the external asset
+    spend_balance(e, e.current_contract_address(), escrow.amount);
+    sweep::release_native(e, escrow.amount);
+    unlock_balance(e, escrow.depositor.clone(), escrow.amount);
+    decrease_supply(e, payout);
+    e.events().publish(
+        (symbol_short!("burn"), e.current_contract_address()),
+        payout
+    );
+    token::Client::new(e, &external_token).transfer(
+        &e.current_contract_address(),
+        &escrow.beneficiary,
+        &payout,
+    );
+
+    // Emit Event
+    e.events().publish(
+        (Symbol::new(e, "escrow"), Symbol::new(e, "settled_external"), escrow_id),
+        (external_token, escrow.beneficiary)
+    );
+}
+
+/// Lets the depositor waive the timelock so the escrow can be released now.
+pub fn approve_early_release(e: &Env, escrow_id: u32) {
+    let mut escrow = get_escrow(e, escrow_id);
+    escrow.depositor.require_auth();
+
+    if escrow.released || escrow.refunded {
+        panic_with_error!(e, Error::AlreadySettled);
+    }
+
+    escrow.early_release_approved = true;
+    e.storage().persistent().set(&DataKey::Escrow(escrow_id), &escrow);
+
+    e.events().publish(
+        (Symbol::new(e, "escrow"), Symbol::new(e, "early_release_approved"), escrow_id),
+        escrow.depositor
+    );
+}
+
+/// Lets the depositor correct the beneficiary while the timelock is active.
+pub fn change_escrow_beneficiary(e: &Env, escrow_id: u32, new_beneficiary: Address) {
+    let mut escrow = get_escrow(e, escrow_id);
+    escrow.depositor.require_auth();
+
+    // State & Timelock Validation
+    if escrow.released || escrow.refunded {
+        panic_with_error!(e, Error::AlreadySettled);
+    }
+    if e.ledger().sequence() >= escrow.release_after_ledger {
+        panic_with_error!(e, Error::TimelockElapsed);
+    }
+    require_not_disputed(e, escrow_id);
+    require_not_blacklisted(e, &new_beneficiary);
+
+    // Update state
+    let previous = escrow.beneficiary.clone();
+    escrow.beneficiary = new_beneficiary.clone();
+    e.storage().persistent().set(&DataKey::Escrow(escrow_id), &escrow);
+    unindex_escrow(e, &previous, escrow_id);
+    index_escrow(e, &new_beneficiary, escrow_id);
+
+    // Emit Event
+    e.events().publish(
+        (Symbol::new(e, "escrow"), Symbol::new(e, "beneficiary_changed"), escrow_id),
+        (previous, new_beneficiary)
+    );
+}
+
+/// Releases several escrows in one call. With `strict`, any escrow that is
+/// settled, timelocked, expired or disputed aborts the whole batch;
+/// otherwise it is skipped. Returns the ids that were released.
+/// Caller is responsible for admin authorization (see `check_admin`).
+pub fn release_escrows(e: &Env, ids: Vec<u32>, strict: bool) -> Vec<u32> {
+    let mut released = Vec::new(e);
+
+    for id in ids.iter() {
+        let escrow = get_escrow(e, id);
+        if !strict {
+            let settled = escrow.released || escrow.refunded;
+            let locked = !escrow.early_release_approved
+                && e.ledger().sequence() < escrow.release_after_ledger;
+            if settled || locked || is_expired(e, &escrow) || is_escrow_disputed(e, id) {
+                continue;
+            }
+        }
+        require_not_expired(e, &escrow);
+        require_not_disputed(e, id);
+        release_to_beneficiary(e, id);
+        released.push_back(id);
+    }
+
+    released
+}
+
+/// Refunds the escrowed funds back to the depositor. The depositor is held
+/// to a binding escrow's window; the arbiter can refund at any time.
+pub fn refund_escrow(e: &Env, caller: Address, escrow_id: u32) {
+    caller.require_auth();
+    let escrow = get_escrow(e, escrow_id);
+
+    if escrow.arbiter != Some(caller.clone()) {
+        if caller != escrow.depositor {
+            panic_with_error!(e, Error::Unauthorized);
+        }
+        require_refundable(e, &escrow);
+    }
+    require_not_disputed(e, escrow_id);
+    return_to_depositor(e, escrow_id, "refunded");
+}
+
+/// Refunds an expired escrow to the depositor. Anyone can call this once
+/// `expiration_ledger` is reached, binding or not.
+pub fn claim_expired(e: &Env, escrow_id: u32) {
+    let escrow = get_escrow(e, escrow_id);
+    if !is_expired(e, &escrow) {
+        panic_with_error!(e, Error::EscrowNotExpired);
+    }
+    require_not_disputed(e, escrow_id);
+    return_to_depositor(e, escrow_id, "expired");
+}
+
+/// Returns the funds to the depositor at any time, with both parties' consent.
+pub fn cancel_escrow(e: &Env, escrow_id: u32) {
+    let escrow = get_escrow(e, escrow_id);
+    escrow.depositor.require_auth();
+    escrow.beneficiary.require_auth();
+    require_not_disputed(e, escrow_id);
+    return_to_depositor(e, escrow_id, "cancelled");
+}
+
+/// Settles the escrow back to the depositor without the binding check.
+/// Used directly by dispute rulings, which override the binding window.
+pub(crate) fn return_to_depositor(e: &Env, escrow_id: u32, action: &str) {
     let mut escrow = get_escrow(e, escrow_id);
 
     // State Validation
     if escrow.released || escrow.refunded {
-        panic!("InvalidState: Escrow is already settled");
+        panic_with_error!(e, Error::AlreadySettled);
     }
 
     // Update state
     escrow.refunded = true;
+    decrement_active_escrows(e);
     e.storage().persistent().set(&DataKey::Escrow(escrow_id), &escrow);
 
     // Move funds from contract back to depositor
-    spend_balance(e, e.current_contract_address(), escrow.amount);
-    receive_balance(e, escrow.depositor.clone(), escrow.amount);
+    withdraw_escrowed(e, &escrow, escrow.amount);
+    pay_from_escrow(e, &escrow, &escrow.depositor, escrow.amount);
 
     // Emit Event
     e.events().publish(
-        (Symbol::new(e, "escrow"), Symbol::new(e, "refunded"), escrow_id),
+        (Symbol::new(e, "escrow"), Symbol::new(e, action), escrow_id),
         escrow.depositor
     );
 }
 
+/// Settles the escrow to both parties: `beneficiary_bps` to the
+/// beneficiary, less the escrow fee on that share, and the rest to the
+/// depositor. Used by split dispute rulings.
+pub(crate) fn split_to_parties(e: &Env, escrow_id: u32, beneficiary_bps: u32) {
+    let mut escrow = get_escrow(e, escrow_id);
+
+    // State Validation
+    if escrow.released || escrow.refunded {
+        panic_with_error!(e, Error::AlreadySettled);
+    }
+
+    // Update state
+    escrow.released = true;
+    decrement_active_escrows(e);
+    e.storage().persistent().set(&DataKey::Escrow(escrow_id), &escrow);
+
+    // Split with the splitter's math, so the depositor absorbs the dust
+    let shares = Vec::from_array(
+        e,
+        [
+            SplitRecipient { address: escrow.beneficiary.clone(), share_bps: beneficiary_bps },
+            SplitRecipient { address: escrow.depositor.clone(), share_bps: 10000 - beneficiary_bps },
+        ],
+    );
+    let amounts = calculate_distribution(e, escrow.amount, &shares);
+    let to_beneficiary = amounts.get(0).unwrap();
+    let to_depositor = amounts.get(1).unwrap();
+
+    // Move funds from contract to both parties
+    withdraw_escrowed(e, &escrow, escrow.amount);
+    let fee = escrow_fee(e, &escrow, to_beneficiary);
+    pay_from_escrow(e, &escrow, &escrow.beneficiary, to_beneficiary - fee);
+    pay_from_escrow(e, &escrow, &escrow.depositor, to_depositor);
+
+    // Emit Event
+    e.events().publish(
+        (Symbol::new(e, "escrow"), Symbol::new(e, "split_settled"), escrow_id),
+        (to_beneficiary, to_depositor)
+    );
+}
+
+/// Destroys `amount` of an unsettled escrow's locked funds, reducing total
+/// supply. Used by dispute rulings with a penalty burn, which only apply to
+/// escrows in this token.
+pub(crate) fn burn_escrow_portion(e: &Env, escrow_id: u32, amount: i128) {
+    let mut escrow = get_escrow(e, escrow_id);
+    if escrow.released || escrow.refunded {
+        panic_with_error!(e, Error::AlreadySettled);
+    }
+    if escrow.token.is_some() {
+        panic_with_error!(e, Error::InvalidToken);
+    }
+
+    escrow.amount -= amount;
+    e.storage().persistent().set(&DataKey::Escrow(escrow_id), &escrow);
+
+    spend_balance(e, e.current_contract_address(), amount);
+    sweep::release_native(e, amount);
+    unlock_balance(e, escrow.depositor.clone(), amount);
+    decrease_supply(e, amount);
+    e.events().publish(
+        (symbol_short!("burn"), e.current_contract_address()),
+        amount
+    );
+}
+
+/// Takes `amount` out of the contract's custody for an escrow in this
+/// token. An escrow in another token is paid straight from the contract's
+/// holding by `pay_from_escrow`.
+fn withdraw_escrowed(e: &Env, escrow: &EscrowRecord, amount: i128) {
+    if escrow.token.is_none() {
+        spend_balance(e, e.current_contract_address(), amount);
+        sweep::release_native(e, amount);
+        unlock_balance(e, escrow.depositor.clone(), amount);
+    }
+}
+
+/// Pays `amount` of the escrow's funds to `to`, in the escrow's token.
+fn pay_from_escrow(e: &Env, escrow: &EscrowRecord, to: &Address, amount: i128) {
+    match &escrow.token {
+        Some(token_address) => {
+            sweep::release_in_token(e, token_address, amount);
+            token::Client::new(e, token_address).transfer(&e.current_contract_address(), to, &amount)
+        }
+        None => receive_balance(e, to.clone(), amount),
+    }
+}
+
+/// The escrow fee is charged in this token, so escrows in other tokens pay none.
+fn escrow_fee(e: &Env, escrow: &EscrowRecord, amount: i128) -> i128 {
+    if escrow.token.is_some() {
+        0
+    } else {
+        take_escrow_fee(e, amount)
+    }
+}
+
+fn is_expired(e: &Env, escrow: &EscrowRecord) -> bool {
+    e.ledger().sequence() >= escrow.expiration_ledger
+}
+
+fn require_not_expired(e: &Env, escrow: &EscrowRecord) {
+    if is_expired(e, escrow) {
+        panic_with_error!(e, Error::EscrowExpired);
+    }
+}
+
+/// Panics while a dispute holds the escrow; rulings settle it directly.
+fn require_not_disputed(e: &Env, escrow_id: u32) {
+    if is_escrow_disputed(e, escrow_id) {
+        panic_with_error!(e, FeatureError::EscrowDisputed);
+    }
+}
+
+fn require_refundable(e: &Env, escrow: &EscrowRecord) {
+    if escrow.binding && e.ledger().sequence() < escrow.expiration_ledger {
+        panic_with_error!(e, Error::EscrowActive);
+    }
+}
+
+/// Refunds part of the escrowed funds back to the depositor.
+/// The escrow is only marked refunded once nothing remains locked.
+pub fn refund_escrow_partial(e: &Env, escrow_id: u32, amount: i128) {
+    let mut escrow = get_escrow(e, escrow_id);
+    escrow.depositor.require_auth();
+
+    // State Validation
+    if escrow.released || escrow.refunded {
+        panic_with_error!(e, Error::AlreadySettled);
+    }
+    require_refundable(e, &escrow);
+    require_not_disputed(e, escrow_id);
+    if amount <= 0 || amount > escrow.amount {
+        panic_with_error!(e, Error::InvalidAmount);
+    }
+
+    // Update state
+    escrow.amount -= amount;
+    if escrow.amount == 0 {
+        escrow.refunded = true;
+        decrement_active_escrows(e);
+    }
+    e.storage().persistent().set(&DataKey::Escrow(escrow_id), &escrow);
+
+    // Move funds from contract back to depositor
+    withdraw_escrowed(e, &escrow, amount);
+    pay_from_escrow(e, &escrow, &escrow.depositor, amount);
+
+    // Emit Event
+    e.events().publish(
+        (Symbol::new(e, "escrow"), Symbol::new(e, "partial_refund"), escrow_id),
+        amount
+    );
+}
+
+/// Amount still locked in an escrow and its settlement status: `active`,
+/// `released`, `refunded`, or `expired` once an unsettled escrow reaches
+/// its `expiration_ledger`.
+pub fn escrow_status(e: &Env, escrow_id: u32) -> (i128, Symbol) {
+    let escrow = get_escrow(e, escrow_id);
+
+    if escrow.released {
+        (0, Symbol::new(e, "released"))
+    } else if escrow.refunded {
+        (0, Symbol::new(e, "refunded"))
+    } else if e.ledger().sequence() >= escrow.expiration_ledger {
+        (escrow.amount, Symbol::new(e, "expired"))
+    } else {
+        (escrow.amount, Symbol::new(e, "active"))
+    }
+}
+
+/// Number of escrows neither released nor refunded.
+pub fn active_escrow_count(e: &Env) -> u32 {
+    e.storage().instance().get(&DataKey::ActiveEscrowCount).unwrap_or(0)
+}
+
+fn decrement_active_escrows(e: &Env) {
+    // Saturating, as escrows created before the counter existed are untracked
+    let active = active_escrow_count(e).saturating_sub(1);
+    e.storage().instance().set(&DataKey::ActiveEscrowCount, &active);
+}
+
 /// Helper to read an escrow record
 pub fn get_escrow(e: &Env, escrow_id: u32) -> EscrowRecord {
+    try_get_escrow(e, escrow_id).unwrap_or_else(|| panic_with_error!(e, Error::EscrowNotFound))
+}
+
+/// Reads an escrow record, returning `None` if the id does not exist.
+pub fn try_get_escrow(e: &Env, escrow_id: u32) -> Option<EscrowRecord> {
+    e.storage().persistent().get(&DataKey::Escrow(escrow_id))
+}
+
+/// Ids of the escrows `addr` is depositor or beneficiary of, oldest first.
+pub fn escrow_ids_for(e: &Env, addr: &Address) -> Vec<u32> {
     e.storage()
         .persistent()
-        .get(&DataKey::Escrow(escrow_id))
-        .expect("Escrow not found")
+        .get(&EscrowIndexKey::EscrowsOf(addr.clone()))
+        .unwrap_or_else(|| Vec::new(e))
+}
+
+/// A page of the escrows `addr` is depositor or beneficiary of: at most
+/// `limit` records, skipping the first `start` in creation order.
+pub fn get_escrows_for(e: &Env, addr: Address, start: u32, limit: u32) -> Vec<EscrowRecord> {
+    let ids = escrow_ids_for(e, &addr);
+    let mut page = Vec::new(e);
+
+    for id in ids.iter().skip(start as usize) {
+        if page.len() >= limit {
+            break;
+        }
+        if let Some(record) = try_get_escrow(e, id) {
+            page.push_back(record);
+        }
+    }
+
+    page
+}
+
+fn index_escrow(e: &Env, addr: &Address, escrow_id: u32) {
+    let mut ids = escrow_ids_for(e, addr);
+    ids.push_back(escrow_id);
+    e.storage().persistent().set(&EscrowIndexKey::EscrowsOf(addr.clone()), &ids);
+}
+
+fn unindex_escrow(e: &Env, addr: &Address, escrow_id: u32) {
+    let mut ids = escrow_ids_for(e, addr);
+    if let Some(index) = ids.first_index_of(escrow_id) {
+        ids.remove(index);
+        e.storage().persistent().set(&EscrowIndexKey::EscrowsOf(addr.clone()), &ids);
+    }
 }
 
 // --- MULTI-RECIPIENT ESCROW LOGIC ---
@@ -131,6 +750,23 @@ pub struct MultiEscrowRecord {
     pub total_amount: i128,
     pub released: bool,
     pub refunded: bool,
+    /// Ledger at which releases stop. The depositor may refund at any time.
+    pub expiration_ledger: u32,
+    /// Ledger before which no recipient can be released.
+    pub release_after_ledger: u32,
+    /// Per-recipient timelocks, parallel to `recipients`; empty when the
+    /// escrow was created without them.
+    pub release_after: Vec<u32>,
+    /// Indexes of recipients already paid by `release_multi_recipient`.
+    pub paid: Vec<u32>,
+}
+
+/// Helper to read a multi-recipient escrow record
+pub fn get_multi_escrow(e: &Env, escrow_id: u32) -> MultiEscrowRecord {
+    e.storage()
+        .persistent()
+        .get(&DataKey::MultiEscrow(escrow_id))
+        .unwrap_or_else(|| panic_with_error!(e, Error::EscrowNotFound))
 }
 
 /// Creates a multi-recipient escrow and locks the funds.
@@ -139,21 +775,63 @@ pub fn create_multi_escrow(
     depositor: Address,
     recipients: Vec<SplitRecipient>,
     total_amount: i128,
+    expiration_ledger: u32,
+    release_after_ledger: u32,
 ) -> u32 {
-    depositor.require_auth();
+    lock_multi_escrow(
+        e,
+        depositor,
+        recipients,
+        total_amount,
+        expiration_ledger,
+        release_after_ledger,
+        Vec::new(e),
+    )
+}
 
-    // 1. Validate BPS Sums to 10000 (100.00%)
-    let mut total_bps: u32 = 0;
-    for recipient in recipients.iter() {
-        total_bps += recipient.share_bps;
+/// Creates a multi-recipient escrow for staggered vesting: recipient `i`
+/// can be paid individually once `release_after[i]` is reached.
+pub fn create_vesting_multi_escrow(
+    e: &Env,
+    depositor: Address,
+    recipients: Vec<SplitRecipient>,
+    total_amount: i128,
+    expiration_ledger: u32,
+    release_after: Vec<u32>,
+) -> u32 {
+    if release_after.len() != recipients.len() {
+        panic_with_error!(e, Error::LengthMismatch);
     }
-    if total_bps != 10000 {
-        panic!("total bps must equal 10000");
+    lock_multi_escrow(e, depositor, recipients, total_amount, expiration_ledger, 0, release_after)
+}
+
+fn lock_multi_escrow(
+    e: &Env,
+    depositor: Address,
+    recipients: Vec<SplitRecipient>,
+    total_amount: i128,
+    expiration_ledger: u32,
+    release_after_ledger: u32,
+    release_after: Vec<u32>,
+) -> u32 {
+    depositor.require_auth();
+    require_not_paused(e);
+    require_can_send(e, &depositor);
+    if total_amount <= 0 {
+        panic_with_error!(e, Error::InvalidAmount);
+    }
+
+    // 1. Validate BPS Sums to 10000 (100.00%) and the recipient bound
+    validate_bps(&recipients);
+    if recipients.iter().any(|r| r.address == depositor) {
+        panic_with_error!(e, Error::InvalidBeneficiary);
     }
 
     // 2. Move funds from depositor to the contract
     spend_balance(e, depositor.clone(), total_amount);
     receive_balance(e, e.current_contract_address(), total_amount);
+    sweep::hold_native(e, total_amount);
+    lock_balance(e, depositor.clone(), total_amount);
 
     // 3. Manage ID and Storage
     let mut count: u32 = e.storage().instance().get(&DataKey::MultiEscrowCount).unwrap_or(0);
@@ -167,6 +845,10 @@ pub fn create_multi_escrow(
         total_amount,
         released: false,
         refunded: false,
+        expiration_ledger,
+        release_after_ledger,
+        release_after,
+        paid: Vec::new(e),
     };
     e.storage().persistent().set(&DataKey::MultiEscrow(count), &record);
 
@@ -180,63 +862,257 @@ pub fn create_multi_escrow(
 pub fn release_multi_escrow(e: &Env, caller: Address, escrow_id: u32) {
     caller.require_auth();
 
-    let mut record: MultiEscrowRecord = e.storage().persistent().get(&DataKey::MultiEscrow(escrow_id)).expect("Escrow not found");
+    let mut record: MultiEscrowRecord = e.storage().persistent().get(&DataKey::MultiEscrow(escrow_id)).unwrap_or_else(|| panic_with_error!(e, Error::EscrowNotFound));
 
     // 1. Validation: Prevent double-settlement
     if record.released || record.refunded {
-        panic!("Already settled");
+        panic_with_error!(e, Error::AlreadySettled);
     }
 
     // 2. Authorization: Caller must be depositor or admin
     if caller != record.depositor {
         let admin = read_admin(e);
         if caller != admin {
-            panic!("unauthorized: must be depositor or admin");
+            panic_with_error!(e, Error::Unauthorized);
+        }
+    }
+    require_multi_releasable(e, &record);
+
+    // 3. Distribute funds proportionally (handling dust), skipping recipients
+    // already paid individually
+    unlock_balance(e, record.depositor.clone(), unpaid_multi_amount(e, &record));
+    let shares = multi_escrow_shares(e, &record);
+    for (i, (recipient, amount_to_send)) in record.recipients.iter().zip(shares.iter()).enumerate() {
+        if record.paid.contains(i as u32) {
+            continue;
+        }
+        spend_balance(e, e.current_contract_address(), amount_to_send);
+        sweep::release_native(e, amount_to_send);
+        receive_balance(e, recipient.address.clone(), amount_to_send);
+    }
+
+    // 4. Update state
+    record.released = true;
+    e.storage().persistent().set(&DataKey::MultiEscrow(escrow_id), &record);
+
+    e.events().publish((Symbol::new(e, "multi_escrow"), Symbol::new(e, "released"), escrow_id), record.total_amount);
+}
+
+/// Releases a multi-recipient escrow by crediting each share to a claimable
+/// entry instead of pushing it. Every recipient then pulls their own share
+/// with `claim_multi_escrow`, so one recipient that cannot receive (e.g. a
+/// fully frozen account) no longer blocks the others.
+pub fn release_multi_escrow_to_claims(e: &Env, caller: Address, escrow_id: u32) {
+    caller.require_auth();
+
+    let mut record = get_multi_escrow(e, escrow_id);
+
+    // 1. Validation: Prevent double-settlement
+    if record.released || record.refunded {
+        panic_with_error!(e, Error::AlreadySettled);
+    }
+
+    // 2. Authorization: Caller must be depositor or admin
+    if caller != record.depositor && caller != read_admin(e) {
+        panic_with_error!(e, Error::Unauthorized);
+    }
+    require_multi_releasable(e, &record);
+
+    // 3. Allocate shares; funds stay in the contract until claimed, but
+    // now belong to the recipients rather than the depositor
+    unlock_balance(e, record.depositor.clone(), unpaid_multi_amount(e, &record));
+    let shares = multi_escrow_shares(e, &record);
+    for (i, (recipient, amount)) in record.recipients.iter().zip(shares.iter()).enumerate() {
+        if record.paid.contains(i as u32) {
+            continue;
+        }
+        let key = DataKey::MultiClaimable(escrow_id, recipient.address);
+        let claimable: i128 = e.storage().persistent().get(&key).unwrap_or(0);
+        e.storage().persistent().set(&key, &(claimable + amount));
+    }
+
+    // 4. Update state
+    record.released = true;
+    e.storage().persistent().set(&DataKey::MultiEscrow(escrow_id), &record);
+
+    e.events().publish((Symbol::new(e, "multi_escrow"), Symbol::new(e, "allocated"), escrow_id), record.total_amount);
+}
+
+/// Pays out a recipient's share allocated by `release_multi_escrow_to_claims`.
+pub fn claim_multi_escrow(e: &Env, escrow_id: u32, recipient: Address) -> i128 {
+    recipient.require_auth();
+
+    let key = DataKey::MultiClaimable(escrow_id, recipient.clone());
+    let amount = multi_claimable(e, escrow_id, &recipient);
+    if amount == 0 {
+        panic_with_error!(e, Error::NothingToClaim);
+    }
+    e.storage().persistent().remove(&key);
+
+    spend_balance(e, e.current_contract_address(), amount);
+    sweep::release_native(e, amount);
+    receive_balance(e, recipient.clone(), amount);
+
+    e.events().publish((Symbol::new(e, "multi_escrow"), Symbol::new(e, "claimed"), escrow_id), (recipient, amount));
+    amount
+}
+
+/// Unclaimed share allocated to `recipient`; 0 if none.
+pub fn multi_claimable(e: &Env, escrow_id: u32, recipient: &Address) -> i128 {
+    e.storage()
+        .persistent()
+        .get(&DataKey::MultiClaimable(escrow_id, recipient.clone()))
+        .unwrap_or(0)
+}
+
+/// Pays recipient `recipient_index` their share once their own timelock
+/// has passed. Anyone can call this. The escrow counts as released once
+/// every recipient has been paid.
+pub fn release_multi_recipient(e: &Env, escrow_id: u32, recipient_index: u32) {
+    let mut record = get_multi_escrow(e, escrow_id);
+
+    // 1. Validation
+    if record.released || record.refunded {
+        panic_with_error!(e, Error::AlreadySettled);
+    }
+    let recipient = record.recipients.get(recipient_index).unwrap_or_else(|| panic_with_error!(e, Error::RecipientNotFound));
+    if record.paid.contains(recipient_index) {
+        panic_with_error!(e, Error::AlreadyPaid);
+    }
+    require_multi_releasable(e, &record);
+    let release_after = record.release_after.get(recipient_index).unwrap_or(0);
+    if e.ledger().sequence() < release_after {
+        panic_with_error!(e, Error::TimelockActive);
+    }
+
+    // 2. Pay this recipient's share
+    pay_multi_recipient(e, &mut record, recipient_index, recipient);
+    e.storage().persistent().set(&DataKey::MultiEscrow(escrow_id), &record);
+}
+
+/// Pays only the recipients at `indexes` (e.g. a vendor that delivered
+/// while the venue did not), leaving the other shares locked for a later
+/// release or refund. Caller must be the depositor or admin, who are not
+/// held to per-recipient vesting timelocks.
+pub fn release_multi_recipients(e: &Env, caller: Address, escrow_id: u32, indexes: Vec<u32>) {
+    caller.require_auth();
+
+    let mut record = get_multi_escrow(e, escrow_id);
+
+    // 1. Validation
+    if record.released || record.refunded {
+        panic_with_error!(e, Error::AlreadySettled);
+    }
+    if caller != record.depositor && caller != read_admin(e) {
+        panic_with_error!(e, Error::Unauthorized);
+    }
+    require_multi_releasable(e, &record);
+
+    // 2. Pay each selected recipient; a repeated index fails as AlreadyPaid
+    for index in indexes.iter() {
+        let recipient = record.recipients.get(index).unwrap_or_else(|| panic_with_error!(e, Error::RecipientNotFound));
+        if record.paid.contains(index) {
+            panic_with_error!(e, Error::AlreadyPaid);
         }
+        pay_multi_recipient(e, &mut record, index, recipient);
+    }
+    e.storage().persistent().set(&DataKey::MultiEscrow(escrow_id), &record);
+}
+
+/// Marks recipient `index` paid and sends their share. The escrow counts as
+/// released once every recipient has been paid. Caller persists `record`.
+fn pay_multi_recipient(e: &Env, record: &mut MultiEscrowRecord, index: u32, recipient: SplitRecipient) {
+    record.paid.push_back(index);
+    if record.paid.len() == record.recipients.len() {
+        record.released = true;
+    }
+
+    let amount = multi_escrow_shares(e, record).get(index).unwrap();
+    spend_balance(e, e.current_contract_address(), amount);
+    sweep::release_native(e, amount);
+    unlock_balance(e, record.depositor.clone(), amount);
+    receive_balance(e, recipient.address.clone(), amount);
+
+    e.events().publish(
+        (Symbol::new(e, "multi_escrow"), Symbol::new(e, "recipient_released"), record.id),
+        (recipient.address, amount)
+    );
+}
+
+/// Amount of a multi-escrow still locked for its unpaid recipients.
+pub fn multi_escrow_remaining(e: &Env, escrow_id: u32) -> i128 {
+    let record = get_multi_escrow(e, escrow_id);
+    if record.released || record.refunded {
+        return 0;
+    }
+    unpaid_multi_amount(e, &record)
+}
+
+/// Same window as a single escrow: releases are allowed from
+/// `release_after_ledger` until `expiration_ledger`.
+fn require_multi_releasable(e: &Env, record: &MultiEscrowRecord) {
+    let now = e.ledger().sequence();
+    if now < record.release_after_ledger {
+        panic_with_error!(e, Error::TimelockActive);
+    }
+    if now >= record.expiration_ledger {
+        panic_with_error!(e, Error::EscrowExpired);
     }
+}
 
-    // 3. Distribute funds proportionally (handling dust)
+/// Amount still locked for recipients not yet paid individually.
+pub fn unpaid_multi_amount(e: &Env, record: &MultiEscrowRecord) -> i128 {
+    let shares = multi_escrow_shares(e, record);
+    let mut unpaid = record.total_amount;
+    for index in record.paid.iter() {
+        unpaid -= shares.get(index).unwrap();
+    }
+    unpaid
+}
+
+/// Each recipient's share of a multi-escrow by bps, with the dust going to
+/// the final recipient.
+fn multi_escrow_shares(e: &Env, record: &MultiEscrowRecord) -> Vec<i128> {
+    let mut shares = Vec::new(e);
     let mut remaining_amount = record.total_amount;
     let len = record.recipients.len();
 
     for (i, recipient) in record.recipients.iter().enumerate() {
-        let amount_to_send = if i == (len as usize - 1) {
+        let share = if i == (len as usize - 1) {
             remaining_amount // Final recipient gets remainder to prevent dust
         } else {
             (record.total_amount * recipient.share_bps as i128) / 10000
         };
-
-        spend_balance(e, e.current_contract_address(), amount_to_send);
-        receive_balance(e, recipient.address.clone(), amount_to_send);
-        remaining_amount -= amount_to_send;
+        shares.push_back(share);
+        remaining_amount -= share;
     }
 
-    // 4. Update state
-    record.released = true;
-    e.storage().persistent().set(&DataKey::MultiEscrow(escrow_id), &record);
-
-    e.events().publish((Symbol::new(e, "multi_escrow"), Symbol::new(e, "released"), escrow_id), record.total_amount);
+    shares
 }
 
 /// Refunds the entire amount back to the depositor.
 pub fn refund_multi_escrow(e: &Env, caller: Address, escrow_id: u32) {
     caller.require_auth();
 
-    let mut record: MultiEscrowRecord = e.storage().persistent().get(&DataKey::MultiEscrow(escrow_id)).expect("Escrow not found");
+    let mut record: MultiEscrowRecord = e.storage().persistent().get(&DataKey::MultiEscrow(escrow_id)).unwrap_or_else(|| panic_with_error!(e, Error::EscrowNotFound));
 
     // 1. Validation: Prevent double-settlement
     if record.released || record.refunded {
-        panic!("Already settled");
+        panic_with_error!(e, Error::AlreadySettled);
     }
 
-    // 2. Authorization: Caller must be depositor
+    // 2. Authorization: Caller must be depositor; like a non-binding
+    // escrow, the refund is open before and after expiry
     if caller != record.depositor {
-        panic!("unauthorized: must be depositor");
+        panic_with_error!(e, Error::Unauthorized);
     }
 
-    // 3. Return funds to depositor
-    spend_balance(e, e.current_contract_address(), record.total_amount);
-    receive_balance(e, record.depositor.clone(), record.total_amount);
+    // 3. Return the funds not yet paid out to depositor
+    let unpaid = unpaid_multi_amount(e, &record);
+    spend_balance(e, e.current_contract_address(), unpaid);
+    sweep::release_native(e, unpaid);
+    unlock_balance(e, record.depositor.clone(), unpaid);
+    receive_balance(e, record.depositor.clone(), unpaid);
 
     // 4. Update state
     record.refunded = true;
@@ -1,15 +1,35 @@
 #[cfg(test)]
 mod escrow_tests {
-    use super::*;
-    use soroban_sdk::{testutils::{Address as _, Ledger}, Address, Env};
-    use crate::escrow::{EscrowContract, EscrowContractClient}; // Adjust based on your actual trait name
+    use crate::freeze::FreezeMode;
+    use crate::splitter::SplitRecipient;
+    use crate::storage_types::FeeConfig;
+    use crate::{VeritixToken, VeritixTokenClient};
+    use soroban_sdk::{testutils::{Address as _, Ledger}, token, vec, Address, Env, String, Symbol};
+
+    fn setup_test(e: &Env) -> (Address, Address, VeritixTokenClient<'_>) {
+        let (_, depositor, beneficiary, client) = setup_with_admin(e);
+        (depositor, beneficiary, client)
+    }
+
+    fn setup_with_admin(e: &Env) -> (Address, Address, Address, VeritixTokenClient<'_>) {
+        e.mock_all_auths();
+
+        let contract_id = e.register_contract(None, VeritixToken);
+        let client = VeritixTokenClient::new(e, &contract_id);
 
-    fn setup_test(e: &Env) -> (Address, Address, EscrowContractClient<'_>) {
+        let admin = Address::generate(e);
         let depositor = Address::generate(e);
         let beneficiary = Address::generate(e);
-        let contract_id = e.register_contract(None, EscrowContract);
-        let client = EscrowContractClient::new(e, &contract_id);
-        (depositor, beneficiary, client)
+
+        client.initialize(
+            &admin,
+            &String::from_str(e, "Veritix"),
+            &String::from_str(e, "VTX"),
+            &7u32,
+        );
+        client.mint(&admin, &depositor, &10_000i128);
+
+        (admin, depositor, beneficiary, client)
     }
 
     #[test]
@@ -18,12 +38,74 @@ mod escrow_tests {
         let (depositor, beneficiary, client) = setup_test(&e);
         let amount = 1000i128;
 
-        client.create_escrow(&depositor, &beneficiary, &amount);
-        
-        let escrow = client.get_escrow(&depositor, &beneficiary);
+        let id = client.create_escrow(&depositor, &beneficiary, &amount, &1000, &0);
+
+        let escrow = client.get_escrow(&id);
         assert_eq!(escrow.amount, amount);
-        assert_eq!(escrow.released, false);
-        assert_eq!(escrow.refunded, false);
+        assert!(!escrow.released);
+        assert!(!escrow.refunded);
+        assert_eq!(client.balance(&depositor), 9000);
+        assert_eq!(client.balance(&client.address), amount);
+    }
+
+    #[test]
+    fn test_locked_balance_tracks_escrows_and_splits() {
+        let e = Env::default();
+        let (depositor, beneficiary, client) = setup_test(&e);
+        let other = Address::generate(&e);
+
+        let first = client.create_escrow(&depositor, &beneficiary, &1000, &1000, &0);
+        let second = client.create_escrow(&depositor, &beneficiary, &500, &1000, &0);
+        let recipients = vec![
+            &e,
+            SplitRecipient { address: beneficiary.clone(), share_bps: 5000 },
+            SplitRecipient { address: other.clone(), share_bps: 5000 },
+        ];
+        let split = client.create_split(&depositor, &recipients, &2000, &None);
+
+        assert_eq!(client.locked_balance(&depositor), 3500);
+        assert_eq!(client.balance(&depositor), 6500);
+        assert_eq!(client.spendable_balance(&depositor), 6500);
+
+        client.release_escrow(&beneficiary, &first);
+        client.refund_escrow_partial(&second, &200);
+        client.claim_split(&split, &other);
+        assert_eq!(client.locked_balance(&depositor), 1300);
+
+        client.refund_escrow(&depositor, &second);
+        client.distribute(&depositor, &split);
+        assert_eq!(client.locked_balance(&depositor), 0);
+        assert_eq!(client.balance(&depositor), 7000);
+    }
+
+    #[test]
+    fn test_get_escrows_for_pages_by_participant() {
+        let e = Env::default();
+        let (depositor, beneficiary, client) = setup_test(&e);
+        let other = Address::generate(&e);
+
+        let first = client.create_escrow(&depositor, &beneficiary, &100, &1000, &10);
+        let second = client.create_escrow(&depositor, &other, &200, &1000, &10);
+        let third = client.create_escrow(&depositor, &beneficiary, &300, &1000, &10);
+
+        let page = client.get_escrows_for(&depositor, &0, &2);
+        assert_eq!(page.len(), 2);
+        assert_eq!(page.get(0).unwrap().id, first);
+        assert_eq!(page.get(1).unwrap().id, second);
+        let page = client.get_escrows_for(&depositor, &2, &2);
+        assert_eq!(page.len(), 1);
+        assert_eq!(page.get(0).unwrap().id, third);
+
+        let page = client.get_escrows_for(&beneficiary, &0, &10);
+        assert_eq!(page.len(), 2);
+        assert_eq!(page.get(1).unwrap().id, third);
+
+        // Changing the beneficiary moves the escrow between indexes
+        client.change_escrow_beneficiary(&first, &other);
+        assert_eq!(client.get_escrows_for(&beneficiary, &0, &10).len(), 1);
+        let page = client.get_escrows_for(&other, &0, &10);
+        assert_eq!(page.len(), 2);
+        assert_eq!(page.get(1).unwrap().id, first);
     }
 
     #[test]
@@ -32,12 +114,12 @@ mod escrow_tests {
         let (depositor, beneficiary, client) = setup_test(&e);
         let amount = 1000i128;
 
-        client.create_escrow(&depositor, &beneficiary, &amount);
-        client.release_escrow(&beneficiary); // Should be called by beneficiary
+        let id = client.create_escrow(&depositor, &beneficiary, &amount, &1000, &0);
+        client.release_escrow(&beneficiary, &id);
 
-        let escrow = client.get_escrow(&depositor, &beneficiary);
+        let escrow = client.get_escrow(&id);
         assert!(escrow.released);
-        // Verify beneficiary balance increased by 'amount' via your token mock here
+        assert_eq!(client.balance(&beneficiary), amount);
     }
 
     #[test]
@@ -46,133 +128,817 @@ mod escrow_tests {
         let (depositor, beneficiary, client) = setup_test(&e);
         let amount = 1000i128;
 
-        client.create_escrow(&depositor, &beneficiary, &amount);
-        client.refund_escrow(&depositor);
+        let id = client.create_escrow(&depositor, &beneficiary, &amount, &1000, &0);
+        client.refund_escrow(&depositor, &id);
 
-        let escrow = client.get_escrow(&depositor, &beneficiary);
+        let escrow = client.get_escrow(&id);
         assert!(escrow.refunded);
+        assert_eq!(client.balance(&depositor), 10_000);
     }
 
     #[test]
-    #[should_panic(expected = "not beneficiary")]
-    fn test_release_unauthorized_panics() {
+    #[should_panic(expected = "Error(Contract, #31)")]
+    fn test_double_release_panics() {
+        let e = Env::default();
+        let (depositor, beneficiary, client) = setup_test(&e);
+        let id = client.create_escrow(&depositor, &beneficiary, &1000, &1000, &0);
+
+        client.release_escrow(&beneficiary, &id);
+        client.release_escrow(&beneficiary, &id); // Panic
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #31)")]
+    fn test_double_refund_panics() {
+        let e = Env::default();
+        let (depositor, beneficiary, client) = setup_test(&e);
+        let id = client.create_escrow(&depositor, &beneficiary, &1000, &1000, &0);
+
+        client.refund_escrow(&depositor, &id);
+        client.refund_escrow(&depositor, &id); // Panic
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #31)")]
+    fn test_release_after_refund_panics() {
+        let e = Env::default();
+        let (depositor, beneficiary, client) = setup_test(&e);
+        let id = client.create_escrow(&depositor, &beneficiary, &1000, &1000, &0);
+
+        client.refund_escrow(&depositor, &id);
+        client.release_escrow(&beneficiary, &id); // Panic
+    }
+
+    #[test]
+    fn test_refund_escrow_partial() {
+        let e = Env::default();
+        let (depositor, beneficiary, client) = setup_test(&e);
+        let id = client.create_escrow(&depositor, &beneficiary, &1000, &1000, &0);
+
+        // Refund half, the rest stays locked
+        client.refund_escrow_partial(&id, &500);
+        let escrow = client.get_escrow(&id);
+        assert_eq!(escrow.amount, 500);
+        assert!(!escrow.refunded);
+        assert_eq!(client.balance(&depositor), 9500);
+
+        // Refund the remainder, which settles the escrow
+        client.refund_escrow_partial(&id, &500);
+        let escrow = client.get_escrow(&id);
+        assert_eq!(escrow.amount, 0);
+        assert!(escrow.refunded);
+        assert_eq!(client.balance(&depositor), 10_000);
+        assert_eq!(client.balance(&client.address), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #20)")]
+    fn test_refund_escrow_partial_exceeding_amount_panics() {
+        let e = Env::default();
+        let (depositor, beneficiary, client) = setup_test(&e);
+        let id = client.create_escrow(&depositor, &beneficiary, &1000, &1000, &0);
+
+        client.refund_escrow_partial(&id, &1001);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #31)")]
+    fn test_refund_escrow_partial_after_full_refund_panics() {
+        let e = Env::default();
+        let (depositor, beneficiary, client) = setup_test(&e);
+        let id = client.create_escrow(&depositor, &beneficiary, &1000, &1000, &0);
+
+        client.refund_escrow_partial(&id, &1000);
+        client.refund_escrow_partial(&id, &1); // Panic
+    }
+
+    #[test]
+    fn test_create_multi_escrow() {
         let e = Env::default();
         let (depositor, _, client) = setup_test(&e);
-        client.create_escrow(&depositor, &Address::generate(&e), &1000);
-        
-        // Hacker tries to release
-        let hacker = Address::generate(&e);
-        client.release_escrow(&hacker);
+        let recipient1 = Address::generate(&e);
+        let recipient2 = Address::generate(&e);
+
+        let recipients = vec![
+            &e,
+            SplitRecipient { address: recipient1, share_bps: 6000 },
+            SplitRecipient { address: recipient2, share_bps: 4000 },
+        ];
+
+        let id = client.create_multi_escrow(&depositor, &recipients, &1000, &1000, &0);
+
+        let record = client.get_multi_escrow(&id);
+        assert_eq!(record.total_amount, 1000);
+        assert_eq!(record.recipients, recipients);
+        assert_eq!(client.balance(&depositor), 9000);
     }
 
     #[test]
-    #[should_panic(expected = "not depositor")]
-    fn test_refund_unauthorized_panics() {
+    fn test_release_multi_escrow_3_recipients() {
+        let e = Env::default();
+        let (depositor, _, client) = setup_test(&e);
+        let r1 = Address::generate(&e);
+        let r2 = Address::generate(&e);
+        let r3 = Address::generate(&e);
+
+        let recipients = vec![
+            &e,
+            SplitRecipient { address: r1.clone(), share_bps: 5000 },
+            SplitRecipient { address: r2.clone(), share_bps: 3000 },
+            SplitRecipient { address: r3.clone(), share_bps: 2000 },
+        ];
+
+        let id = client.create_multi_escrow(&depositor, &recipients, &1000, &1000, &0);
+        client.release_multi_escrow(&depositor, &id);
+
+        assert_eq!(client.balance(&r1), 500);
+        assert_eq!(client.balance(&r2), 300);
+        assert_eq!(client.balance(&r3), 200);
+        assert!(client.get_multi_escrow(&id).released);
+    }
+
+    #[test]
+    fn test_multi_escrow_claims_isolate_blocked_recipient() {
+        let e = Env::default();
+        let (admin, depositor, _, client) = setup_with_admin(&e);
+        let r1 = Address::generate(&e);
+        let r2 = Address::generate(&e);
+
+        let recipients = vec![
+            &e,
+            SplitRecipient { address: r1.clone(), share_bps: 6000 },
+            SplitRecipient { address: r2.clone(), share_bps: 4000 },
+        ];
+
+        let id = client.create_multi_escrow(&depositor, &recipients, &1000, &1000, &0);
+        // A fully frozen recipient would make a push release fail for everyone
+        client.freeze_with_mode(&admin, &r2, &FreezeMode::Full);
+        client.release_multi_escrow_to_claims(&depositor, &id);
+
+        assert_eq!(client.claim_multi_escrow(&id, &r1), 600);
+        assert_eq!(client.balance(&r1), 600);
+        assert_eq!(client.multi_claimable(&id, &r1), 0);
+        assert_eq!(client.multi_claimable(&id, &r2), 400);
+        assert_eq!(client.balance(&client.address), 400);
+        assert!(client.get_multi_escrow(&id).released);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #39)")]
+    fn test_multi_escrow_double_claim_panics() {
         let e = Env::default();
         let (depositor, beneficiary, client) = setup_test(&e);
-        client.create_escrow(&depositor, &beneficiary, &1000);
-        
-        // Beneficiary tries to refund themselves (unauthorized)
-        client.refund_escrow(&beneficiary);
+        let recipients = vec![&e, SplitRecipient { address: beneficiary.clone(), share_bps: 10000 }];
+
+        let id = client.create_multi_escrow(&depositor, &recipients, &1000, &1000, &0);
+        client.release_multi_escrow_to_claims(&depositor, &id);
+        client.claim_multi_escrow(&id, &beneficiary);
+        client.claim_multi_escrow(&id, &beneficiary);
     }
 
     #[test]
-    #[should_panic(expected = "already settled")]
-    fn test_double_release_panics() {
+    fn test_refund_multi_escrow_before_and_after_expiry() {
+        let e = Env::default();
+        let (depositor, _, client) = setup_test(&e);
+        let recipients = vec![&e, SplitRecipient { address: Address::generate(&e), share_bps: 10000 }];
+
+        let early = client.create_multi_escrow(&depositor, &recipients, &1000, &1000, &0);
+        let late = client.create_multi_escrow(&depositor, &recipients, &2000, &1000, &0);
+
+        client.refund_multi_escrow(&depositor, &early);
+        assert!(client.get_multi_escrow(&early).refunded);
+        assert_eq!(client.balance(&depositor), 8000);
+
+        e.ledger().set_sequence_number(1000);
+        client.refund_multi_escrow(&depositor, &late);
+        assert!(client.get_multi_escrow(&late).refunded);
+        assert_eq!(client.balance(&depositor), 10_000);
+    }
+
+    #[test]
+    fn test_multi_escrow_window_matches_single_escrow() {
         let e = Env::default();
         let (depositor, beneficiary, client) = setup_test(&e);
-        client.create_escrow(&depositor, &beneficiary, &1000);
-        
-        client.release_escrow(&beneficiary);
-        client.release_escrow(&beneficiary); // Panic
+        let recipients = vec![&e, SplitRecipient { address: beneficiary.clone(), share_bps: 10000 }];
+        let id = client.create_multi_escrow(&depositor, &recipients, &1000, &500, &100);
+        let record = client.get_multi_escrow(&id);
+        assert_eq!((record.expiration_ledger, record.release_after_ledger), (500, 100));
+
+        assert_eq!(
+            client.try_release_multi_escrow(&depositor, &id),
+            Err(Ok(crate::error::Error::TimelockActive.into()))
+        );
+
+        e.ledger().set_sequence_number(500);
+        assert_eq!(
+            client.try_release_multi_escrow(&depositor, &id),
+            Err(Ok(crate::error::Error::EscrowExpired.into()))
+        );
+        client.refund_multi_escrow(&depositor, &id);
+        assert_eq!(client.balance(&depositor), 10_000);
     }
 
     #[test]
-    #[should_panic(expected = "already settled")]
-    fn test_double_refund_panics() {
+    #[should_panic(expected = "Error(Contract, #52)")]
+    fn test_invalid_bps_panics() {
+        let e = Env::default();
+        let (depositor, _, client) = setup_test(&e);
+        let recipients = vec![
+            &e,
+            SplitRecipient { address: Address::generate(&e), share_bps: 9999 }
+        ];
+
+        client.create_multi_escrow(&depositor, &recipients, &1000, &1000, &0);
+    }
+
+    #[test]
+    fn test_release_escrows_batch() {
+        let e = Env::default();
+        let (admin, depositor, _, client) = setup_with_admin(&e);
+        let beneficiaries = [Address::generate(&e), Address::generate(&e), Address::generate(&e)];
+
+        let mut ids = vec![&e];
+        for beneficiary in beneficiaries.iter() {
+            ids.push_back(client.create_escrow(&depositor, beneficiary, &1000, &1000, &0));
+        }
+
+        let released = client.release_escrows(&admin, &ids, &true);
+
+        assert_eq!(released, ids);
+        for beneficiary in beneficiaries.iter() {
+            assert_eq!(client.balance(beneficiary), 1000);
+        }
+        assert_eq!(client.balance(&client.address), 0);
+    }
+
+    #[test]
+    fn test_release_escrows_skips_ineligible() {
+        let e = Env::default();
+        let (admin, depositor, beneficiary, client) = setup_with_admin(&e);
+        e.ledger().set_sequence_number(10);
+
+        let ready = client.create_escrow(&depositor, &beneficiary, &1000, &1000, &0);
+        let settled = client.create_escrow(&depositor, &beneficiary, &1000, &1000, &0);
+        let locked = client.create_escrow(&depositor, &beneficiary, &1000, &1000, &500);
+        client.refund_escrow(&depositor, &settled);
+
+        let released = client.release_escrows(&admin, &vec![&e, ready, settled, locked], &false);
+
+        assert_eq!(released, vec![&e, ready]);
+        assert_eq!(client.balance(&beneficiary), 1000);
+        assert!(!client.get_escrow(&locked).released);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #32)")]
+    fn test_release_escrows_strict_fails_on_timelocked() {
+        let e = Env::default();
+        let (admin, depositor, beneficiary, client) = setup_with_admin(&e);
+
+        let ready = client.create_escrow(&depositor, &beneficiary, &1000, &1000, &0);
+        let locked = client.create_escrow(&depositor, &beneficiary, &1000, &1000, &500);
+
+        client.release_escrows(&admin, &vec![&e, ready, locked], &true);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #34)")]
+    fn test_binding_escrow_premature_refund_panics() {
+        let e = Env::default();
+        let (depositor, beneficiary, client) = setup_test(&e);
+
+        let id = client.create_binding_escrow(&depositor, &beneficiary, &1000, &500, &0);
+        e.ledger().set_sequence_number(499);
+        client.refund_escrow(&depositor, &id);
+    }
+
+    #[test]
+    fn test_binding_escrow_refund_after_expiration() {
         let e = Env::default();
         let (depositor, beneficiary, client) = setup_test(&e);
-        client.create_escrow(&depositor, &beneficiary, &1000);
-        
-        client.refund_escrow(&depositor);
-        client.refund_escrow(&depositor); // Panic
+
+        let id = client.create_binding_escrow(&depositor, &beneficiary, &1000, &500, &0);
+        e.ledger().set_sequence_number(500);
+        client.refund_escrow(&depositor, &id);
+
+        assert!(client.get_escrow(&id).refunded);
+        assert_eq!(client.balance(&depositor), 10_000);
     }
 
     #[test]
-    #[should_panic(expected = "already settled")]
-    fn test_release_after_refund_panics() {
+    fn test_binding_escrow_mutual_cancel() {
         let e = Env::default();
         let (depositor, beneficiary, client) = setup_test(&e);
-        client.create_escrow(&depositor, &beneficiary, &1000);
-        
-        client.refund_escrow(&depositor);
-        client.release_escrow(&beneficiary); // Panic
+
+        let id = client.create_binding_escrow(&depositor, &beneficiary, &1000, &500, &0);
+        client.cancel_escrow(&id);
+
+        assert!(client.get_escrow(&id).refunded);
+        assert_eq!(client.balance(&depositor), 10_000);
     }
 
-    use crate::splitter::SplitRecipient;
-use soroban_sdk::{vec, Vec};
-
-// ... inside your test module ...
-
-#[test]
-fn test_create_multi_escrow() {
-    let e = Env::default();
-    let (depositor, _, client) = setup_test(&e); // Assuming setup_test exists in your test file
-    let recipient1 = Address::generate(&e);
-    let recipient2 = Address::generate(&e);
-    
-    let recipients = vec![
-        &e,
-        SplitRecipient { address: recipient1, share_bps: 6000 },
-        SplitRecipient { address: recipient2, share_bps: 4000 },
-    ];
-
-    // Assuming you have a wrapper client or call the function directly:
-    // create_multi_escrow(&e, depositor.clone(), recipients, 1000);
-    // Add assertions for balance deductions and record creation
-}
+    #[test]
+    fn test_find_escrow() {
+        let e = Env::default();
+        let (depositor, beneficiary, client) = setup_test(&e);
 
-#[test]
-fn test_release_multi_escrow_3_recipients() {
-    let e = Env::default();
-    // Setup environment and balances...
-    let depositor = Address::generate(&e);
-    let r1 = Address::generate(&e);
-    let r2 = Address::generate(&e);
-    let r3 = Address::generate(&e);
-    
-    let recipients = vec![
-        &e,
-        SplitRecipient { address: r1, share_bps: 5000 },
-        SplitRecipient { address: r2, share_bps: 3000 },
-        SplitRecipient { address: r3, share_bps: 2000 },
-    ];
-    
-    // Test logic: Create escrow for 1000. Release.
-    // Verify balances: r1 = 500, r2 = 300, r3 = 200.
-}
+        assert_eq!(client.find_escrow(&1), None);
 
-#[test]
-fn test_refund_multi_escrow() {
-    let e = Env::default();
-    // Setup environment...
-    let depositor = Address::generate(&e);
-    let recipients = vec![&e, SplitRecipient { address: Address::generate(&e), share_bps: 10000 }];
-    
-    // Create escrow for 1000. Refund.
-    // Verify depositor gets 1000 back and record is refunded.
-}
+        let id = client.create_escrow(&depositor, &beneficiary, &1000, &1000, &0);
+        assert_eq!(client.find_escrow(&id), Some(client.get_escrow(&id)));
+    }
+
+    #[test]
+    fn test_change_escrow_beneficiary() {
+        let e = Env::default();
+        let (depositor, beneficiary, client) = setup_test(&e);
+        let corrected = Address::generate(&e);
+
+        let id = client.create_escrow(&depositor, &beneficiary, &1000, &1000, &100);
+        client.change_escrow_beneficiary(&id, &corrected);
+
+        e.ledger().set_sequence_number(100);
+        client.release_escrow(&corrected, &id);
+
+        assert_eq!(client.balance(&corrected), 1000);
+        assert_eq!(client.balance(&beneficiary), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #33)")]
+    fn test_change_escrow_beneficiary_after_timelock_panics() {
+        let e = Env::default();
+        let (depositor, beneficiary, client) = setup_test(&e);
+
+        let id = client.create_escrow(&depositor, &beneficiary, &1000, &1000, &100);
+        e.ledger().set_sequence_number(100);
+        client.change_escrow_beneficiary(&id, &Address::generate(&e));
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #35)")]
+    fn test_self_escrow_panics() {
+        let e = Env::default();
+        let (depositor, _, client) = setup_test(&e);
+
+        client.create_escrow(&depositor, &depositor, &1000, &1000, &0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #35)")]
+    fn test_multi_escrow_with_depositor_recipient_panics() {
+        let e = Env::default();
+        let (depositor, beneficiary, client) = setup_test(&e);
+        let recipients = vec![
+            &e,
+            SplitRecipient { address: beneficiary, share_bps: 5000 },
+            SplitRecipient { address: depositor.clone(), share_bps: 5000 },
+        ];
 
-#[test]
-#[should_panic(expected = "total bps must equal 10000")]
-fn test_invalid_bps_panics() {
-    let e = Env::default();
-    let depositor = Address::generate(&e);
-    let recipients = vec![
-        &e,
-        SplitRecipient { address: Address::generate(&e), share_bps: 9999 }
-    ];
-
-    crate::escrow::create_multi_escrow(&e, depositor, recipients, 1000);
+        client.create_multi_escrow(&depositor, &recipients, &1000, &1000, &0);
+    }
+
+    #[test]
+    fn test_escrow_status_active_and_expired() {
+        let e = Env::default();
+        let (depositor, beneficiary, client) = setup_test(&e);
+        let id = client.create_escrow(&depositor, &beneficiary, &1000, &500, &0);
+
+        assert_eq!(client.escrow_status(&id), (1000, Symbol::new(&e, "active")));
+
+        client.refund_escrow_partial(&id, &400);
+        assert_eq!(client.escrow_status(&id), (600, Symbol::new(&e, "active")));
+
+        e.ledger().set_sequence_number(500);
+        assert_eq!(client.escrow_status(&id), (600, Symbol::new(&e, "expired")));
+    }
+
+    #[test]
+    fn test_claim_expired_refunds_and_blocks_release() {
+        let e = Env::default();
+        let (depositor, beneficiary, client) = setup_test(&e);
+        let id = client.create_binding_escrow(&depositor, &beneficiary, &1000, &500, &0);
+
+        assert_eq!(
+            client.try_claim_expired(&id),
+            Err(Ok(crate::error::Error::EscrowNotExpired.into()))
+        );
+
+        e.ledger().set_sequence_number(500);
+        assert_eq!(
+            client.try_release_escrow(&beneficiary, &id),
+            Err(Ok(crate::error::Error::EscrowExpired.into()))
+        );
+
+        client.claim_expired(&id);
+        assert!(client.get_escrow(&id).refunded);
+        assert_eq!(client.balance(&depositor), 10_000);
+        assert_eq!(client.escrow_status(&id), (0, Symbol::new(&e, "refunded")));
+    }
+
+    #[test]
+    fn test_release_authorization_depends_on_caller() {
+        let e = Env::default();
+        let (depositor, beneficiary, client) = setup_test(&e);
+        let stranger = Address::generate(&e);
+
+        let id = client.create_escrow(&depositor, &beneficiary, &1000, &1000, &100);
+        assert_eq!(
+            client.try_release_escrow(&stranger, &id),
+            Err(Ok(crate::error::Error::Unauthorized.into()))
+        );
+        // The beneficiary waits out the timelock, the depositor does not
+        assert_eq!(
+            client.try_release_escrow(&beneficiary, &id),
+            Err(Ok(crate::error::Error::TimelockActive.into()))
+        );
+        client.release_escrow(&depositor, &id);
+        assert_eq!(client.balance(&beneficiary), 1000);
+
+        let id = client.create_escrow(&depositor, &beneficiary, &1000, &1000, &0);
+        assert_eq!(
+            client.try_refund_escrow(&beneficiary, &id),
+            Err(Ok(crate::error::Error::Unauthorized.into()))
+        );
+    }
+
+    #[test]
+    fn test_arbiter_settles_either_way() {
+        let e = Env::default();
+        let (depositor, beneficiary, client) = setup_test(&e);
+        let arbiter = Address::generate(&e);
+
+        assert_eq!(
+            client.try_create_escrow_with_arbiter(&depositor, &beneficiary, &beneficiary, &1000, &1000, &100),
+            Err(Ok(crate::error::Error::InvalidArbiter.into()))
+        );
+
+        let released = client.create_escrow_with_arbiter(&depositor, &beneficiary, &arbiter, &1000, &1000, &100);
+        assert_eq!(client.get_escrow(&released).arbiter, Some(arbiter.clone()));
+        client.release_escrow(&arbiter, &released);
+        assert_eq!(client.balance(&beneficiary), 1000);
+
+        let refunded = client.create_escrow_with_arbiter(&depositor, &beneficiary, &arbiter, &1000, &1000, &100);
+        client.refund_escrow(&arbiter, &refunded);
+        assert!(client.get_escrow(&refunded).refunded);
+        assert_eq!(client.balance(&depositor), 9000);
+    }
+
+    #[test]
+    fn test_escrow_status_released() {
+        let e = Env::default();
+        let (depositor, beneficiary, client) = setup_test(&e);
+        let id = client.create_escrow(&depositor, &beneficiary, &1000, &500, &0);
+
+        client.release_escrow(&beneficiary, &id);
+        assert_eq!(client.escrow_status(&id), (0, Symbol::new(&e, "released")));
+    }
+
+    #[test]
+    fn test_escrow_status_refunded() {
+        let e = Env::default();
+        let (depositor, beneficiary, client) = setup_test(&e);
+        let id = client.create_escrow(&depositor, &beneficiary, &1000, &500, &0);
+
+        client.refund_escrow(&depositor, &id);
+        assert_eq!(client.escrow_status(&id), (0, Symbol::new(&e, "refunded")));
+    }
+
+    #[test]
+    fn test_settle_external_pays_in_external_token() {
+        let e = Env::default();
+        let (admin, depositor, beneficiary, client) = setup_with_admin(&e);
+        let id = client.create_escrow(&depositor, &beneficiary, &1000, &500, &0);
+
+        let asset = e.register_stellar_asset_contract_v2(admin.clone());
+        token::StellarAssetClient::new(&e, &asset.address()).mint(&client.address, &1000);
+
+        client.settle_external(&id, &asset.address());
+
+        let external = token::Client::new(&e, &asset.address());
+        assert_eq!(external.balance(&beneficiary), 1000);
+        assert_eq!(external.balance(&client.address), 0);
+        assert_eq!(client.balance(&beneficiary), 0);
+        assert_eq!(client.balance(&client.address), 0);
+        assert_eq!(client.total_supply(), 9000);
+        assert!(client.get_escrow(&id).released);
+    }
+
+    #[test]
+    fn test_settle_external_cannot_drain_other_escrows() {
+        let e = Env::default();
+        let (admin, depositor, beneficiary, client) = setup_with_admin(&e);
+        let usdc = e.register_stellar_asset_contract_v2(admin.clone());
+        let other_depositor = Address::generate(&e);
+        let other_beneficiary = Address::generate(&e);
+        token::StellarAssetClient::new(&e, &usdc.address()).mint(&other_depositor, &1000);
+
+        // Another user's USDC escrow is the only USDC the contract holds
+        let usdc_escrow = client.create_token_escrow(
+            &other_depositor, &other_beneficiary, &usdc.address(), &1000, &500, &0,
+        );
+        let vtx_escrow = client.create_escrow(&depositor, &beneficiary, &1000, &500, &0);
+
+        assert_eq!(
+            client.try_settle_external(&vtx_escrow, &usdc.address()),
+            Err(Ok(crate::error::FeatureError::SettlementExceedsSurplus.into()))
+        );
+        let external = token::Client::new(&e, &usdc.address());
+        assert_eq!(external.balance(&beneficiary), 0);
+        assert_eq!(external.balance(&client.address), 1000);
+        assert!(!client.get_escrow(&vtx_escrow).released);
+
+        client.release_escrow(&other_depositor, &usdc_escrow);
+        assert_eq!(external.balance(&other_beneficiary), 1000);
+    }
+
+    #[test]
+    fn test_settle_external_charges_escrow_fee() {
+        let e = Env::default();
+        let (admin, depositor, beneficiary, client) = setup_with_admin(&e);
+        client.set_fee_config(
+            &admin,
+            &FeeConfig { escrow_bps: 100, recurring_bps: 0, dispute_fee: 0 },
+        );
+        let id = client.create_escrow(&depositor, &beneficiary, &1000, &500, &0);
+
+        let asset = e.register_stellar_asset_contract_v2(admin.clone());
+        token::StellarAssetClient::new(&e, &asset.address()).mint(&client.address, &990);
+
+        client.settle_external(&id, &asset.address());
+
+        let external = token::Client::new(&e, &asset.address());
+        assert_eq!(external.balance(&beneficiary), 990);
+        assert_eq!(client.balance(&admin), 10);
+        assert_eq!(client.balance(&client.address), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #37)")]
+    fn test_settle_external_with_own_token_panics() {
+        let e = Env::default();
+        let (depositor, beneficiary, client) = setup_test(&e);
+        let id = client.create_escrow(&depositor, &beneficiary, &1000, &500, &0);
+
+        client.settle_external(&id, &client.address);
+    }
+
+    #[test]
+    fn test_token_escrow_holds_and_pays_external_token() {
+        let e = Env::default();
+        let (admin, depositor, beneficiary, client) = setup_with_admin(&e);
+        let asset = e.register_stellar_asset_contract_v2(admin.clone());
+        token::StellarAssetClient::new(&e, &asset.address()).mint(&depositor, &1000);
+        let external = token::Client::new(&e, &asset.address());
+
+        let paid = client.create_token_escrow(&depositor, &beneficiary, &asset.address(), &600, &500, &0);
+        let refunded = client.create_token_escrow(&depositor, &beneficiary, &asset.address(), &400, &500, &0);
+        assert_eq!(client.get_escrow(&paid).token, Some(asset.address()));
+        assert_eq!(external.balance(&client.address), 1000);
+        assert_eq!(client.balance(&depositor), 10_000);
+        assert_eq!(client.locked_balance(&depositor), 0);
+
+        client.release_escrow(&beneficiary, &paid);
+        client.refund_escrow(&depositor, &refunded);
+
+        assert_eq!(external.balance(&beneficiary), 600);
+        assert_eq!(external.balance(&depositor), 400);
+        assert_eq!(external.balance(&client.address), 0);
+        assert_eq!(client.balance(&client.address), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #37)")]
+    fn test_token_escrow_with_own_token_panics() {
+        let e = Env::default();
+        let (depositor, beneficiary, client) = setup_test(&e);
+
+        client.create_token_escrow(&depositor, &beneficiary, &client.address, &1000, &500, &0);
+    }
+
+    #[test]
+    fn test_create_escrows_reject_non_positive_amounts() {
+        let e = Env::default();
+        let (admin, depositor, beneficiary, client) = setup_with_admin(&e);
+        let arbiter = Address::generate(&e);
+        let asset = e.register_stellar_asset_contract_v2(admin);
+        let recipients = vec![&e, SplitRecipient { address: beneficiary.clone(), share_bps: 10000 }];
+        let invalid = Err(Ok(crate::error::Error::InvalidAmount.into()));
+
+        for amount in [-1000i128, 0] {
+            assert_eq!(client.try_create_escrow(&depositor, &beneficiary, &amount, &1000, &0), invalid);
+            assert_eq!(client.try_create_binding_escrow(&depositor, &beneficiary, &amount, &1000, &0), invalid);
+            assert_eq!(client.try_create_escrow_ttl(&depositor, &beneficiary, &amount, &0, &1000), invalid);
+            assert_eq!(
+                client.try_create_escrow_with_arbiter(&depositor, &beneficiary, &arbiter, &amount, &1000, &0),
+                invalid
+            );
+            assert_eq!(
+                client.try_create_token_escrow(&depositor, &beneficiary, &asset.address(), &amount, &1000, &0),
+                invalid
+            );
+            assert_eq!(client.try_create_multi_escrow(&depositor, &recipients, &amount, &1000, &0), invalid);
+            assert_eq!(
+                client.try_create_vesting_multi_escrow(&depositor, &recipients, &amount, &1000, &vec![&e, 0u32]),
+                invalid
+            );
+        }
+        assert_eq!(client.balance(&depositor), 10_000);
+        assert_eq!(client.balance(&client.address), 0);
+    }
+
+    #[test]
+    fn test_create_escrow_ttl_uses_offsets() {
+        let e = Env::default();
+        let (depositor, beneficiary, client) = setup_test(&e);
+        e.ledger().set_sequence_number(1000);
+
+        let id = client.create_escrow_ttl(&depositor, &beneficiary, &500, &100, &250);
+
+        let escrow = client.get_escrow(&id);
+        assert_eq!(escrow.release_after_ledger, 1100);
+        assert_eq!(escrow.expiration_ledger, 1250);
+    }
+
+    #[test]
+    fn test_create_escrow_ttl_offset_overflow_rejected() {
+        let e = Env::default();
+        let (depositor, beneficiary, client) = setup_test(&e);
+        e.ledger().set_sequence_number(1000);
+
+        assert_eq!(
+            client.try_create_escrow_ttl(&depositor, &beneficiary, &500, &100, &u32::MAX),
+            Err(Ok(crate::error::FeatureError::InvalidExpiration.into()))
+        );
+        assert_eq!(client.balance(&depositor), 10_000);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #36)")]
+    fn test_create_escrow_ttl_release_after_expiry_panics() {
+        let e = Env::default();
+        let (depositor, beneficiary, client) = setup_test(&e);
+
+        client.create_escrow_ttl(&depositor, &beneficiary, &500, &300, &250);
+    }
+
+    #[test]
+    fn test_early_release_after_depositor_approval() {
+        let e = Env::default();
+        let (depositor, beneficiary, client) = setup_test(&e);
+        let id = client.create_escrow(&depositor, &beneficiary, &1000, &5000, &1000);
+
+        assert!(client.try_release_escrow(&beneficiary, &id).is_err());
+
+        client.approve_early_release(&id);
+        client.release_escrow(&beneficiary, &id);
+
+        assert_eq!(client.balance(&beneficiary), 1000);
+        assert!(client.get_escrow(&id).released);
+    }
+
+    #[test]
+    fn test_release_multi_recipient_staggered() {
+        let e = Env::default();
+        let (depositor, _, client) = setup_test(&e);
+        let r1 = Address::generate(&e);
+        let r2 = Address::generate(&e);
+        let r3 = Address::generate(&e);
+
+        let recipients = vec![
+            &e,
+            SplitRecipient { address: r1.clone(), share_bps: 5000 },
+            SplitRecipient { address: r2.clone(), share_bps: 3000 },
+            SplitRecipient { address: r3.clone(), share_bps: 2000 },
+        ];
+        let release_after = vec![&e, 100u32, 200, 300];
+        let id = client.create_vesting_multi_escrow(&depositor, &recipients, &1000, &250, &release_after);
+
+        e.ledger().set_sequence_number(100);
+        client.release_multi_recipient(&id, &0);
+        assert!(client.try_release_multi_recipient(&id, &1).is_err());
+
+        e.ledger().set_sequence_number(200);
+        client.release_multi_recipient(&id, &1);
+
+        assert_eq!(client.balance(&r1), 500);
+        assert_eq!(client.balance(&r2), 300);
+        assert_eq!(client.balance(&r3), 0);
+        assert_eq!(client.balance(&client.address), 200);
+        assert!(!client.get_multi_escrow(&id).released);
+
+        // Refund only returns what has not vested out yet
+        e.ledger().set_sequence_number(250);
+        client.refund_multi_escrow(&depositor, &id);
+        assert_eq!(client.balance(&depositor), 9200);
+    }
+
+    #[test]
+    fn test_release_multi_recipients_pays_only_selected() {
+        let e = Env::default();
+        let (depositor, _, client) = setup_test(&e);
+        let vendor = Address::generate(&e);
+        let venue = Address::generate(&e);
+        let recipients = vec![
+            &e,
+            SplitRecipient { address: vendor.clone(), share_bps: 7000 },
+            SplitRecipient { address: venue.clone(), share_bps: 3000 },
+        ];
+        let id = client.create_multi_escrow(&depositor, &recipients, &1000, &1000, &0);
+
+        client.release_multi_recipients(&depositor, &id, &vec![&e, 0u32]);
+
+        assert_eq!(client.balance(&vendor), 700);
+        assert_eq!(client.balance(&venue), 0);
+        assert_eq!(client.multi_escrow_remaining(&id), 300);
+        let record = client.get_multi_escrow(&id);
+        assert_eq!(record.paid, vec![&e, 0u32]);
+        assert!(!record.released);
+
+        // The undelivered share goes back to the depositor on expiry
+        e.ledger().set_sequence_number(1000);
+        client.refund_multi_escrow(&depositor, &id);
+        assert_eq!(client.balance(&depositor), 9300);
+        assert_eq!(client.multi_escrow_remaining(&id), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #41)")]
+    fn test_release_multi_recipients_twice_panics() {
+        let e = Env::default();
+        let (depositor, beneficiary, client) = setup_test(&e);
+        let recipients = vec![
+            &e,
+            SplitRecipient { address: beneficiary, share_bps: 5000 },
+            SplitRecipient { address: Address::generate(&e), share_bps: 5000 },
+        ];
+        let id = client.create_multi_escrow(&depositor, &recipients, &1000, &1000, &0);
+
+        client.release_multi_recipients(&depositor, &id, &vec![&e, 0u32, 0u32]);
+    }
+
+    #[test]
+    fn test_release_multi_recipient_last_completes_escrow() {
+        let e = Env::default();
+        let (depositor, beneficiary, client) = setup_test(&e);
+        let recipients = vec![&e, SplitRecipient { address: beneficiary.clone(), share_bps: 10000 }];
+
+        let id = client.create_vesting_multi_escrow(&depositor, &recipients, &1000, &1000, &vec![&e, 0u32]);
+        client.release_multi_recipient(&id, &0);
+
+        assert!(client.get_multi_escrow(&id).released);
+        assert_eq!(client.balance(&beneficiary), 1000);
+    }
+
+    #[test]
+    fn test_active_escrow_count() {
+        let e = Env::default();
+        let (depositor, beneficiary, client) = setup_test(&e);
+
+        let first = client.create_escrow(&depositor, &beneficiary, &100, &1000, &0);
+        let second = client.create_escrow(&depositor, &beneficiary, &100, &1000, &0);
+        client.create_escrow(&depositor, &beneficiary, &100, &1000, &0);
+        assert_eq!(client.active_escrow_count(), 3);
+
+        client.release_escrow(&beneficiary, &first);
+        assert_eq!(client.active_escrow_count(), 2);
+
+        client.refund_escrow_partial(&second, &100);
+        assert_eq!(client.active_escrow_count(), 1);
+    }
+
+    #[test]
+    fn test_release_escrow_split_three_recipients() {
+        let e = Env::default();
+        let (depositor, beneficiary, client) = setup_test(&e);
+        let partner = Address::generate(&e);
+        let agency = Address::generate(&e);
+        let id = client.create_escrow(&depositor, &beneficiary, &1001, &1000, &0);
+
+        let recipients = vec![
+            &e,
+            SplitRecipient { address: beneficiary.clone(), share_bps: 5000 },
+            SplitRecipient { address: partner.clone(), share_bps: 3000 },
+            SplitRecipient { address: agency.clone(), share_bps: 2000 },
+        ];
+        client.release_escrow_split(&id, &recipients);
+
+        // 500 + 300 + 201 (dust to the last)
+        assert_eq!(client.balance(&beneficiary), 500);
+        assert_eq!(client.balance(&partner), 300);
+        assert_eq!(client.balance(&agency), 201);
+        assert_eq!(client.balance(&client.address), 0);
+        assert!(client.get_escrow(&id).released);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #52)")]
+    fn test_release_escrow_split_invalid_bps_panics() {
+        let e = Env::default();
+        let (depositor, beneficiary, client) = setup_test(&e);
+        let id = client.create_escrow(&depositor, &beneficiary, &1000, &1000, &0);
+
+        let recipients = vec![&e, SplitRecipient { address: beneficiary, share_bps: 9000 }];
+        client.release_escrow_split(&id, &recipients);
+    }
 }
-}
\ No newline at end of file
@@ -0,0 +1,48 @@
+use crate::admin::read_admin;
+use crate::balance::read_total_supply;
+use crate::fee::{read_fee_config, read_transfer_fee};
+use crate::metadata::{read_metadata, TokenMetadata};
+use crate::storage_types::{DataKey, FeeConfig, TransferFee};
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+/// Contract-wide settings a migration script needs to carry across an upgrade.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ExportedConfig {
+    pub admin: Address,
+    pub metadata: TokenMetadata,
+    pub fee_config: FeeConfig,
+    pub transfer_fee: TransferFee,
+    pub total_supply: i128,
+}
+
+/// Ids below `count_key` that still have a record under `record_key`.
+/// Plain reads only: nothing here extends a TTL.
+fn existing_ids(e: &Env, count_key: DataKey, record_key: fn(u32) -> DataKey) -> Vec<u32> {
+    let count: u32 = e.storage().instance().get(&count_key).unwrap_or(0);
+    let mut ids = Vec::new(e);
+    for id in 1..=count {
+        if e.storage().persistent().has(&record_key(id)) {
+            ids.push_back(id);
+        }
+    }
+    ids
+}
+
+pub fn export_escrow_ids(e: &Env) -> Vec<u32> {
+    existing_ids(e, DataKey::EscrowCount, DataKey::Escrow)
+}
+
+pub fn export_split_ids(e: &Env) -> Vec<u32> {
+    existing_ids(e, DataKey::SplitCount, DataKey::Split)
+}
+
+pub fn export_config(e: &Env) -> ExportedConfig {
+    ExportedConfig {
+        admin: read_admin(e),
+        metadata: read_metadata(e),
+        fee_config: read_fee_config(e),
+        transfer_fee: read_transfer_fee(e),
+        total_supply: read_total_supply(e),
+    }
+}
@@ -0,0 +1,61 @@
+#[cfg(test)]
+mod export_tests {
+    use crate::splitter::SplitRecipient;
+    use crate::storage_types::{FeeConfig, TransferFee};
+    use crate::{VeritixToken, VeritixTokenClient};
+    use soroban_sdk::{testutils::Address as _, vec, Address, Env, String};
+
+    #[test]
+    fn test_export_reflects_written_state() {
+        let e = Env::default();
+        e.mock_all_auths();
+
+        let contract_id = e.register_contract(None, VeritixToken);
+        let client = VeritixTokenClient::new(&e, &contract_id);
+
+        let admin = Address::generate(&e);
+        let user = Address::generate(&e);
+        let other = Address::generate(&e);
+
+        client.initialize(
+            &admin,
+            &String::from_str(&e, "Veritix"),
+            &String::from_str(&e, "VTX"),
+            &7u32,
+        );
+        client.mint(&admin, &user, &10_000i128);
+
+        let fee_config = FeeConfig {
+            escrow_bps: 250,
+            recurring_bps: 100,
+            dispute_fee: 50,
+        };
+        let transfer_fee = TransferFee {
+            bps: 100,
+            collector: Some(admin.clone()),
+            reflection_bps: 0,
+        };
+        client.set_fee_config(&admin, &fee_config);
+        client.set_transfer_fee(&admin, &transfer_fee);
+
+        assert_eq!(client.export_escrow_ids().len(), 0);
+        assert_eq!(client.export_split_ids().len(), 0);
+
+        let first = client.create_escrow(&user, &other, &100, &1000, &0);
+        let second = client.create_escrow(&user, &other, &200, &1000, &0);
+        let recipients = vec![&e, SplitRecipient { address: other.clone(), share_bps: 10000 }];
+        let split = client.create_split(&user, &recipients, &300, &None);
+
+        assert_eq!(client.export_escrow_ids(), vec![&e, first, second]);
+        assert_eq!(client.export_split_ids(), vec![&e, split]);
+
+        let config = client.export_config();
+        assert_eq!(config.admin, admin);
+        assert_eq!(config.metadata.name, String::from_str(&e, "Veritix"));
+        assert_eq!(config.metadata.symbol, String::from_str(&e, "VTX"));
+        assert_eq!(config.metadata.decimal, 7);
+        assert_eq!(config.fee_config, fee_config);
+        assert_eq!(config.transfer_fee, transfer_fee);
+        assert_eq!(config.total_supply, 10_000);
+    }
+}
@@ -0,0 +1,116 @@
+use crate::admin::read_admin;
+use crate::balance::{decrease_supply, receive_balance};
+use crate::error::{Error, FeatureError};
+use crate::reflection::reflect;
+use crate::storage_types::{DataKey, FeeConfig, TransferFee};
+use soroban_sdk::{panic_with_error, symbol_short, Address, Env};
+
+/// Highest transfer fee the admin can set: 10% of every transfer.
+pub const MAX_TRANSFER_FEE_BPS: u32 = 1000;
+
+/// Returns the stored fee schedule, or an all-zero schedule if none is set.
+pub fn read_fee_config(e: &Env) -> FeeConfig {
+    e.storage()
+        .instance()
+        .get(&DataKey::FeeConfig)
+        .unwrap_or(FeeConfig {
+            escrow_bps: 0,
+            recurring_bps: 0,
+            dispute_fee: 0,
+        })
+}
+
+/// Stores the fee schedule. Panics on out-of-range values.
+pub fn write_fee_config(e: &Env, config: &FeeConfig) {
+    if config.escrow_bps > 10000 || config.recurring_bps > 10000 {
+        panic_with_error!(e, Error::InvalidBps);
+    }
+    if config.dispute_fee < 0 {
+        panic_with_error!(e, Error::InvalidAmount);
+    }
+    e.storage().instance().set(&DataKey::FeeConfig, config);
+}
+
+/// Returns the transfer fee, or a zero fee if none is set.
+pub fn read_transfer_fee(e: &Env) -> TransferFee {
+    e.storage()
+        .instance()
+        .get(&DataKey::TransferFee)
+        .unwrap_or(TransferFee {
+            bps: 0,
+            collector: None,
+            reflection_bps: 0,
+        })
+}
+
+/// Stores the transfer fee. Panics on out-of-range values.
+pub fn write_transfer_fee(e: &Env, fee: &TransferFee) {
+    if fee.bps > MAX_TRANSFER_FEE_BPS {
+        panic_with_error!(e, FeatureError::TransferFeeTooHigh);
+    }
+    if fee.reflection_bps > 10000 {
+        panic_with_error!(e, Error::InvalidBps);
+    }
+    e.storage().instance().set(&DataKey::TransferFee, fee);
+}
+
+/// `(fee, net_to_beneficiary)` for releasing an escrow of `amount`.
+pub fn quote_escrow_release(e: &Env, amount: i128) -> (i128, i128) {
+    let fee = amount * read_fee_config(e).escrow_bps as i128 / 10000;
+    (fee, amount - fee)
+}
+
+/// `(fee, net_to_recipient)` for a `transfer`/`transfer_from` of `amount`.
+pub fn quote_transfer(e: &Env, amount: i128) -> (i128, i128) {
+    let fee = amount * read_transfer_fee(e).bps as i128 / 10000;
+    (fee, amount - fee)
+}
+
+/// Pays the escrow fee on `amount` to the admin, who administers the fee
+/// schedule. The contract must already have been debited the full amount;
+/// the returned fee is what the beneficiary should not receive.
+pub fn take_escrow_fee(e: &Env, amount: i128) -> i128 {
+    let (fee, _) = quote_escrow_release(e, amount);
+    if fee > 0 {
+        let admin = read_admin(e);
+        receive_balance(e, admin.clone(), fee);
+        e.events().publish(
+            (symbol_short!("transfer"), e.current_contract_address(), admin),
+            fee
+        );
+    }
+    fee
+}
+
+/// Routes the transfer fee on `amount`: the reflection share to holders,
+/// the rest to the collector, or burned. The sender must already have been
+/// debited the full `amount`; the returned fee is what the recipient should
+/// not receive.
+pub fn take_transfer_fee(e: &Env, from: &Address, amount: i128) -> i128 {
+    let config = read_transfer_fee(e);
+    let (fee, _) = quote_transfer(e, amount);
+    if fee == 0 {
+        return 0;
+    }
+
+    let mut remainder = fee;
+    let reflected = fee * config.reflection_bps as i128 / 10000;
+    if reflected > 0 && reflect(e, reflected, amount) {
+        remainder -= reflected;
+    }
+    if remainder == 0 {
+        return fee;
+    }
+
+    match config.collector {
+        Some(collector) => {
+            receive_balance(e, collector.clone(), remainder);
+            e.events().publish((symbol_short!("transfer"), from.clone(), collector), remainder);
+        }
+        None => {
+            decrease_supply(e, remainder);
+            e.events().publish((symbol_short!("burn"), from.clone()), remainder);
+        }
+    }
+    fee
+}
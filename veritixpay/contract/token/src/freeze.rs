@@ -1,19 +1,141 @@
+use crate::error::FeatureError;
 use crate::storage_types::DataKey;
-use soroban_sdk::{Address, Env};
+use soroban_sdk::{contracttype, panic_with_error, Address, Env, Symbol, Vec};
 
-pub fn is_frozen(e: &Env, addr: &Address) -> bool {
+/// Largest target list accepted by `freeze_many` / `unfreeze_many`.
+pub const MAX_FREEZE_BATCH: u32 = 20;
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FreezeMode {
+    /// Blocks sending, burning and spending; the account can still receive.
+    Outbound,
+    /// Also blocks receiving, for full sanctions compliance.
+    Full,
+}
+
+pub fn read_freeze_mode(e: &Env, addr: &Address) -> Option<FreezeMode> {
     e.storage()
         .persistent()
         .get(&DataKey::Freeze(addr.clone()))
+}
+
+/// True for any freeze mode; frozen accounts cannot move funds out.
+pub fn is_frozen(e: &Env, addr: &Address) -> bool {
+    read_freeze_mode(e, addr).is_some()
+}
+
+/// True only for `FreezeMode::Full`; such accounts cannot receive funds.
+pub fn is_inbound_frozen(e: &Env, addr: &Address) -> bool {
+    read_freeze_mode(e, addr) == Some(FreezeMode::Full)
+}
+
+/// False when `receive_balance` would reject the address.
+pub fn can_receive(e: &Env, addr: &Address) -> bool {
+    !is_inbound_frozen(e, addr) && !is_blacklisted(e, addr)
+}
+
+/// Every currently frozen address, in the order it was first frozen.
+pub fn read_frozen_accounts(e: &Env) -> Vec<Address> {
+    e.storage()
+        .persistent()
+        .get(&DataKey::FrozenAccounts)
+        .unwrap_or(Vec::new(e))
+}
+
+/// Caller is responsible for admin authorization (see `check_admin`).
+pub fn freeze_account(e: &Env, target: Address, mode: FreezeMode) {
+    let mut frozen = read_frozen_accounts(e);
+    if !frozen.contains(&target) {
+        frozen.push_back(target.clone());
+        e.storage().persistent().set(&DataKey::FrozenAccounts, &frozen);
+    }
+    e.storage().persistent().set(&DataKey::Freeze(target.clone()), &mode);
+
+    e.events().publish(
+        (Symbol::new(e, "freeze"), Symbol::new(e, "frozen"), target),
+        mode
+    );
+}
+
+pub fn unfreeze_account(e: &Env, target: Address) {
+    let mut frozen = read_frozen_accounts(e);
+    if let Some(index) = frozen.first_index_of(&target) {
+        frozen.remove(index);
+        e.storage().persistent().set(&DataKey::FrozenAccounts, &frozen);
+    }
+    e.storage().persistent().remove(&DataKey::Freeze(target.clone()));
+
+    e.events().publish(
+        (Symbol::new(e, "freeze"), Symbol::new(e, "unfrozen"), target),
+        ()
+    );
+}
+
+fn validate_batch(targets: &Vec<Address>) {
+    if targets.is_empty() {
+        panic_with_error!(targets.env(), FeatureError::EmptyBatch);
+    }
+    if targets.len() > MAX_FREEZE_BATCH {
+        panic_with_error!(targets.env(), FeatureError::BatchTooLarge);
+    }
+}
+
+/// Outbound-freezes every target, e.g. from a single sanctions update.
+/// Caller is responsible for admin authorization (see `check_admin`).
+pub fn freeze_many(e: &Env, targets: Vec<Address>) {
+    validate_batch(&targets);
+    for target in targets.iter() {
+        freeze_account(e, target, FreezeMode::Outbound);
+    }
+}
+
+/// Caller is responsible for admin authorization (see `check_admin`).
+pub fn unfreeze_many(e: &Env, targets: Vec<Address>) {
+    validate_batch(&targets);
+    for target in targets.iter() {
+        unfreeze_account(e, target);
+    }
+}
+
+/// Blacklisting is the permanent-sanctions counterpart to freezing: the
+/// address can neither send nor receive, nor be named as an escrow party.
+pub fn is_blacklisted(e: &Env, addr: &Address) -> bool {
+    e.storage()
+        .persistent()
+        .get(&DataKey::Blacklist(addr.clone()))
         .unwrap_or(false)
 }
 
-pub fn freeze_account(e: &Env, admin: Address, target: Address) {
-    admin.require_auth();
-    e.storage().persistent().set(&DataKey::Freeze(target), &true);
+pub fn require_not_blacklisted(e: &Env, addr: &Address) {
+    if is_blacklisted(e, addr) {
+        panic_with_error!(e, FeatureError::Blacklisted);
+    }
 }
 
-pub fn unfreeze_account(e: &Env, admin: Address, target: Address) {
-    admin.require_auth();
-    e.storage().persistent().set(&DataKey::Freeze(target), &false);
-}
\ No newline at end of file
+/// Panics unless `addr` may move funds out: not blacklisted and not frozen.
+pub fn require_can_send(e: &Env, addr: &Address) {
+    require_not_blacklisted(e, addr);
+    if is_frozen(e, addr) {
+        panic_with_error!(e, FeatureError::AccountFrozen);
+    }
+}
+
+/// Caller is responsible for admin authorization (see `check_admin`).
+pub fn blacklist_account(e: &Env, target: Address) {
+    e.storage().persistent().set(&DataKey::Blacklist(target.clone()), &true);
+
+    e.events().publish(
+        (Symbol::new(e, "blacklist"), Symbol::new(e, "added"), target),
+        ()
+    );
+}
+
+pub fn unblacklist_account(e: &Env, target: Address) {
+    e.storage().persistent().remove(&DataKey::Blacklist(target.clone()));
+
+    e.events().publish(
+        (Symbol::new(e, "blacklist"), Symbol::new(e, "removed"), target),
+        ()
+    );
+}
@@ -0,0 +1,285 @@
+use crate::balance::{receive_balance, spend_balance};
+use crate::error::{Error, FeatureError};
+use crate::freeze::{require_can_send, require_not_blacklisted};
+use crate::pause::require_not_paused;
+use crate::storage_types::DataKey;
+use crate::sweep;
+use soroban_sdk::{contracttype, panic_with_error, token, Address, Bytes, BytesN, Env, Symbol};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HashlockEscrow {
+    pub id: u32,
+    pub depositor: Address,
+    pub beneficiary: Address,
+    pub amount: i128,
+    pub hashlock: BytesN<32>,
+    pub timeout_ledger: u32,
+    pub preimage: Option<Bytes>,
+    pub claimed: bool,
+    pub refunded: bool,
+    pub linked_id: Option<u32>,
+    /// SEP-41 token the funds are held in; `None` for this token.
+    pub token: Option<Address>,
+}
+
+/// Locks funds that the beneficiary can claim by revealing the SHA-256
+/// preimage of `hashlock` before `timeout_ledger`.
+pub fn create_hashlock_escrow(
+    e: &Env,
+    depositor: Address,
+    beneficiary: Address,
+    amount: i128,
+    hashlock: BytesN<32>,
+    timeout_ledger: u32,
+) -> u32 {
+    lock_hashlock(e, depositor, beneficiary, None, amount, hashlock, timeout_ledger)
+}
+
+fn lock_hashlock(
+    e: &Env,
+    depositor: Address,
+    beneficiary: Address,
+    token: Option<Address>,
+    amount: i128,
+    hashlock: BytesN<32>,
+    timeout_ledger: u32,
+) -> u32 {
+    depositor.require_auth();
+    require_not_paused(e);
+    if amount <= 0 {
+        panic_with_error!(e, Error::InvalidAmount);
+    }
+    require_can_send(e, &depositor);
+    require_not_blacklisted(e, &beneficiary);
+
+    if timeout_ledger <= e.ledger().sequence() {
+        panic_with_error!(e, FeatureError::InvalidTimeout);
+    }
+
+    // 1. Move funds from the depositor to the contract itself
+    match &token {
+        Some(token_address) => {
+            token::Client::new(e, token_address).transfer(
+                &depositor,
+                &e.current_contract_address(),
+                &amount,
+            );
+            sweep::hold_in_token(e, token_address, amount);
+        }
+        None => {
+            spend_balance(e, depositor.clone(), amount);
+            receive_balance(e, e.current_contract_address(), amount);
+            sweep::hold_native(e, amount);
+        }
+    }
+
+    // 2. Increment and fetch the new Hashlock ID
+    let mut count: u32 = e.storage().instance().get(&DataKey::HashlockCount).unwrap_or(0);
+    count += 1;
+    e.storage().instance().set(&DataKey::HashlockCount, &count);
+
+    // 3. Store the record
+    let record = HashlockEscrow {
+        id: count,
+        depositor: depositor.clone(),
+        beneficiary: beneficiary.clone(),
+        amount,
+        hashlock,
+        timeout_ledger,
+        preimage: None,
+        claimed: false,
+        refunded: false,
+        linked_id: None,
+        token,
+    };
+    e.storage().persistent().set(&DataKey::Hashlock(count), &record);
+
+    // 4. Emit Event
+    e.events().publish(
+        (Symbol::new(e, "htlc"), Symbol::new(e, "created"), depositor),
+        (beneficiary, amount, timeout_ledger)
+    );
+
+    count
+}
+
+/// Pays the beneficiary if `preimage` hashes to the stored hashlock.
+/// The preimage is stored on the record so the counterparty can read it.
+pub fn claim_hashlock(e: &Env, escrow_id: u32, preimage: Bytes) {
+    let mut record = get_hashlock_escrow(e, escrow_id);
+    record.beneficiary.require_auth();
+    require_not_paused(e);
+
+    // State & Condition Validation
+    if record.claimed || record.refunded {
+        panic_with_error!(e, FeatureError::InvalidState);
+    }
+    if e.ledger().sequence() >= record.timeout_ledger {
+        panic_with_error!(e, FeatureError::TimeoutReached);
+    }
+    let hash: BytesN<32> = e.crypto().sha256(&preimage).into();
+    if hash != record.hashlock {
+        panic_with_error!(e, FeatureError::InvalidPreimage);
+    }
+
+    // Update state
+    record.claimed = true;
+    record.preimage = Some(preimage.clone());
+    e.storage().persistent().set(&DataKey::Hashlock(escrow_id), &record);
+
+    // Reveal the preimage on the other leg of a swap
+    if let Some(linked_id) = record.linked_id {
+        let mut linked = get_hashlock_escrow(e, linked_id);
+        if linked.preimage.is_none() {
+            linked.preimage = Some(preimage.clone());
+            e.storage().persistent().set(&DataKey::Hashlock(linked_id), &linked);
+        }
+    }
+
+    // Move funds from contract to beneficiary
+    pay_from_hashlock(e, &record, &record.beneficiary);
+
+    // Emit Event (carries the preimage so watchers of linked escrows can use it)
+    e.events().publish(
+        (Symbol::new(e, "htlc"), Symbol::new(e, "claimed"), escrow_id),
+        preimage
+    );
+}
+
+/// Returns the funds to the depositor once the timeout has passed.
+pub fn refund_hashlock(e: &Env, escrow_id: u32) {
+    let mut record = get_hashlock_escrow(e, escrow_id);
+    record.depositor.require_auth();
+    require_not_paused(e);
+
+    // State & Timeout Validation
+    if record.claimed || record.refunded {
+        panic_with_error!(e, FeatureError::InvalidState);
+    }
+    if e.ledger().sequence() < record.timeout_ledger {
+        panic_with_error!(e, FeatureError::TimeoutActive);
+    }
+
+    // Update state
+    record.refunded = true;
+    e.storage().persistent().set(&DataKey::Hashlock(escrow_id), &record);
+
+    // Move funds from contract back to depositor
+    pay_from_hashlock(e, &record, &record.depositor);
+
+    // Emit Event
+    e.events().publish(
+        (Symbol::new(e, "htlc"), Symbol::new(e, "refunded"), escrow_id),
+        record.depositor
+    );
+}
+
+/// Ledger at which claims close and the depositor may refund. A pause halts
+/// both claims and refunds but does not move this deadline, so a pause that
+/// outlasts it hands the escrow to the depositor.
+pub fn hashlock_timeout(e: &Env, escrow_id: u32) -> u32 {
+    get_hashlock_escrow(e, escrow_id).timeout_ledger
+}
+
+/// Pays out the whole hashlock escrow to `to`, in the escrow's token.
+fn pay_from_hashlock(e: &Env, record: &HashlockEscrow, to: &Address) {
+    match &record.token {
+        Some(token_address) => {
+            sweep::release_in_token(e, token_address, record.amount);
+            token::Client::new(e, token_address).transfer(
+                &e.current_contract_address(),
+                to,
+                &record.amount,
+            );
+        }
+        None => {
+            spend_balance(e, e.current_contract_address(), record.amount);
+            sweep::release_native(e, record.amount);
+            receive_balance(e, to.clone(), record.amount);
+        }
+    }
+}
+
+/// Sets up both legs of an atomic swap sharing one hashlock, and links them
+/// so claiming either leg records the preimage on the other.
+///
+/// The initiator holds the preimage. Their leg pays the counterparty in
+/// `initiator_token`; the counterparty's leg pays the initiator in
+/// `counterparty_token`. Either token may be this contract's address, in
+/// which case that leg is held in this token. `counterparty_timeout` must be
+/// strictly earlier than `initiator_timeout`: the initiator reveals the
+/// preimage by claiming the counterparty's leg, and the counterparty then
+/// needs time left on the initiator's leg to claim with the same preimage.
+/// Otherwise the initiator could wait, claim late, and refund their own leg.
+///
+/// Returns `(initiator_leg_id, counterparty_leg_id)`.
+#[allow(clippy::too_many_arguments)]
+pub fn create_swap(
+    e: &Env,
+    initiator: Address,
+    counterparty: Address,
+    initiator_token: Address,
+    initiator_amount: i128,
+    counterparty_token: Address,
+    counterparty_amount: i128,
+    hashlock: BytesN<32>,
+    initiator_timeout: u32,
+    counterparty_timeout: u32,
+) -> (u32, u32) {
+    if counterparty_timeout >= initiator_timeout {
+        panic_with_error!(e, FeatureError::InvalidTimeout);
+    }
+
+    let initiator_leg = lock_hashlock(
+        e,
+        initiator.clone(),
+        counterparty.clone(),
+        external_token(e, initiator_token),
+        initiator_amount,
+        hashlock.clone(),
+        initiator_timeout,
+    );
+    let counterparty_leg = lock_hashlock(
+        e,
+        counterparty,
+        initiator,
+        external_token(e, counterparty_token),
+        counterparty_amount,
+        hashlock,
+        counterparty_timeout,
+    );
+
+    // Link the legs to each other
+    let mut first = get_hashlock_escrow(e, initiator_leg);
+    first.linked_id = Some(counterparty_leg);
+    e.storage().persistent().set(&DataKey::Hashlock(initiator_leg), &first);
+
+    let mut second = get_hashlock_escrow(e, counterparty_leg);
+    second.linked_id = Some(initiator_leg);
+    e.storage().persistent().set(&DataKey::Hashlock(counterparty_leg), &second);
+
+    e.events().publish(
+        (Symbol::new(e, "htlc"), Symbol::new(e, "swap_created"), initiator_leg),
+        counterparty_leg
+    );
+
+    (initiator_leg, counterparty_leg)
+}
+
+/// `None` for this contract's own address, so that leg uses the internal ledger.
+fn external_token(e: &Env, token_address: Address) -> Option<Address> {
+    if token_address == e.current_contract_address() {
+        None
+    } else {
+        Some(token_address)
+    }
+}
+
+/// Helper to read a hashlock escrow record
+pub fn get_hashlock_escrow(e: &Env, escrow_id: u32) -> HashlockEscrow {
+    e.storage()
+        .persistent()
+        .get(&DataKey::Hashlock(escrow_id))
+        .unwrap_or_else(|| panic_with_error!(e, FeatureError::HashlockNotFound))
+}
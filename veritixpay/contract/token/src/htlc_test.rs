@@ -0,0 +1,266 @@
+#[cfg(test)]
+mod htlc_tests {
+    use crate::error::{Error, FeatureError};
+    use crate::{VeritixToken, VeritixTokenClient};
+    use soroban_sdk::{testutils::{Address as _, Ledger}, token, Address, Bytes, BytesN, Env, String};
+
+    fn setup_test(e: &Env) -> (Address, Address, VeritixTokenClient<'_>) {
+        let (_, depositor, beneficiary, client) = setup_with_admin(e);
+        (depositor, beneficiary, client)
+    }
+
+    fn setup_with_admin(e: &Env) -> (Address, Address, Address, VeritixTokenClient<'_>) {
+        e.mock_all_auths();
+
+        let contract_id = e.register_contract(None, VeritixToken);
+        let client = VeritixTokenClient::new(e, &contract_id);
+
+        let admin = Address::generate(e);
+        let depositor = Address::generate(e);
+        let beneficiary = Address::generate(e);
+
+        client.initialize(
+            &admin,
+            &String::from_str(e, "Veritix"),
+            &String::from_str(e, "VTX"),
+            &7u32,
+        );
+        client.mint(&admin, &depositor, &10_000i128);
+        client.mint(&admin, &beneficiary, &5000i128);
+
+        (admin, depositor, beneficiary, client)
+    }
+
+    fn hashlock_for(e: &Env, preimage: &Bytes) -> BytesN<32> {
+        e.crypto().sha256(preimage).into()
+    }
+
+    #[test]
+    fn test_claim_with_correct_preimage() {
+        let e = Env::default();
+        let (depositor, beneficiary, client) = setup_test(&e);
+        let preimage = Bytes::from_slice(&e, b"veritix-secret");
+        let hashlock = hashlock_for(&e, &preimage);
+
+        let id = client.create_hashlock_escrow(&depositor, &beneficiary, &1000, &hashlock, &100);
+        assert_eq!(client.balance(&client.address), 1000);
+
+        client.claim_hashlock(&id, &preimage);
+
+        let record = client.get_hashlock_escrow(&id);
+        assert!(record.claimed);
+        assert_eq!(record.preimage, Some(preimage));
+        assert_eq!(client.balance(&beneficiary), 6000);
+        assert_eq!(client.balance(&client.address), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #144)")]
+    fn test_claim_with_wrong_preimage_panics() {
+        let e = Env::default();
+        let (depositor, beneficiary, client) = setup_test(&e);
+        let preimage = Bytes::from_slice(&e, b"veritix-secret");
+        let hashlock = hashlock_for(&e, &preimage);
+
+        let id = client.create_hashlock_escrow(&depositor, &beneficiary, &1000, &hashlock, &100);
+        client.claim_hashlock(&id, &Bytes::from_slice(&e, b"wrong-guess"));
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #142)")]
+    fn test_claim_after_timeout_panics() {
+        let e = Env::default();
+        let (depositor, beneficiary, client) = setup_test(&e);
+        let preimage = Bytes::from_slice(&e, b"veritix-secret");
+        let hashlock = hashlock_for(&e, &preimage);
+
+        let id = client.create_hashlock_escrow(&depositor, &beneficiary, &1000, &hashlock, &100);
+        e.ledger().set_sequence_number(100);
+        client.claim_hashlock(&id, &preimage);
+    }
+
+    #[test]
+    fn test_refund_after_timeout() {
+        let e = Env::default();
+        let (depositor, beneficiary, client) = setup_test(&e);
+        let hashlock = hashlock_for(&e, &Bytes::from_slice(&e, b"veritix-secret"));
+
+        let id = client.create_hashlock_escrow(&depositor, &beneficiary, &1000, &hashlock, &100);
+        e.ledger().set_sequence_number(100);
+        client.refund_hashlock(&id);
+
+        assert!(client.get_hashlock_escrow(&id).refunded);
+        assert_eq!(client.balance(&depositor), 10_000);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #143)")]
+    fn test_refund_before_timeout_panics() {
+        let e = Env::default();
+        let (depositor, beneficiary, client) = setup_test(&e);
+        let hashlock = hashlock_for(&e, &Bytes::from_slice(&e, b"veritix-secret"));
+
+        let id = client.create_hashlock_escrow(&depositor, &beneficiary, &1000, &hashlock, &100);
+        client.refund_hashlock(&id);
+    }
+
+    #[test]
+    fn test_claim_and_refund_wait_out_a_pause() {
+        let e = Env::default();
+        let (admin, depositor, beneficiary, client) = setup_with_admin(&e);
+        let preimage = Bytes::from_slice(&e, b"veritix-secret");
+        let hashlock = hashlock_for(&e, &preimage);
+
+        let claimed = client.create_hashlock_escrow(&depositor, &beneficiary, &1000, &hashlock, &100);
+        let refunded = client.create_hashlock_escrow(&depositor, &beneficiary, &500, &hashlock, &50);
+        assert_eq!(client.hashlock_timeout(&claimed), 100);
+        assert_eq!(client.hashlock_timeout(&refunded), 50);
+
+        client.pause(&admin);
+        assert_eq!(
+            client.try_claim_hashlock(&claimed, &preimage),
+            Err(Ok(Error::ContractPaused.into()))
+        );
+        e.ledger().set_sequence_number(50);
+        assert_eq!(client.try_refund_hashlock(&refunded), Err(Ok(Error::ContractPaused.into())));
+        assert_eq!(client.balance(&client.address), 1500);
+
+        // The pause does not move either deadline
+        client.unpause(&admin);
+        assert_eq!(client.hashlock_timeout(&claimed), 100);
+        client.claim_hashlock(&claimed, &preimage);
+        client.refund_hashlock(&refunded);
+
+        assert_eq!(client.balance(&beneficiary), 6000);
+        assert_eq!(client.balance(&depositor), 9000);
+        assert_eq!(client.balance(&client.address), 0);
+    }
+
+    #[test]
+    fn test_swap_both_legs_claimed() {
+        let e = Env::default();
+        let (alice, bob, client) = setup_test(&e);
+        let preimage = Bytes::from_slice(&e, b"swap-secret");
+        let hashlock = hashlock_for(&e, &preimage);
+
+        let (alice_leg, bob_leg) = client.create_swap(
+            &alice, &bob, &client.address, &1000, &client.address, &400, &hashlock, &200, &100,
+        );
+
+        // Alice claims Bob's leg, revealing the preimage on her own leg
+        client.claim_hashlock(&bob_leg, &preimage);
+        let revealed = client.get_hashlock_escrow(&alice_leg).preimage.unwrap();
+        assert_eq!(revealed, preimage);
+
+        // Bob reuses the revealed preimage before Alice's later timeout
+        e.ledger().set_sequence_number(150);
+        client.claim_hashlock(&alice_leg, &revealed);
+
+        assert_eq!(client.balance(&alice), 9000 + 400);
+        assert_eq!(client.balance(&bob), 5000 - 400 + 1000);
+        assert_eq!(client.balance(&client.address), 0);
+    }
+
+    #[test]
+    fn test_swap_timed_out_refunds_both_legs() {
+        let e = Env::default();
+        let (alice, bob, client) = setup_test(&e);
+        let hashlock = hashlock_for(&e, &Bytes::from_slice(&e, b"swap-secret"));
+
+        let (alice_leg, bob_leg) = client.create_swap(
+            &alice, &bob, &client.address, &1000, &client.address, &400, &hashlock, &200, &100,
+        );
+
+        e.ledger().set_sequence_number(100);
+        client.refund_hashlock(&bob_leg);
+        e.ledger().set_sequence_number(200);
+        client.refund_hashlock(&alice_leg);
+
+        assert_eq!(client.balance(&alice), 10_000);
+        assert_eq!(client.balance(&bob), 5000);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #141)")]
+    fn test_swap_with_misordered_timeouts_panics() {
+        let e = Env::default();
+        let (alice, bob, client) = setup_test(&e);
+        let hashlock = hashlock_for(&e, &Bytes::from_slice(&e, b"swap-secret"));
+
+        client.create_swap(
+            &alice, &bob, &client.address, &1000, &client.address, &400, &hashlock, &100, &200,
+        );
+    }
+
+    #[test]
+    fn test_create_hashlock_rejects_bad_amount_blacklist_and_pause() {
+        let e = Env::default();
+        let (admin, alice, bob, client) = setup_with_admin(&e);
+        let hashlock = hashlock_for(&e, &Bytes::from_slice(&e, b"veritix-secret"));
+
+        assert_eq!(
+            client.try_create_hashlock_escrow(&alice, &bob, &-1000, &hashlock, &100),
+            Err(Ok(Error::InvalidAmount.into()))
+        );
+        assert_eq!(
+            client.try_create_swap(
+                &alice, &bob, &client.address, &1000, &client.address, &0, &hashlock, &200, &100,
+            ),
+            Err(Ok(Error::InvalidAmount.into()))
+        );
+
+        client.blacklist(&admin, &bob);
+        assert_eq!(
+            client.try_create_hashlock_escrow(&alice, &bob, &1000, &hashlock, &100),
+            Err(Ok(FeatureError::Blacklisted.into()))
+        );
+        client.unblacklist(&admin, &bob);
+
+        client.pause(&admin);
+        assert_eq!(
+            client.try_create_swap(
+                &alice, &bob, &client.address, &1000, &client.address, &400, &hashlock, &200, &100,
+            ),
+            Err(Ok(Error::ContractPaused.into()))
+        );
+
+        assert_eq!(client.balance(&alice), 10_000);
+        assert_eq!(client.balance(&bob), 5000);
+        assert_eq!(client.balance(&client.address), 0);
+    }
+
+    #[test]
+    fn test_swap_across_two_tokens() {
+        let e = Env::default();
+        let (admin, alice, bob, client) = setup_with_admin(&e);
+        let usdc = e.register_stellar_asset_contract_v2(admin.clone());
+        let eurc = e.register_stellar_asset_contract_v2(admin.clone());
+        token::StellarAssetClient::new(&e, &usdc.address()).mint(&alice, &1000);
+        token::StellarAssetClient::new(&e, &eurc.address()).mint(&bob, &900);
+        let preimage = Bytes::from_slice(&e, b"swap-secret");
+        let hashlock = hashlock_for(&e, &preimage);
+
+        let (alice_leg, bob_leg) = client.create_swap(
+            &alice, &bob, &usdc.address(), &1000, &eurc.address(), &900, &hashlock, &200, &100,
+        );
+        let usdc_client = token::Client::new(&e, &usdc.address());
+        let eurc_client = token::Client::new(&e, &eurc.address());
+        assert_eq!(usdc_client.balance(&client.address), 1000);
+        assert_eq!(eurc_client.balance(&client.address), 900);
+        assert_eq!(
+            client.try_admin_sweep(&admin, &usdc.address(), &1, &admin),
+            Err(Ok(FeatureError::SweepExceedsSurplus.into()))
+        );
+
+        client.claim_hashlock(&bob_leg, &preimage);
+        client.claim_hashlock(&alice_leg, &preimage);
+
+        assert_eq!(usdc_client.balance(&bob), 1000);
+        assert_eq!(eurc_client.balance(&alice), 900);
+        assert_eq!(usdc_client.balance(&client.address), 0);
+        assert_eq!(eurc_client.balance(&client.address), 0);
+        // The internal ledger is untouched
+        assert_eq!(client.balance(&alice), 10_000);
+        assert_eq!(client.balance(&bob), 5000);
+    }
+}
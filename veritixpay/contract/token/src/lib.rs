@@ -4,15 +4,65 @@
 // Contributors: see CONTRIBUTING.md for how to get started.
 
 pub mod storage_types;
+pub mod error;
 pub mod admin;
+pub mod roles;
+pub mod multisig;
+pub mod timelock;
 pub mod metadata;
 pub mod allowance;
 pub mod balance;
 pub mod freeze;
+pub mod pause;
+pub mod fee;
+pub mod rate_limit;
+pub mod reflection;
+pub mod snapshot;
+pub mod splitter;
+pub mod supply;
+pub mod sweep;
+pub mod votes;
+pub mod escrow;
+pub mod export;
+pub mod recurring;
+pub mod scheduled;
+pub mod htlc;
+pub mod milestone;
+pub mod dispute;
 
 mod contract;
 
 #[cfg(test)]
 mod test;
+#[cfg(test)]
+mod escrow_test;
+#[cfg(test)]
+mod export_test;
+#[cfg(test)]
+mod recurring_test;
+#[cfg(test)]
+mod splitter_test;
+#[cfg(test)]
+mod htlc_test;
+#[cfg(test)]
+mod milestone_test;
+#[cfg(test)]
+mod multisig_test;
+#[cfg(test)]
+mod dispute_test;
+#[cfg(test)]
+mod rate_limit_test;
+#[cfg(test)]
+mod reflection_test;
+#[cfg(test)]
+mod scheduled_test;
+#[cfg(test)]
+mod snapshot_test;
+#[cfg(test)]
+mod timelock_test;
+#[cfg(test)]
+mod votes_test;
+#[cfg(test)]
+mod sweep_test;
 
-pub use crate::contract::VeritixToken;
+pub use crate::contract::{VeritixToken, VeritixTokenClient};
@@ -1,8 +1,16 @@
-use soroban_sdk::{contracttype, Env, String};
+use soroban_sdk::{contracttype, panic_with_error, Env, String, Symbol};
 
+use crate::error::{Error, FeatureError};
 use crate::storage_types::DataKey;
 
-#[derive(Clone)]
+/// Highest supported `decimal`; beyond this fixed-point math overflows.
+pub const MAX_DECIMALS: u32 = 18;
+
+/// Byte bounds wallets can display without truncation.
+pub const MAX_NAME_LEN: u32 = 64;
+pub const MAX_SYMBOL_LEN: u32 = 12;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
 #[contracttype]
 pub struct TokenMetadata {
     pub name: String,
@@ -18,6 +26,24 @@ pub fn write_metadata(e: &Env, metadata: TokenMetadata) {
     e.storage().instance().set(&DataKey::Metadata, &metadata);
 }
 
+/// Renames the token. Decimals are fixed at initialization.
+/// Caller is responsible for admin authorization (see `check_admin`).
+pub fn update_metadata(e: &Env, name: String, symbol: String) {
+    if name.is_empty() || name.len() > MAX_NAME_LEN {
+        panic_with_error!(e, FeatureError::InvalidMetadata);
+    }
+    if symbol.is_empty() || symbol.len() > MAX_SYMBOL_LEN {
+        panic_with_error!(e, FeatureError::InvalidMetadata);
+    }
+
+    let decimal = read_decimal(e);
+    write_metadata(e, TokenMetadata { name: name.clone(), symbol: symbol.clone(), decimal });
+    e.events().publish(
+        (Symbol::new(e, "metadata"), Symbol::new(e, "updated")),
+        (name, symbol)
+    );
+}
+
 pub fn read_decimal(e: &Env) -> u32 {
     read_metadata(e).decimal
 }
@@ -29,3 +55,26 @@ pub fn read_name(e: &Env) -> String {
 pub fn read_symbol(e: &Env) -> String {
     read_metadata(e).symbol
 }
+
+/// Splits a raw amount into whole tokens and the fractional remainder in
+/// base units, e.g. `12_345_678` at 7 decimals is `(1, 2_345_678)`. The
+/// fraction is an `i128` because it needs up to `MAX_DECIMALS` digits.
+pub fn to_display_units(e: &Env, raw: i128) -> (i128, i128) {
+    if raw < 0 {
+        panic_with_error!(e, Error::InvalidAmount);
+    }
+    let unit = 10i128.pow(read_decimal(e));
+    (raw / unit, raw % unit)
+}
+
+/// Inverse of `to_display_units`. `frac` must be below one whole token.
+pub fn from_display_units(e: &Env, whole: i128, frac: i128) -> i128 {
+    let unit = 10i128.pow(read_decimal(e));
+    if whole < 0 || frac < 0 || frac >= unit {
+        panic_with_error!(e, Error::InvalidAmount);
+    }
+    whole
+        .checked_mul(unit)
+        .and_then(|base| base.checked_add(frac))
+        .unwrap_or_else(|| panic_with_error!(e, Error::InvalidAmount))
+}
@@ -0,0 +1,207 @@
+use crate::balance::{lock_balance, receive_balance, spend_balance, unlock_balance};
+use crate::error::Error;
+use crate::fee::take_escrow_fee;
+use crate::freeze::{require_can_send, require_not_blacklisted};
+use crate::pause::require_not_paused;
+use crate::storage_types::MilestoneKey;
+use crate::sweep;
+use soroban_sdk::{contracttype, panic_with_error, Address, Env, Symbol, Vec};
+
+/// Upper bound on milestones per escrow, so a full refund loop always fits
+/// within a single transaction's budget.
+pub const MAX_MILESTONES: u32 = 20;
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MilestoneState {
+    Pending,
+    /// Signed off by the depositor; the beneficiary can now release it.
+    Approved,
+    Released,
+    Refunded,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Milestone {
+    pub amount: i128,
+    pub state: MilestoneState,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MilestoneEscrow {
+    pub id: u32,
+    pub depositor: Address,
+    pub beneficiary: Address,
+    pub milestones: Vec<Milestone>,
+}
+
+/// Locks the sum of `amounts` in the contract as one milestone per amount,
+/// each approved and released on its own.
+pub fn create_milestone_escrow(
+    e: &Env,
+    depositor: Address,
+    beneficiary: Address,
+    amounts: Vec<i128>,
+) -> u32 {
+    depositor.require_auth();
+    require_not_paused(e);
+    if depositor == beneficiary {
+        panic_with_error!(e, Error::InvalidBeneficiary);
+    }
+    if amounts.is_empty() || amounts.len() > MAX_MILESTONES {
+        panic_with_error!(e, Error::InvalidMilestones);
+    }
+    require_can_send(e, &depositor);
+    require_not_blacklisted(e, &beneficiary);
+
+    let mut milestones = Vec::new(e);
+    let mut total: i128 = 0;
+    for amount in amounts.iter() {
+        if amount <= 0 {
+            panic_with_error!(e, Error::InvalidAmount);
+        }
+        total = total
+            .checked_add(amount)
+            .unwrap_or_else(|| panic_with_error!(e, Error::InvalidAmount));
+        milestones.push_back(Milestone { amount, state: MilestoneState::Pending });
+    }
+
+    // 1. Move funds from the depositor to the contract itself
+    spend_balance(e, depositor.clone(), total);
+    receive_balance(e, e.current_contract_address(), total);
+    sweep::hold_native(e, total);
+    lock_balance(e, depositor.clone(), total);
+
+    // 2. Increment and fetch the new Milestone Escrow ID
+    let mut count: u32 = e.storage().instance().get(&MilestoneKey::MilestoneCount).unwrap_or(0);
+    count += 1;
+    e.storage().instance().set(&MilestoneKey::MilestoneCount, &count);
+
+    // 3. Store the record
+    let record = MilestoneEscrow {
+        id: count,
+        depositor: depositor.clone(),
+        beneficiary: beneficiary.clone(),
+        milestones,
+    };
+    e.storage().persistent().set(&MilestoneKey::MilestoneEscrow(count), &record);
+
+    // 4. Emit Event
+    e.events().publish(
+        (Symbol::new(e, "milestone"), Symbol::new(e, "created"), count),
+        (depositor, beneficiary, total)
+    );
+
+    count
+}
+
+/// Lets the depositor sign off on a pending milestone.
+pub fn approve_milestone(e: &Env, escrow_id: u32, index: u32) {
+    let mut record = get_milestone_escrow(e, escrow_id);
+    record.depositor.require_auth();
+
+    let mut milestone = read_milestone(e, &record, index);
+    if milestone.state != MilestoneState::Pending {
+        panic_with_error!(e, Error::AlreadySettled);
+    }
+
+    milestone.state = MilestoneState::Approved;
+    record.milestones.set(index, milestone);
+    e.storage().persistent().set(&MilestoneKey::MilestoneEscrow(escrow_id), &record);
+
+    e.events().publish(
+        (Symbol::new(e, "milestone"), Symbol::new(e, "approved"), escrow_id),
+        index
+    );
+}
+
+/// Pays an approved milestone to the beneficiary, less the escrow fee.
+pub fn release_milestone(e: &Env, escrow_id: u32, index: u32) {
+    let mut record = get_milestone_escrow(e, escrow_id);
+    record.beneficiary.require_auth();
+
+    let mut milestone = read_milestone(e, &record, index);
+    match milestone.state {
+        MilestoneState::Approved => {}
+        MilestoneState::Pending => panic_with_error!(e, Error::MilestoneNotApproved),
+        _ => panic_with_error!(e, Error::AlreadySettled),
+    }
+
+    // Update state
+    milestone.state = MilestoneState::Released;
+    record.milestones.set(index, milestone.clone());
+    e.storage().persistent().set(&MilestoneKey::MilestoneEscrow(escrow_id), &record);
+
+    // Move funds from contract to beneficiary, less the escrow fee
+    spend_balance(e, e.current_contract_address(), milestone.amount);
+    sweep::release_native(e, milestone.amount);
+    unlock_balance(e, record.depositor.clone(), milestone.amount);
+    let fee = take_escrow_fee(e, milestone.amount);
+    receive_balance(e, record.beneficiary.clone(), milestone.amount - fee);
+
+    e.events().publish(
+        (Symbol::new(e, "milestone"), Symbol::new(e, "released"), escrow_id),
+        (index, milestone.amount)
+    );
+}
+
+/// Returns every still-pending milestone to the depositor. Approved
+/// milestones stay payable to the beneficiary. Returns the amount refunded.
+pub fn refund_milestones(e: &Env, escrow_id: u32) -> i128 {
+    let mut record = get_milestone_escrow(e, escrow_id);
+    record.depositor.require_auth();
+
+    // Update state
+    let mut refunded: i128 = 0;
+    for (i, mut milestone) in record.milestones.clone().iter().enumerate() {
+        if milestone.state == MilestoneState::Pending {
+            refunded += milestone.amount;
+            milestone.state = MilestoneState::Refunded;
+            record.milestones.set(i as u32, milestone);
+        }
+    }
+    if refunded == 0 {
+        panic_with_error!(e, Error::NothingToClaim);
+    }
+    e.storage().persistent().set(&MilestoneKey::MilestoneEscrow(escrow_id), &record);
+
+    // Move funds from contract back to depositor
+    spend_balance(e, e.current_contract_address(), refunded);
+    sweep::release_native(e, refunded);
+    unlock_balance(e, record.depositor.clone(), refunded);
+    receive_balance(e, record.depositor.clone(), refunded);
+
+    e.events().publish(
+        (Symbol::new(e, "milestone"), Symbol::new(e, "refunded"), escrow_id),
+        refunded
+    );
+
+    refunded
+}
+
+fn read_milestone(e: &Env, record: &MilestoneEscrow, index: u32) -> Milestone {
+    record
+        .milestones
+        .get(index)
+        .unwrap_or_else(|| panic_with_error!(e, Error::MilestoneNotFound))
+}
+
+/// Sum of the milestones still pending or approved, i.e. still held.
+pub fn unsettled_milestone_amount(record: &MilestoneEscrow) -> i128 {
+    record
+        .milestones
+        .iter()
+        .filter(|m| matches!(m.state, MilestoneState::Pending | MilestoneState::Approved))
+        .map(|m| m.amount)
+        .sum()
+}
+
+/// Helper to read a milestone escrow record
+pub fn get_milestone_escrow(e: &Env, escrow_id: u32) -> MilestoneEscrow {
+    e.storage()
+        .persistent()
+        .get(&MilestoneKey::MilestoneEscrow(escrow_id))
+        .unwrap_or_else(|| panic_with_error!(e, Error::EscrowNotFound))
+}
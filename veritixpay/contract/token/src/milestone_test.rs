@@ -0,0 +1,96 @@
+#[cfg(test)]
+mod milestone_tests {
+    use crate::error::Error;
+    use crate::milestone::MilestoneState;
+    use crate::{VeritixToken, VeritixTokenClient};
+    use soroban_sdk::{testutils::Address as _, vec, Address, Env, String};
+
+    fn setup_test(e: &Env) -> (Address, Address, VeritixTokenClient<'_>) {
+        e.mock_all_auths();
+
+        let contract_id = e.register_contract(None, VeritixToken);
+        let client = VeritixTokenClient::new(e, &contract_id);
+
+        let admin = Address::generate(e);
+        let depositor = Address::generate(e);
+        let beneficiary = Address::generate(e);
+
+        client.initialize(
+            &admin,
+            &String::from_str(e, "Veritix"),
+            &String::from_str(e, "VTX"),
+            &7u32,
+        );
+        client.mint(&admin, &depositor, &10_000i128);
+
+        (depositor, beneficiary, client)
+    }
+
+    #[test]
+    fn test_milestones_release_independently() {
+        let e = Env::default();
+        let (depositor, beneficiary, client) = setup_test(&e);
+
+        let id = client.create_milestone_escrow(&depositor, &beneficiary, &vec![&e, 1000, 2000, 3000]);
+        assert_eq!(client.balance(&client.address), 6000);
+        assert_eq!(client.locked_balance(&depositor), 6000);
+
+        client.approve_milestone(&id, &1);
+        client.release_milestone(&id, &1);
+        assert_eq!(client.balance(&beneficiary), 2000);
+
+        // An unapproved milestone cannot be released, nor a paid one twice
+        assert_eq!(
+            client.try_release_milestone(&id, &0),
+            Err(Ok(Error::MilestoneNotApproved.into()))
+        );
+        assert_eq!(
+            client.try_release_milestone(&id, &1),
+            Err(Ok(Error::AlreadySettled.into()))
+        );
+
+        let record = client.get_milestone_escrow(&id);
+        assert_eq!(record.milestones.get(0).unwrap().state, MilestoneState::Pending);
+        assert_eq!(record.milestones.get(1).unwrap().state, MilestoneState::Released);
+        assert_eq!(client.locked_balance(&depositor), 4000);
+    }
+
+    #[test]
+    fn test_refund_returns_only_pending_milestones() {
+        let e = Env::default();
+        let (depositor, beneficiary, client) = setup_test(&e);
+
+        let id = client.create_milestone_escrow(&depositor, &beneficiary, &vec![&e, 1000, 2000, 3000]);
+        client.approve_milestone(&id, &0);
+
+        assert_eq!(client.refund_milestones(&id), 5000);
+        assert_eq!(client.balance(&depositor), 9000);
+
+        // The approved milestone is still owed to the beneficiary
+        client.release_milestone(&id, &0);
+        assert_eq!(client.balance(&beneficiary), 1000);
+        assert_eq!(client.balance(&client.address), 0);
+        assert_eq!(client.locked_balance(&depositor), 0);
+    }
+
+    #[test]
+    fn test_invalid_milestones_rejected() {
+        let e = Env::default();
+        let (depositor, beneficiary, client) = setup_test(&e);
+
+        assert_eq!(
+            client.try_create_milestone_escrow(&depositor, &beneficiary, &vec![&e]),
+            Err(Ok(Error::InvalidMilestones.into()))
+        );
+        assert_eq!(
+            client.try_create_milestone_escrow(&depositor, &beneficiary, &vec![&e, 100, 0]),
+            Err(Ok(Error::InvalidAmount.into()))
+        );
+
+        let id = client.create_milestone_escrow(&depositor, &beneficiary, &vec![&e, 100]);
+        assert_eq!(
+            client.try_approve_milestone(&id, &1),
+            Err(Ok(Error::MilestoneNotFound.into()))
+        );
+    }
+}
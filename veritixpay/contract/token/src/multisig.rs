@@ -0,0 +1,154 @@
+use crate::error::Error;
+use crate::storage_types::DataKey;
+use soroban_sdk::{contracttype, panic_with_error, Address, Env, Symbol, Vec};
+
+/// M-of-N signer set. Once configured, sensitive admin calls can only run
+/// as approved actions.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MultisigConfig {
+    pub signers: Vec<Address>,
+    pub threshold: u32,
+}
+
+/// A sensitive admin call awaiting signer approval.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AdminAction {
+    Mint(Address, i128),
+    Clawback(Address, i128),
+    SetAdmin(Address),
+    Freeze(Address),
+    Unfreeze(Address),
+    /// Replaces the signer set; its threshold applies to later actions.
+    SetMultisig(Vec<Address>, u32),
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ActionProposal {
+    pub id: u32,
+    pub action: AdminAction,
+    pub approvals: Vec<Address>,
+    pub executed: bool,
+}
+
+pub fn read_multisig(e: &Env) -> Option<MultisigConfig> {
+    e.storage().instance().get(&DataKey::MultisigConfig)
+}
+
+/// Caller is responsible for admin authorization (see `check_admin`), or
+/// for executing an approved `SetMultisig` action.
+pub fn write_multisig(e: &Env, signers: Vec<Address>, threshold: u32) {
+    if threshold == 0 || threshold > signers.len() {
+        panic_with_error!(e, Error::InvalidThreshold);
+    }
+    for (i, signer) in signers.iter().enumerate() {
+        if signers.first_index_of(&signer) != Some(i as u32) {
+            panic_with_error!(e, Error::InvalidThreshold);
+        }
+    }
+
+    let config = MultisigConfig { signers, threshold };
+    e.storage().instance().set(&DataKey::MultisigConfig, &config);
+    e.events().publish(
+        (Symbol::new(e, "multisig"), Symbol::new(e, "configured")),
+        (config.signers, config.threshold)
+    );
+}
+
+/// Panics once a signer set is configured. Every sensitive admin path that
+/// can bypass approvals must call this.
+pub fn require_no_multisig(e: &Env) {
+    if read_multisig(e).is_some() {
+        panic_with_error!(e, Error::MultisigRequired);
+    }
+}
+
+fn require_signer(e: &Env, signer: &Address) -> MultisigConfig {
+    signer.require_auth();
+    let config = read_multisig(e).unwrap_or_else(|| panic_with_error!(e, Error::NotSigner));
+    if !config.signers.contains(signer) {
+        panic_with_error!(e, Error::NotSigner);
+    }
+    config
+}
+
+/// Records a new action; the proposer's approval is counted.
+pub fn propose_action(e: &Env, proposer: Address, action: AdminAction) -> u32 {
+    require_signer(e, &proposer);
+
+    let mut count: u32 = e.storage().instance().get(&DataKey::AdminActionCount).unwrap_or(0);
+    count += 1;
+    e.storage().instance().set(&DataKey::AdminActionCount, &count);
+
+    let proposal = ActionProposal {
+        id: count,
+        action,
+        approvals: Vec::from_array(e, [proposer.clone()]),
+        executed: false,
+    };
+    e.storage().persistent().set(&DataKey::AdminAction(count), &proposal);
+
+    e.events().publish(
+        (Symbol::new(e, "multisig"), Symbol::new(e, "proposed"), count),
+        proposer
+    );
+
+    count
+}
+
+pub fn approve_action(e: &Env, signer: Address, action_id: u32) {
+    require_signer(e, &signer);
+
+    let mut proposal = get_action(e, action_id);
+    if proposal.executed {
+        panic_with_error!(e, Error::AlreadyExecuted);
+    }
+    if proposal.approvals.contains(&signer) {
+        panic_with_error!(e, Error::AlreadyApproved);
+    }
+    proposal.approvals.push_back(signer.clone());
+    e.storage().persistent().set(&DataKey::AdminAction(action_id), &proposal);
+
+    e.events().publish(
+        (Symbol::new(e, "multisig"), Symbol::new(e, "approved"), action_id),
+        signer
+    );
+}
+
+/// Marks an action executed once enough current signers approved it, and
+/// returns it for the caller to apply. Anyone may trigger execution.
+pub fn take_approved_action(e: &Env, action_id: u32) -> AdminAction {
+    let mut proposal = get_action(e, action_id);
+    if proposal.executed {
+        panic_with_error!(e, Error::AlreadyExecuted);
+    }
+
+    // Only approvals from signers still in the set count
+    let config = read_multisig(e).unwrap_or_else(|| panic_with_error!(e, Error::NotSigner));
+    let approvals = proposal
+        .approvals
+        .iter()
+        .filter(|a| config.signers.contains(a))
+        .count() as u32;
+    if approvals < config.threshold {
+        panic_with_error!(e, Error::InsufficientApprovals);
+    }
+
+    proposal.executed = true;
+    e.storage().persistent().set(&DataKey::AdminAction(action_id), &proposal);
+    e.events().publish(
+        (Symbol::new(e, "multisig"), Symbol::new(e, "executed"), action_id),
+        approvals
+    );
+
+    proposal.action
+}
+
+pub fn get_action(e: &Env, action_id: u32) -> ActionProposal {
+    e.storage()
+        .persistent()
+        .get(&DataKey::AdminAction(action_id))
+        .unwrap_or_else(|| panic_with_error!(e, Error::ActionNotFound))
+}
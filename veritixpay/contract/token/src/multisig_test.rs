@@ -0,0 +1,205 @@
+#[cfg(test)]
+mod multisig_tests {
+    use crate::error::Error;
+    use crate::multisig::AdminAction;
+    use crate::roles::Role;
+    use crate::storage_types::TransferFee;
+    use crate::timelock::TimelockedAction;
+    use crate::{VeritixToken, VeritixTokenClient};
+    use soroban_sdk::{testutils::Address as _, vec, Address, Env, String};
+
+    struct Setup<'a> {
+        client: VeritixTokenClient<'a>,
+        admin: Address,
+        signers: [Address; 3],
+        holder: Address,
+    }
+
+    fn setup_test(e: &Env) -> Setup<'_> {
+        e.mock_all_auths();
+
+        let contract_id = e.register_contract(None, VeritixToken);
+        let client = VeritixTokenClient::new(e, &contract_id);
+
+        let admin = Address::generate(e);
+        let holder = Address::generate(e);
+        let signers = [Address::generate(e), Address::generate(e), Address::generate(e)];
+
+        client.initialize(
+            &admin,
+            &String::from_str(e, "Veritix"),
+            &String::from_str(e, "VTX"),
+            &7u32,
+        );
+        client.mint(&admin, &holder, &1000i128);
+        client.set_multisig(
+            &admin,
+            &vec![e, signers[0].clone(), signers[1].clone(), signers[2].clone()],
+            &2,
+        );
+
+        Setup { client, admin, signers, holder }
+    }
+
+    #[test]
+    fn test_mint_needs_two_of_three() {
+        let e = Env::default();
+        let s = setup_test(&e);
+
+        let id = s.client.propose_action(&s.signers[0], &AdminAction::Mint(s.holder.clone(), 500));
+        assert_eq!(
+            s.client.try_execute_action(&id),
+            Err(Ok(Error::InsufficientApprovals.into()))
+        );
+
+        s.client.approve_action(&s.signers[2], &id);
+        s.client.execute_action(&id);
+
+        assert_eq!(s.client.balance(&s.holder), 1500);
+        assert_eq!(s.client.total_supply(), 1500);
+        assert!(s.client.get_action(&id).executed);
+        assert_eq!(s.client.try_execute_action(&id), Err(Ok(Error::AlreadyExecuted.into())));
+    }
+
+    #[test]
+    fn test_direct_sensitive_calls_blocked() {
+        let e = Env::default();
+        let s = setup_test(&e);
+        let required = Err(Ok(Error::MultisigRequired.into()));
+
+        assert_eq!(s.client.try_mint(&s.admin, &s.holder, &1), required);
+        assert_eq!(s.client.try_clawback(&s.admin, &s.holder, &1), required);
+        assert_eq!(s.client.try_freeze(&s.admin, &s.holder), required);
+        assert_eq!(s.client.try_set_admin(&s.signers[0]), required);
+        assert_eq!(s.client.try_reverse_mint(&s.admin, &s.holder, &1, &s.admin), required);
+        assert_eq!(s.client.try_set_authorized(&s.admin, &s.holder, &false), required);
+        assert_eq!(s.client.try_blacklist(&s.admin, &s.holder), required);
+        assert_eq!(s.client.try_accept_admin(), required);
+        assert_eq!(s.client.try_unfreeze(&s.admin, &s.holder), required);
+        assert_eq!(s.client.try_unfreeze_many(&s.admin, &vec![&e, s.holder.clone()]), required);
+        assert_eq!(s.client.try_unblacklist(&s.admin, &s.holder), required);
+        assert_eq!(s.client.try_grant_role(&s.admin, &Role::Minter, &s.holder), required);
+        assert_eq!(s.client.try_revoke_role(&s.admin, &Role::Minter, &s.holder), required);
+        assert_eq!(
+            s.client.try_set_transfer_fee(&s.admin, &TransferFee { bps: 100, collector: None, reflection_bps: 0 }),
+            required
+        );
+        assert_eq!(s.client.try_admin_sweep(&s.admin, &s.client.address, &1, &s.admin), required);
+    }
+
+    #[test]
+    fn test_enabling_multisig_drops_pending_admin() {
+        let e = Env::default();
+        e.mock_all_auths();
+        let contract_id = e.register_contract(None, VeritixToken);
+        let client = VeritixTokenClient::new(&e, &contract_id);
+        let admin = Address::generate(&e);
+        let successor = Address::generate(&e);
+        client.initialize(&admin, &String::from_str(&e, "Veritix"), &String::from_str(&e, "VTX"), &7u32);
+
+        client.propose_admin(&successor);
+        client.set_multisig(&admin, &vec![&e, Address::generate(&e)], &1);
+
+        assert_eq!(client.pending_admin(), None);
+    }
+
+    #[test]
+    fn test_clawback_and_freeze_actions() {
+        let e = Env::default();
+        let s = setup_test(&e);
+
+        let clawback = s.client.propose_action(&s.signers[0], &AdminAction::Clawback(s.holder.clone(), 400));
+        s.client.approve_action(&s.signers[1], &clawback);
+        s.client.execute_action(&clawback);
+        assert_eq!(s.client.balance(&s.holder), 600);
+        assert_eq!(s.client.total_supply(), 600);
+
+        let freeze = s.client.propose_action(&s.signers[1], &AdminAction::Freeze(s.holder.clone()));
+        s.client.approve_action(&s.signers[2], &freeze);
+        s.client.execute_action(&freeze);
+        assert!(s.client.is_frozen(&s.holder));
+
+        let unfreeze = s.client.propose_action(&s.signers[0], &AdminAction::Unfreeze(s.holder.clone()));
+        s.client.approve_action(&s.signers[2], &unfreeze);
+        s.client.execute_action(&unfreeze);
+        assert!(!s.client.is_frozen(&s.holder));
+    }
+
+    #[test]
+    fn test_non_signer_and_double_approval_rejected() {
+        let e = Env::default();
+        let s = setup_test(&e);
+
+        assert_eq!(
+            s.client.try_propose_action(&s.admin, &AdminAction::SetAdmin(s.admin.clone())),
+            Err(Ok(Error::NotSigner.into()))
+        );
+        let id = s.client.propose_action(&s.signers[0], &AdminAction::SetAdmin(s.holder.clone()));
+        assert_eq!(
+            s.client.try_approve_action(&s.signers[0], &id),
+            Err(Ok(Error::AlreadyApproved.into()))
+        );
+    }
+
+    #[test]
+    fn test_signer_set_rotates_through_an_action() {
+        let e = Env::default();
+        let s = setup_test(&e);
+        let newcomer = Address::generate(&e);
+
+        let signers = vec![&e, s.signers[0].clone(), newcomer.clone()];
+        let id = s.client.propose_action(&s.signers[0], &AdminAction::SetMultisig(signers.clone(), 2));
+        s.client.approve_action(&s.signers[1], &id);
+        s.client.execute_action(&id);
+
+        let config = s.client.multisig_config().unwrap();
+        assert_eq!(config.signers, signers);
+        assert_eq!(config.threshold, 2);
+
+        // A removed signer's approval no longer counts
+        let mint = s.client.propose_action(&s.signers[0], &AdminAction::Mint(s.holder.clone(), 1));
+        assert_eq!(
+            s.client.try_approve_action(&s.signers[1], &mint),
+            Err(Ok(Error::NotSigner.into()))
+        );
+        s.client.approve_action(&newcomer, &mint);
+        s.client.execute_action(&mint);
+        assert_eq!(s.client.balance(&s.holder), 1001);
+    }
+
+    #[test]
+    fn test_invalid_threshold_rejected() {
+        let e = Env::default();
+        e.mock_all_auths();
+        let client = VeritixTokenClient::new(&e, &e.register_contract(None, VeritixToken));
+        let admin = Address::generate(&e);
+        let signer = Address::generate(&e);
+        client.initialize(
+            &admin,
+            &String::from_str(&e, "Veritix"),
+            &String::from_str(&e, "VTX"),
+            &7u32,
+        );
+
+        let signers = vec![&e, signer.clone()];
+        let invalid = Err(Ok(Error::InvalidThreshold.into()));
+        assert_eq!(client.try_set_multisig(&admin, &signers, &0), invalid);
+        assert_eq!(client.try_set_multisig(&admin, &signers, &2), invalid);
+        let duplicated = vec![&e, signer.clone(), signer];
+        assert_eq!(client.try_set_multisig(&admin, &duplicated, &2), invalid);
+    }
+
+    #[test]
+    fn test_timelock_unavailable_under_multisig() {
+        let e = Env::default();
+        let s = setup_test(&e);
+        let required = Err(Ok(Error::MultisigRequired.into()));
+
+        assert_eq!(s.client.try_set_admin_timelock(&s.admin, &100), required);
+        assert_eq!(
+            s.client.try_queue_admin_action(&s.admin, &TimelockedAction::SetAdmin(s.holder.clone())),
+            Err(Ok(Error::MultisigRequired.into()))
+        );
+        assert_eq!(s.client.admin_timelock(), 0);
+    }
+}
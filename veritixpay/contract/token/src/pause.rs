@@ -0,0 +1,24 @@
+use crate::error::Error;
+use crate::storage_types::DataKey;
+use soroban_sdk::{panic_with_error, Env, Symbol};
+
+pub fn is_paused(e: &Env) -> bool {
+    e.storage().instance().get(&DataKey::Paused).unwrap_or(false)
+}
+
+/// Halts or resumes fund movement contract-wide.
+/// Caller is responsible for admin authorization (see `check_admin`).
+pub fn set_paused(e: &Env, paused: bool) {
+    e.storage().instance().set(&DataKey::Paused, &paused);
+
+    let action = if paused { "paused" } else { "unpaused" };
+    e.events().publish((Symbol::new(e, "pause"), Symbol::new(e, action)), ());
+}
+
+/// Panics while the contract is paused. Every path that moves funds on a
+/// user's behalf must call this.
+pub fn require_not_paused(e: &Env) {
+    if is_paused(e) {
+        panic_with_error!(e, Error::ContractPaused);
+    }
+}
@@ -0,0 +1,121 @@
+use crate::error::{Error, FeatureError};
+use crate::storage_types::{DataKey, DAY_IN_LEDGERS};
+use soroban_sdk::{contracttype, panic_with_error, Address, Env};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RateLimit {
+    pub max_per_window: i128,
+    pub window_ledgers: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RateUsage {
+    pub window_start: u32,
+    pub spent: i128,
+}
+
+pub fn read_rate_limit(e: &Env, addr: &Address) -> Option<RateLimit> {
+    e.storage()
+        .persistent()
+        .get(&DataKey::RateLimit(addr.clone()))
+}
+
+/// Caps outbound transfers from `addr`. Both the cap and the window must be
+/// non-zero; use `clear_rate_limit` to lift a limit.
+pub fn write_rate_limit(e: &Env, addr: &Address, max_per_window: i128, window_ledgers: u32) {
+    if max_per_window <= 0 || window_ledgers == 0 {
+        panic_with_error!(e, FeatureError::InvalidRateLimit);
+    }
+    e.storage().persistent().set(
+        &DataKey::RateLimit(addr.clone()),
+        &RateLimit {
+            max_per_window,
+            window_ledgers,
+        },
+    );
+}
+
+/// Removes the outbound limit on `addr` along with its usage in the current window.
+pub fn clear_rate_limit(e: &Env, addr: &Address) {
+    e.storage().persistent().remove(&DataKey::RateLimit(addr.clone()));
+    e.storage().persistent().remove(&DataKey::RateUsage(addr.clone()));
+}
+
+/// Records `amount` against the sender's current window, panicking if it
+/// would exceed the configured cap. A no-op for addresses without a limit.
+pub fn consume_rate_limit(e: &Env, addr: &Address, amount: i128) {
+    let limit = match read_rate_limit(e, addr) {
+        Some(limit) => limit,
+        None => return,
+    };
+
+    let key = DataKey::RateUsage(addr.clone());
+    let now = e.ledger().sequence();
+    let mut usage: RateUsage = e.storage().persistent().get(&key).unwrap_or(RateUsage {
+        window_start: now,
+        spent: 0,
+    });
+
+    // Start a fresh window once the previous one has rolled over
+    if now >= usage.window_start + limit.window_ledgers {
+        usage = RateUsage {
+            window_start: now,
+            spent: 0,
+        };
+    }
+
+    if usage.spent + amount > limit.max_per_window {
+        panic_with_error!(e, FeatureError::RateLimited);
+    }
+
+    usage.spent += amount;
+    e.storage().persistent().set(&key, &usage);
+}
+
+pub fn read_mint_limit(e: &Env) -> Option<i128> {
+    e.storage().instance().get(&DataKey::MintLimit)
+}
+
+/// Caps total minting per `DAY_IN_LEDGERS`. Zero clears the cap.
+pub fn write_mint_limit(e: &Env, max_per_day: i128) {
+    if max_per_day < 0 {
+        panic_with_error!(e, Error::InvalidAmount);
+    }
+    if max_per_day == 0 {
+        e.storage().instance().remove(&DataKey::MintLimit);
+    } else {
+        e.storage().instance().set(&DataKey::MintLimit, &max_per_day);
+    }
+}
+
+/// Records `amount` against the current day's mint window, panicking if it
+/// would exceed the configured cap. A no-op while no cap is set.
+pub fn consume_mint_limit(e: &Env, amount: i128) {
+    let max_per_day = match read_mint_limit(e) {
+        Some(max) => max,
+        None => return,
+    };
+
+    let now = e.ledger().sequence();
+    let mut usage: RateUsage = e.storage().instance().get(&DataKey::MintUsage).unwrap_or(RateUsage {
+        window_start: now,
+        spent: 0,
+    });
+
+    // Start a fresh day once the previous one has rolled over
+    if now >= usage.window_start + DAY_IN_LEDGERS {
+        usage = RateUsage {
+            window_start: now,
+            spent: 0,
+        };
+    }
+
+    if usage.spent + amount > max_per_day {
+        panic_with_error!(e, FeatureError::MintLimitExceeded);
+    }
+
+    usage.spent += amount;
+    e.storage().instance().set(&DataKey::MintUsage, &usage);
+}
@@ -0,0 +1,145 @@
+#[cfg(test)]
+mod rate_limit_tests {
+    use crate::error::FeatureError;
+    use crate::storage_types::DAY_IN_LEDGERS;
+    use crate::{VeritixToken, VeritixTokenClient};
+    use soroban_sdk::{testutils::{Address as _, Ledger}, Address, Env, String};
+
+    fn setup_test(e: &Env) -> (Address, Address, VeritixTokenClient<'_>) {
+        e.mock_all_auths();
+
+        let contract_id = e.register_contract(None, VeritixToken);
+        let client = VeritixTokenClient::new(e, &contract_id);
+
+        let admin = Address::generate(e);
+        let sender = Address::generate(e);
+
+        client.initialize(
+            &admin,
+            &String::from_str(e, "Veritix"),
+            &String::from_str(e, "VTX"),
+            &7u32,
+        );
+        client.mint(&admin, &sender, &10_000i128);
+        client.set_rate_limit(&admin, &sender, &1000, &100);
+
+        (admin, sender, client)
+    }
+
+    #[test]
+    fn test_transfers_within_limit() {
+        let e = Env::default();
+        let (_, sender, client) = setup_test(&e);
+        let receiver = Address::generate(&e);
+
+        client.transfer(&sender, &receiver, &600);
+        client.transfer(&sender, &receiver, &400);
+
+        assert_eq!(client.balance(&receiver), 1000);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #113)")]
+    fn test_transfer_over_limit_panics() {
+        let e = Env::default();
+        let (_, sender, client) = setup_test(&e);
+        let receiver = Address::generate(&e);
+
+        client.transfer(&sender, &receiver, &600);
+        client.transfer(&sender, &receiver, &401);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #113)")]
+    fn test_transfer_from_counts_against_limit() {
+        let e = Env::default();
+        let (_, sender, client) = setup_test(&e);
+        let spender = Address::generate(&e);
+        let receiver = Address::generate(&e);
+
+        client.approve(&sender, &spender, &5000, &1000);
+        client.transfer(&sender, &receiver, &600);
+        client.transfer_from(&spender, &sender, &receiver, &401);
+    }
+
+    #[test]
+    fn test_limit_resets_after_window() {
+        let e = Env::default();
+        let (_, sender, client) = setup_test(&e);
+        let receiver = Address::generate(&e);
+
+        client.transfer(&sender, &receiver, &1000);
+
+        e.ledger().set_sequence_number(100);
+        client.transfer(&sender, &receiver, &1000);
+
+        assert_eq!(client.balance(&receiver), 2000);
+    }
+
+    #[test]
+    fn test_clear_rate_limit() {
+        let e = Env::default();
+        let (admin, sender, client) = setup_test(&e);
+        let receiver = Address::generate(&e);
+
+        client.transfer(&sender, &receiver, &1000);
+        client.clear_rate_limit(&admin, &sender);
+        client.transfer(&sender, &receiver, &5000);
+
+        assert_eq!(client.balance(&receiver), 6000);
+    }
+
+    #[test]
+    fn test_zero_cap_or_window_rejected() {
+        let e = Env::default();
+        let (admin, sender, client) = setup_test(&e);
+        let invalid = Err(Ok(FeatureError::InvalidRateLimit.into()));
+
+        assert_eq!(client.try_set_rate_limit(&admin, &sender, &0, &100), invalid);
+        assert_eq!(client.try_set_rate_limit(&admin, &sender, &-1, &100), invalid);
+        assert_eq!(client.try_set_rate_limit(&admin, &sender, &1000, &0), invalid);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #114)")]
+    fn test_mint_over_daily_limit_panics() {
+        let e = Env::default();
+        let (admin, sender, client) = setup_test(&e);
+
+        client.set_mint_limit(&admin, &1000);
+        client.mint(&admin, &sender, &700);
+        client.mint(&admin, &sender, &301);
+    }
+
+    #[test]
+    fn test_mint_limit_resets_after_a_day() {
+        let e = Env::default();
+        e.ledger().with_mut(|li| {
+            li.min_persistent_entry_ttl = 2 * DAY_IN_LEDGERS;
+            li.min_temp_entry_ttl = 2 * DAY_IN_LEDGERS;
+        });
+        let (admin, sender, client) = setup_test(&e);
+
+        client.set_mint_limit(&admin, &1000);
+        client.mint(&admin, &sender, &1000);
+
+        e.ledger().set_sequence_number(DAY_IN_LEDGERS);
+        client.mint(&admin, &sender, &1000);
+
+        assert_eq!(client.balance(&sender), 12_000);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #114)")]
+    fn test_mint_split_counts_against_daily_limit() {
+        let e = Env::default();
+        let (admin, sender, client) = setup_test(&e);
+        let recipients = soroban_sdk::vec![
+            &e,
+            crate::splitter::SplitRecipient { address: sender, share_bps: 10000 },
+        ];
+
+        client.set_mint_limit(&admin, &1000);
+        client.mint_split(&admin, &1001, &recipients);
+    }
+}
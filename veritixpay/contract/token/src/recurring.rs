@@ -1,6 +1,14 @@
 use crate::balance::{receive_balance, spend_balance};
+use crate::error::{Error, FeatureError};
+use crate::freeze::{can_receive, require_can_send};
+use crate::pause::require_not_paused;
+use crate::splitter::{calculate_distribution, validate_bps, SplitRecipient};
 use crate::storage_types::DataKey;
-use soroban_sdk::{contracttype, Address, Env, Symbol};
+use soroban_sdk::{contracttype, panic_with_error, Address, Env, Symbol, Vec};
+
+/// Most records one `list_due_payments` call examines, so a scan always
+/// fits within a single transaction's budget.
+pub const MAX_DUE_SCAN: u32 = 50;
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -12,6 +20,13 @@ pub struct RecurringRecord {
     pub interval: u32,
     pub last_charged_ledger: u32,
     pub active: bool,
+    pub iterations: u32,
+    pub completed: u32,
+    /// Set once the final iteration has been paid; the record is then inactive.
+    pub finished: bool,
+    /// When non-empty, each charge is split across these recipients by bps
+    /// (dust to the last) and `payee` is just the first recipient.
+    pub recipients: Vec<SplitRecipient>,
 }
 
 /// Sets up a new recurring payment configuration.
@@ -21,9 +36,65 @@ pub fn setup_recurring(
     payee: Address,
     amount: i128,
     interval: u32,
+    iterations: u32,
 ) -> u32 {
     // 1. Authorization: The payer must explicitly authorize this recurring charge
     payer.require_auth();
+    store_recurring(e, payer, payee, amount, interval, iterations, Vec::new(e))
+}
+
+/// Like `setup_recurring`, but takes the first payment immediately instead
+/// of one interval from now. The first charge counts as an iteration.
+pub fn setup_recurring_charge_now(
+    e: &Env,
+    payer: Address,
+    payee: Address,
+    amount: i128,
+    interval: u32,
+    iterations: u32,
+) -> u32 {
+    payer.require_auth();
+    let id = store_recurring(e, payer, payee, amount, interval, iterations, Vec::new(e));
+    charge(e, get_recurring(e, id));
+    id
+}
+
+/// Sets up a recurring payment that splits `total_amount` across
+/// `recipients` on every execution, e.g. a subscription shared by
+/// collaborators.
+pub fn setup_recurring_split(
+    e: &Env,
+    payer: Address,
+    recipients: Vec<SplitRecipient>,
+    total_amount: i128,
+    interval: u32,
+    iterations: u32,
+) -> u32 {
+    payer.require_auth();
+    validate_bps(&recipients);
+
+    let payee = recipients.get(0).unwrap_or_else(|| panic_with_error!(e, Error::RecipientNotFound)).address;
+    store_recurring(e, payer, payee, total_amount, interval, iterations, recipients)
+}
+
+fn store_recurring(
+    e: &Env,
+    payer: Address,
+    payee: Address,
+    amount: i128,
+    interval: u32,
+    iterations: u32,
+    recipients: Vec<SplitRecipient>,
+) -> u32 {
+    if amount <= 0 {
+        panic_with_error!(e, Error::InvalidAmount);
+    }
+    if interval == 0 {
+        panic_with_error!(e, FeatureError::InvalidSchedule);
+    }
+    if iterations == 0 {
+        panic_with_error!(e, FeatureError::InvalidIterations);
+    }
 
     // 2. Increment and get the new Recurring ID
     let mut count: u32 = e.storage().instance().get(&DataKey::RecurringCount).unwrap_or(0);
@@ -39,6 +110,10 @@ pub fn setup_recurring(
         interval,
         last_charged_ledger: e.ledger().sequence(), // Set initial timestamp to now
         active: true,
+        iterations,
+        completed: 0,
+        finished: false,
+        recipients,
     };
     e.storage().persistent().set(&DataKey::Recurring(count), &record);
 
@@ -51,6 +126,148 @@ pub fn setup_recurring(
     count
 }
 
-/// Executes a recurring payment if the interval has passed. 
+/// Executes a recurring payment if the interval has passed.
 /// Anyone can call this ("crank the contract"), but funds only move from payer to payee.
-pub fn execute_recurring(e: &Env, recurring_id: u
\ No newline at end of file
+pub fn execute_recurring(e: &Env, recurring_id: u32) {
+    let record = get_recurring(e, recurring_id);
+
+    // 1. State Validation
+    if record.finished || record.completed >= record.iterations {
+        panic_with_error!(e, FeatureError::AllPaymentsCompleted);
+    }
+    if !record.active {
+        panic_with_error!(e, FeatureError::InvalidState);
+    }
+    if e.ledger().sequence() < record.last_charged_ledger + record.interval {
+        panic_with_error!(e, FeatureError::IntervalNotElapsed);
+    }
+
+    charge(e, record);
+}
+
+/// Takes one payment now and records it against the schedule.
+fn charge(e: &Env, mut record: RecurringRecord) {
+    require_not_paused(e);
+    require_can_send(e, &record.payer);
+    let recurring_id = record.id;
+
+    // 2. A payee that cannot receive skips this cycle without using up an
+    // iteration, so the schedule survives a temporary freeze
+    let payee_blocked = if record.recipients.is_empty() {
+        !can_receive(e, &record.payee)
+    } else {
+        record.recipients.iter().any(|r| !can_receive(e, &r.address))
+    };
+    if payee_blocked {
+        record.last_charged_ledger = e.ledger().sequence();
+        e.storage().persistent().set(&DataKey::Recurring(recurring_id), &record);
+        e.events().publish(
+            (Symbol::new(e, "recurring"), Symbol::new(e, "skipped_frozen"), recurring_id),
+            record.completed
+        );
+        return;
+    }
+
+    // 3. Update state
+    record.last_charged_ledger = e.ledger().sequence();
+    record.completed += 1;
+    if record.completed == record.iterations {
+        record.finished = true;
+        record.active = false;
+    }
+    e.storage().persistent().set(&DataKey::Recurring(recurring_id), &record);
+
+    // 4. Move funds from payer to payee, or across the split recipients
+    spend_balance(e, record.payer.clone(), record.amount);
+    if record.recipients.is_empty() {
+        receive_balance(e, record.payee.clone(), record.amount);
+    } else {
+        let amounts = calculate_distribution(e, record.amount, &record.recipients);
+        for (recipient, amount) in record.recipients.iter().zip(amounts.iter()) {
+            receive_balance(e, recipient.address, amount);
+        }
+    }
+
+    // 5. Billing receipt
+    e.events().publish(
+        (Symbol::new(e, "recurring"), Symbol::new(e, "executed"), recurring_id),
+        (record.amount, record.completed)
+    );
+
+    if record.finished {
+        e.events().publish(
+            (Symbol::new(e, "recurring"), Symbol::new(e, "finished"), recurring_id),
+            record.completed
+        );
+    }
+}
+
+/// Stops a recurring payment for good; `execute_recurring` fails afterwards.
+/// Either the payer or the payee can cancel.
+pub fn cancel_recurring(e: &Env, caller: Address, recurring_id: u32) {
+    caller.require_auth();
+    let mut record = get_recurring(e, recurring_id);
+
+    if caller != record.payer && caller != record.payee {
+        panic_with_error!(e, Error::Unauthorized);
+    }
+    if !record.active {
+        panic_with_error!(e, FeatureError::InvalidState);
+    }
+
+    record.active = false;
+    e.storage().persistent().set(&DataKey::Recurring(recurring_id), &record);
+
+    e.events().publish(
+        (Symbol::new(e, "recurring"), Symbol::new(e, "cancelled"), recurring_id),
+        (caller, record.completed)
+    );
+}
+
+/// Ids of active payments with iterations left whose interval has elapsed,
+/// for keepers looking for work. Examines at most `MAX_DUE_SCAN` records
+/// from `start_id` on and returns at most `limit` ids, in id order; keepers
+/// page on from `start_id + MAX_DUE_SCAN`, or from after the last id
+/// returned when `limit` was reached.
+pub fn list_due_payments(e: &Env, start_id: u32, limit: u32) -> Vec<u32> {
+    let mut due = Vec::new(e);
+    let count: u32 = e.storage().instance().get(&DataKey::RecurringCount).unwrap_or(0);
+    let start = start_id.max(1);
+    let end = count.min(start.saturating_add(MAX_DUE_SCAN - 1));
+
+    for id in start..=end {
+        if due.len() >= limit {
+            break;
+        }
+        let record = get_recurring(e, id);
+        if record.active
+            && !record.finished
+            && record.completed < record.iterations
+            && e.ledger().sequence() >= record.last_charged_ledger + record.interval
+        {
+            due.push_back(id);
+        }
+    }
+
+    due
+}
+
+/// Earliest ledger at which the next charge can execute.
+pub fn next_payment_ledger(e: &Env, recurring_id: u32) -> u32 {
+    let record = get_recurring(e, recurring_id);
+    record.last_charged_ledger + record.interval
+}
+
+/// Number of charges still scheduled for a recurring payment.
+pub fn remaining_iterations(e: &Env, recurring_id: u32) -> u32 {
+    let record = get_recurring(e, recurring_id);
+    record.iterations - record.completed
+}
+
+/// Helper to read a recurring record
+pub fn get_recurring(e: &Env, recurring_id: u32) -> RecurringRecord {
+    e.storage()
+        .persistent()
+        .get(&DataKey::Recurring(recurring_id))
+        .unwrap_or_else(|| panic_with_error!(e, FeatureError::RecurringNotFound))
+}
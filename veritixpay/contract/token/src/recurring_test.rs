@@ -1,27 +1,42 @@
 #[cfg(test)]
 mod recurring_tests {
-    use super::*;
-    use soroban_sdk::{testutils::{Address as _, Ledger}, Address, Env};
-    use crate::recurring::{RecurringContract, RecurringContractClient}; 
+    use crate::error::{Error, FeatureError};
+    use crate::freeze::FreezeMode;
+    use crate::recurring::MAX_DUE_SCAN;
+    use crate::splitter::SplitRecipient;
+    use crate::{VeritixToken, VeritixTokenClient};
+    use soroban_sdk::{
+        testutils::{Address as _, Events, Ledger},
+        vec, Address, Env, IntoVal, String, Symbol,
+    };
 
-    fn setup_test(e: &Env) -> (Address, Address, RecurringContractClient<'_>) {
+    fn setup_test(e: &Env) -> (Address, Address, VeritixTokenClient<'_>) {
+        let (_, payer, receiver, client) = setup_with_admin(e);
+        (payer, receiver, client)
+    }
+
+    fn setup_with_admin(e: &Env) -> (Address, Address, Address, VeritixTokenClient<'_>) {
+        e.mock_all_auths();
+
+        let contract_id = e.register_contract(None, VeritixToken);
+        let client = VeritixTokenClient::new(e, &contract_id);
+
+        let admin = Address::generate(e);
         let payer = Address::generate(e);
         let receiver = Address::generate(e);
-        let contract_id = e.register_contract(None, RecurringContract);
-        let client = RecurringContractClient::new(e, &contract_id);
-        
+
+        client.initialize(
+            &admin,
+            &String::from_str(e, "Veritix"),
+            &String::from_str(e, "VTX"),
+            &7u32,
+        );
+        client.mint(&admin, &payer, &10_000i128);
+
         // Initial ledger setup
-        e.ledger().set(soroban_sdk::testutils::LedgerInfo {
-            timestamp: 0,
-            sequence_number: 100,
-            network_id: [0u8; 32],
-            base_reserve: 10,
-            min_temp_entry_ttl: 10,
-            min_persistent_entry_ttl: 10,
-            max_entry_ttl: 1000,
-        });
+        e.ledger().set_sequence_number(100);
 
-        (payer, receiver, client)
+        (admin, payer, receiver, client)
     }
 
     #[test]
@@ -31,11 +46,13 @@ mod recurring_tests {
         let amount = 500i128;
         let interval = 100u32;
 
-        client.setup_recurring(&payer, &receiver, &amount, &interval);
-        
-        let record = client.get_recurring(&payer, &receiver);
+        let id = client.setup_recurring(&payer, &receiver, &amount, &interval, &3);
+
+        let record = client.get_recurring(&id);
         assert_eq!(record.amount, amount);
         assert_eq!(record.interval, interval);
+        assert_eq!(record.iterations, 3);
+        assert_eq!(record.completed, 0);
         assert!(record.active);
     }
 
@@ -44,81 +61,338 @@ mod recurring_tests {
         let e = Env::default();
         let (payer, receiver, client) = setup_test(&e);
         let interval = 100u32;
-        client.setup_recurring(&payer, &receiver, &500, &interval);
+        let id = client.setup_recurring(&payer, &receiver, &500, &interval, &3);
 
         // Advance ledger: Initial was 100, interval is 100, so 201 is valid
         e.ledger().set_sequence_number(201);
-        
-        client.execute_recurring(&payer, &receiver);
-        
-        let record = client.get_recurring(&payer, &receiver);
+
+        client.execute_recurring(&id);
+
+        let record = client.get_recurring(&id);
         assert_eq!(record.last_charged_ledger, 201);
+        assert_eq!(client.balance(&receiver), 500);
+        assert_eq!(client.balance(&payer), 9500);
     }
 
     #[test]
-    #[should_panic(expected = "too early")]
+    #[should_panic(expected = "Error(Contract, #153)")]
     fn test_execute_too_early_panics() {
         let e = Env::default();
         let (payer, receiver, client) = setup_test(&e);
-        client.setup_recurring(&payer, &receiver, &500, &100);
+        let id = client.setup_recurring(&payer, &receiver, &500, &100, &3);
 
         // Only advance by 50 (total 150), which is less than the 100 interval
         e.ledger().set_sequence_number(150);
-        client.execute_recurring(&payer, &receiver);
+        client.execute_recurring(&id);
     }
 
     #[test]
-    fn test_cancel_recurring() {
+    fn test_multiple_executions() {
         let e = Env::default();
         let (payer, receiver, client) = setup_test(&e);
-        client.setup_recurring(&payer, &receiver, &500, &100);
+        let id = client.setup_recurring(&payer, &receiver, &500, &100, &3);
 
-        client.cancel_recurring(&payer, &receiver);
-        
-        let record = client.get_recurring(&payer, &receiver);
-        assert!(!record.active);
+        // Execution 1
+        e.ledger().set_sequence_number(201);
+        client.execute_recurring(&id);
+
+        // Execution 2
+        e.ledger().set_sequence_number(302);
+        client.execute_recurring(&id);
+
+        let record = client.get_recurring(&id);
+        assert_eq!(record.last_charged_ledger, 302);
+        assert_eq!(record.completed, 2);
+        assert_eq!(client.remaining_iterations(&id), 1);
+    }
+
+    #[test]
+    fn test_executes_exactly_iterations_times() {
+        let e = Env::default();
+        let (payer, receiver, client) = setup_test(&e);
+        let id = client.setup_recurring(&payer, &receiver, &500, &100, &3);
+        assert_eq!(client.remaining_iterations(&id), 3);
+
+        for i in 1..=3u32 {
+            e.ledger().set_sequence_number(100 + i * 100);
+            client.execute_recurring(&id);
+            assert_eq!(client.remaining_iterations(&id), 3 - i);
+        }
+
+        assert_eq!(client.balance(&receiver), 1500);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #152)")]
+    fn test_execute_after_final_iteration_panics() {
+        let e = Env::default();
+        let (payer, receiver, client) = setup_test(&e);
+        let id = client.setup_recurring(&payer, &receiver, &500, &100, &2);
+
+        e.ledger().set_sequence_number(200);
+        client.execute_recurring(&id);
+        e.ledger().set_sequence_number(300);
+        client.execute_recurring(&id);
+
+        e.ledger().set_sequence_number(400);
+        client.execute_recurring(&id); // Panic
+    }
+
+    #[test]
+    fn test_cancel_recurring_emits_event() {
+        let e = Env::default();
+        let (payer, receiver, client) = setup_test(&e);
+        let id = client.setup_recurring(&payer, &receiver, &500, &100, &3);
+
+        client.cancel_recurring(&receiver, &id);
+
+        assert!(!client.get_recurring(&id).active);
+        let events = e.events().all();
+        assert_eq!(
+            events.slice(events.len() - 1..),
+            vec![
+                &e,
+                (
+                    client.address.clone(),
+                    (Symbol::new(&e, "recurring"), Symbol::new(&e, "cancelled"), id).into_val(&e),
+                    (receiver.clone(), 0u32).into_val(&e),
+                ),
+            ]
+        );
+        e.ledger().set_sequence_number(200);
+        assert!(client.list_due_payments(&1, &10).is_empty());
     }
 
     #[test]
-    #[should_panic(expected = "not active")]
+    #[should_panic(expected = "Error(Contract, #121)")]
     fn test_execute_after_cancel_panics() {
         let e = Env::default();
         let (payer, receiver, client) = setup_test(&e);
-        client.setup_recurring(&payer, &receiver, &500, &100);
+        let id = client.setup_recurring(&payer, &receiver, &500, &100, &3);
+
+        client.cancel_recurring(&payer, &id);
+
+        e.ledger().set_sequence_number(200);
+        client.execute_recurring(&id); // Panic
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #21)")]
+    fn test_cancel_recurring_by_stranger_panics() {
+        let e = Env::default();
+        let (payer, receiver, client) = setup_test(&e);
+        let id = client.setup_recurring(&payer, &receiver, &500, &100, &3);
+
+        client.cancel_recurring(&Address::generate(&e), &id);
+    }
+
+    #[test]
+    fn test_setup_recurring_rejects_bad_amount_and_interval() {
+        let e = Env::default();
+        let (payer, receiver, client) = setup_test(&e);
+
+        assert_eq!(
+            client.try_setup_recurring(&payer, &receiver, &-5000, &1, &1),
+            Err(Ok(Error::InvalidAmount.into()))
+        );
+        assert_eq!(
+            client.try_setup_recurring(&payer, &receiver, &0, &1, &1),
+            Err(Ok(Error::InvalidAmount.into()))
+        );
+        assert_eq!(
+            client.try_setup_recurring(&payer, &receiver, &500, &0, &1),
+            Err(Ok(FeatureError::InvalidSchedule.into()))
+        );
+        assert_eq!(client.balance(&receiver), 0);
+    }
+
+    #[test]
+    fn test_list_due_payments() {
+        let e = Env::default();
+        let (payer, receiver, client) = setup_test(&e);
+        let first = client.setup_recurring(&payer, &receiver, &100, &100, &3);
+        let _not_due = client.setup_recurring(&payer, &receiver, &100, &500, &3);
+        let third = client.setup_recurring(&payer, &receiver, &100, &50, &3);
+
+        e.ledger().set_sequence_number(200);
+
+        assert_eq!(client.list_due_payments(&1, &10), vec![&e, first, third]);
+        assert_eq!(client.list_due_payments(&1, &1), vec![&e, first]);
+        assert_eq!(client.list_due_payments(&(first + 1), &10), vec![&e, third]);
+    }
+
+    #[test]
+    fn test_list_due_payments_caps_records_examined() {
+        let e = Env::default();
+        let (payer, receiver, client) = setup_test(&e);
+        for _ in 0..=MAX_DUE_SCAN {
+            client.setup_recurring(&payer, &receiver, &1, &100, &1);
+        }
+
+        e.ledger().set_sequence_number(200);
+
+        // One call never looks past MAX_DUE_SCAN records, whatever the limit
+        assert_eq!(client.list_due_payments(&1, &100).len(), MAX_DUE_SCAN);
+        assert_eq!(
+            client.list_due_payments(&(1 + MAX_DUE_SCAN), &100),
+            vec![&e, MAX_DUE_SCAN + 1]
+        );
+        assert!(client.list_due_payments(&(2 + MAX_DUE_SCAN), &100).is_empty());
+    }
+
+    #[test]
+    fn test_list_due_payments_skips_completed() {
+        let e = Env::default();
+        let (payer, receiver, client) = setup_test(&e);
+        let id = client.setup_recurring(&payer, &receiver, &100, &100, &1);
 
-        client.cancel_recurring(&payer, &receiver);
-        
+        e.ledger().set_sequence_number(200);
+        client.execute_recurring(&id);
         e.ledger().set_sequence_number(300);
-        client.execute_recurring(&payer, &receiver);
+
+        assert_eq!(client.list_due_payments(&1, &10).len(), 0);
     }
 
     #[test]
-    #[should_panic(expected = "unauthorized")]
-    fn test_cancel_unauthorized_panics() {
+    fn test_final_iteration_finishes_payment() {
         let e = Env::default();
         let (payer, receiver, client) = setup_test(&e);
-        client.setup_recurring(&payer, &receiver, &500, &100);
+        let id = client.setup_recurring(&payer, &receiver, &500, &100, &2);
+
+        e.ledger().set_sequence_number(200);
+        client.execute_recurring(&id);
+        assert!(!client.get_recurring(&id).finished);
+
+        e.ledger().set_sequence_number(300);
+        client.execute_recurring(&id);
+
+        let record = client.get_recurring(&id);
+        assert!(record.finished);
+        assert!(!record.active);
 
-        let hacker = Address::generate(&e);
-        // Only the payer should be able to cancel
-        client.cancel_recurring(&hacker, &receiver);
+        e.ledger().set_sequence_number(1000);
+        assert_eq!(client.list_due_payments(&1, &10).len(), 0);
     }
 
     #[test]
-    fn test_multiple_executions() {
+    fn test_recurring_split_two_cycles() {
         let e = Env::default();
         let (payer, receiver, client) = setup_test(&e);
-        client.setup_recurring(&payer, &receiver, &500, &100);
+        let collaborator = Address::generate(&e);
+        let recipients = vec![
+            &e,
+            SplitRecipient { address: receiver.clone(), share_bps: 7000 },
+            SplitRecipient { address: collaborator.clone(), share_bps: 3000 },
+        ];
 
-        // Execution 1
-        e.ledger().set_sequence_number(201);
-        client.execute_recurring(&payer, &receiver);
+        let id = client.setup_recurring_split(&payer, &recipients, &1001, &100, &2);
 
-        // Execution 2
-        e.ledger().set_sequence_number(302);
-        client.execute_recurring(&payer, &receiver);
+        e.ledger().set_sequence_number(200);
+        client.execute_recurring(&id);
+        e.ledger().set_sequence_number(300);
+        client.execute_recurring(&id);
 
-        let record = client.get_recurring(&payer, &receiver);
-        assert_eq!(record.last_charged_ledger, 302);
+        // 700 + 301 (dust) per cycle
+        assert_eq!(client.balance(&receiver), 1400);
+        assert_eq!(client.balance(&collaborator), 602);
+        assert_eq!(client.balance(&payer), 10_000 - 2002);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #52)")]
+    fn test_recurring_split_invalid_bps_panics() {
+        let e = Env::default();
+        let (payer, receiver, client) = setup_test(&e);
+        let recipients = vec![&e, SplitRecipient { address: receiver, share_bps: 9000 }];
+
+        client.setup_recurring_split(&payer, &recipients, &1000, &100, &2);
+    }
+
+    #[test]
+    fn test_frozen_payee_skips_charge() {
+        let e = Env::default();
+        let (admin, payer, receiver, client) = setup_with_admin(&e);
+        let id = client.setup_recurring(&payer, &receiver, &500, &100, &2);
+
+        client.freeze_with_mode(&admin, &receiver, &FreezeMode::Full);
+        e.ledger().set_sequence_number(200);
+        client.execute_recurring(&id);
+
+        let record = client.get_recurring(&id);
+        assert_eq!(record.completed, 0);
+        assert_eq!(record.last_charged_ledger, 200);
+        assert_eq!(client.balance(&payer), 10_000);
+
+        client.unfreeze(&admin, &receiver);
+        e.ledger().set_sequence_number(300);
+        client.execute_recurring(&id);
+
+        assert_eq!(client.get_recurring(&id).completed, 1);
+        assert_eq!(client.remaining_iterations(&id), 1);
+        assert_eq!(client.balance(&receiver), 500);
+    }
+
+    #[test]
+    fn test_frozen_payer_cannot_be_charged() {
+        let e = Env::default();
+        let (admin, payer, receiver, client) = setup_with_admin(&e);
+        let id = client.setup_recurring(&payer, &receiver, &500, &100, &2);
+
+        client.freeze(&admin, &payer);
+        e.ledger().set_sequence_number(200);
+        assert_eq!(
+            client.try_execute_recurring(&id),
+            Err(Ok(FeatureError::AccountFrozen.into()))
+        );
+        assert_eq!(client.get_recurring(&id).completed, 0);
+        assert_eq!(client.balance(&payer), 10_000);
+    }
+
+    #[test]
+    fn test_setup_defers_first_charge() {
+        let e = Env::default();
+        let (payer, receiver, client) = setup_test(&e);
+        let id = client.setup_recurring(&payer, &receiver, &500, &100, &3);
+
+        assert_eq!(client.get_recurring(&id).completed, 0);
+        assert_eq!(client.next_payment_ledger(&id), 200);
+        assert_eq!(client.balance(&receiver), 0);
+    }
+
+    #[test]
+    fn test_setup_charge_now_takes_first_payment() {
+        let e = Env::default();
+        let (payer, receiver, client) = setup_test(&e);
+        let id = client.setup_recurring_charge_now(&payer, &receiver, &500, &100, &3);
+
+        assert_eq!(client.get_recurring(&id).completed, 1);
+        assert_eq!(client.next_payment_ledger(&id), 200);
+        assert_eq!(client.balance(&receiver), 500);
+        assert_eq!(client.remaining_iterations(&id), 2);
+    }
+
+    #[test]
+    fn test_execute_emits_executed_event() {
+        let e = Env::default();
+        let (payer, receiver, client) = setup_test(&e);
+        let id = client.setup_recurring(&payer, &receiver, &500, &100, &3);
+
+        e.ledger().set_sequence_number(200);
+        client.execute_recurring(&id);
+        e.ledger().set_sequence_number(300);
+        client.execute_recurring(&id);
+
+        let events = e.events().all();
+        assert_eq!(
+            events.slice(events.len() - 1..),
+            vec![
+                &e,
+                (
+                    client.address.clone(),
+                    (Symbol::new(&e, "recurring"), Symbol::new(&e, "executed"), id).into_val(&e),
+                    (500i128, 2u32).into_val(&e),
+                ),
+            ]
+        );
     }
-}
\ No newline at end of file
+}
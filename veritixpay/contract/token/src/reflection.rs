@@ -0,0 +1,114 @@
+use crate::balance::{peek_balance, read_total_supply, receive_balance, spend_balance};
+use crate::storage_types::DataKey;
+use soroban_sdk::{contracttype, Address, Env, Symbol};
+
+/// Fixed-point scale for `reward_per_token`.
+pub const REWARD_PRECISION: i128 = 1_000_000_000_000;
+
+/// Per-holder accumulator state: the `reward_per_token` index the holder
+/// was last settled at, and rewards settled but not yet claimed.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RewardDebt {
+    pub index: i128,
+    pub accrued: i128,
+}
+
+pub fn read_reward_per_token(e: &Env) -> i128 {
+    e.storage().instance().get(&DataKey::RewardPerToken).unwrap_or(0)
+}
+
+/// Reflected tokens held by the contract that holders have yet to claim.
+pub fn read_reflection_pool(e: &Env) -> i128 {
+    e.storage().instance().get(&DataKey::ReflectionPool).unwrap_or(0)
+}
+
+fn read_reward_debt(e: &Env, holder: &Address) -> RewardDebt {
+    e.storage()
+        .persistent()
+        .get(&DataKey::RewardDebt(holder.clone()))
+        .unwrap_or(RewardDebt {
+            index: 0,
+            accrued: 0,
+        })
+}
+
+/// Settles `holder`'s rewards up to the current index. Must be called
+/// before a balance changes, with the balance it held until now.
+pub fn checkpoint_rewards(e: &Env, holder: &Address, balance: i128) {
+    let reward_per_token = read_reward_per_token(e);
+    if reward_per_token == 0 || *holder == e.current_contract_address() {
+        return;
+    }
+
+    let mut debt = read_reward_debt(e, holder);
+    if debt.index == reward_per_token {
+        return;
+    }
+    debt.accrued += balance * (reward_per_token - debt.index) / REWARD_PRECISION;
+    debt.index = reward_per_token;
+    e.storage()
+        .persistent()
+        .set(&DataKey::RewardDebt(holder.clone()), &debt);
+}
+
+/// Distributes `amount` pro-rata to every holder in O(1) by raising the
+/// index. `in_flight` is value already debited but not yet credited, which
+/// no holder owns. Returns false (nothing moved) if there are no holders.
+pub fn reflect(e: &Env, amount: i128, in_flight: i128) -> bool {
+    let contract = e.current_contract_address();
+    let eligible = read_total_supply(e) - peek_balance(e, contract.clone()) - in_flight;
+    if eligible <= 0 {
+        return false;
+    }
+
+    let reward_per_token = read_reward_per_token(e) + amount * REWARD_PRECISION / eligible;
+    e.storage().instance().set(&DataKey::RewardPerToken, &reward_per_token);
+    e.storage()
+        .instance()
+        .set(&DataKey::ReflectionPool, &(read_reflection_pool(e) + amount));
+    receive_balance(e, contract, amount);
+
+    e.events().publish(
+        (Symbol::new(e, "reflection"), Symbol::new(e, "distributed")),
+        amount
+    );
+    true
+}
+
+/// Rewards `holder` could claim right now.
+pub fn pending_rewards(e: &Env, holder: Address) -> i128 {
+    let debt = read_reward_debt(e, &holder);
+    let balance = peek_balance(e, holder);
+    debt.accrued + balance * (read_reward_per_token(e) - debt.index) / REWARD_PRECISION
+}
+
+/// Pays out everything `holder` has accrued.
+pub fn claim_rewards(e: &Env, holder: Address) -> i128 {
+    holder.require_auth();
+
+    checkpoint_rewards(e, &holder, peek_balance(e, holder.clone()));
+    let mut debt = read_reward_debt(e, &holder);
+    let amount = debt.accrued;
+    if amount == 0 {
+        return 0;
+    }
+
+    // Update state before moving funds
+    debt.accrued = 0;
+    e.storage()
+        .persistent()
+        .set(&DataKey::RewardDebt(holder.clone()), &debt);
+    e.storage()
+        .instance()
+        .set(&DataKey::ReflectionPool, &(read_reflection_pool(e) - amount));
+
+    spend_balance(e, e.current_contract_address(), amount);
+    receive_balance(e, holder.clone(), amount);
+
+    e.events().publish(
+        (Symbol::new(e, "reflection"), Symbol::new(e, "claimed"), holder),
+        amount
+    );
+    amount
+}
@@ -0,0 +1,101 @@
+#[cfg(test)]
+mod reflection_tests {
+    use crate::storage_types::TransferFee;
+    use crate::{VeritixToken, VeritixTokenClient};
+    use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+    struct Setup<'a> {
+        client: VeritixTokenClient<'a>,
+        admin: Address,
+        big_holder: Address,
+        small_holder: Address,
+        trader: Address,
+    }
+
+    fn setup_test(e: &Env, fee: TransferFee) -> Setup<'_> {
+        e.mock_all_auths();
+
+        let contract_id = e.register_contract(None, VeritixToken);
+        let client = VeritixTokenClient::new(e, &contract_id);
+
+        let admin = Address::generate(e);
+        let big_holder = Address::generate(e);
+        let small_holder = Address::generate(e);
+        let trader = Address::generate(e);
+
+        client.initialize(
+            &admin,
+            &String::from_str(e, "Veritix"),
+            &String::from_str(e, "VTX"),
+            &7u32,
+        );
+        client.mint(&admin, &big_holder, &3000i128);
+        client.mint(&admin, &small_holder, &1000i128);
+        client.mint(&admin, &trader, &4000i128);
+        client.set_transfer_fee(&admin, &fee);
+
+        Setup { client, admin, big_holder, small_holder, trader }
+    }
+
+    #[test]
+    fn test_rewards_are_proportional_to_balance() {
+        let e = Env::default();
+        let s = setup_test(&e, TransferFee { bps: 1000, collector: None, reflection_bps: 10000 });
+        let buyer = Address::generate(&e);
+
+        // 400 fee reflected across the 4000 held by the other two holders
+        s.client.transfer(&s.trader, &buyer, &4000);
+        assert_eq!(s.client.balance(&buyer), 3600);
+
+        assert_eq!(s.client.pending_rewards(&s.big_holder), 300);
+        assert_eq!(s.client.pending_rewards(&s.small_holder), 100);
+        assert_eq!(s.client.pending_rewards(&buyer), 0);
+
+        assert_eq!(s.client.claim_rewards(&s.big_holder), 300);
+        assert_eq!(s.client.claim_rewards(&s.small_holder), 100);
+        assert_eq!(s.client.balance(&s.big_holder), 3300);
+        assert_eq!(s.client.balance(&s.small_holder), 1100);
+        assert_eq!(s.client.total_supply(), 8000);
+        assert_eq!(s.client.contract_balance(), 0);
+    }
+
+    #[test]
+    fn test_rewards_survive_balance_changes() {
+        let e = Env::default();
+        let s = setup_test(&e, TransferFee { bps: 1000, collector: None, reflection_bps: 10000 });
+        let buyer = Address::generate(&e);
+
+        s.client.transfer(&s.trader, &buyer, &4000);
+        // Moving tokens settles the accrued amount first
+        s.client.set_transfer_fee(
+            &s.admin,
+            &TransferFee { bps: 0, collector: None, reflection_bps: 0 },
+        );
+        s.client.transfer(&s.big_holder, &s.small_holder, &3000);
+
+        assert_eq!(s.client.pending_rewards(&s.big_holder), 300);
+        assert_eq!(s.client.pending_rewards(&s.small_holder), 100);
+    }
+
+    #[test]
+    fn test_partial_reflection_with_collector() {
+        let e = Env::default();
+        let collector = Address::generate(&e);
+        let s = setup_test(
+            &e,
+            TransferFee { bps: 1000, collector: Some(collector.clone()), reflection_bps: 5000 },
+        );
+        let buyer = Address::generate(&e);
+
+        s.client.transfer(&s.trader, &buyer, &4000);
+
+        assert_eq!(s.client.balance(&collector), 200);
+        assert_eq!(s.client.pending_rewards(&s.big_holder), 150);
+        assert_eq!(s.client.pending_rewards(&s.small_holder), 50);
+        // The unclaimed pool is not sweepable
+        assert!(s
+            .client
+            .try_admin_sweep(&s.admin, &s.client.address, &1, &s.admin)
+            .is_err());
+    }
+}
@@ -0,0 +1,62 @@
+use crate::admin::read_admin;
+use crate::error::Error;
+use crate::storage_types::DataKey;
+use soroban_sdk::{contracttype, panic_with_error, Address, Env, Symbol};
+
+/// Delegable permissions. The admin implicitly holds every role.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Role {
+    /// May mint new tokens.
+    Minter,
+    /// May freeze and unfreeze accounts.
+    Freezer,
+    /// May pause and unpause the contract.
+    Pauser,
+    /// May be named as, and act as, a dispute resolver.
+    Resolver,
+}
+
+/// Whether `account` was granted `role`. Does not consider the admin.
+pub fn has_role(e: &Env, role: Role, account: &Address) -> bool {
+    e.storage()
+        .persistent()
+        .has(&DataKey::Role(role, account.clone()))
+}
+
+/// Caller is responsible for admin authorization (see `check_admin`).
+pub fn grant_role(e: &Env, role: Role, account: Address) {
+    e.storage()
+        .persistent()
+        .set(&DataKey::Role(role, account.clone()), &true);
+
+    e.events().publish(
+        (Symbol::new(e, "role"), Symbol::new(e, "granted"), account),
+        role
+    );
+}
+
+/// Caller is responsible for admin authorization (see `check_admin`).
+pub fn revoke_role(e: &Env, role: Role, account: Address) {
+    e.storage()
+        .persistent()
+        .remove(&DataKey::Role(role, account.clone()));
+
+    e.events().publish(
+        (Symbol::new(e, "role"), Symbol::new(e, "revoked"), account),
+        role
+    );
+}
+
+/// Panics unless `account` is the admin or holds `role`.
+pub fn require_role(e: &Env, role: Role, account: &Address) {
+    if account != &read_admin(e) && !has_role(e, role, account) {
+        panic_with_error!(e, Error::MissingRole);
+    }
+}
+
+/// Like `require_role`, and also requires `caller`'s authorization.
+pub fn check_role(e: &Env, role: Role, caller: &Address) {
+    caller.require_auth();
+    require_role(e, role, caller);
+}
@@ -0,0 +1,130 @@
+use crate::balance::{receive_balance, spend_balance};
+use crate::error::{Error, FeatureError};
+use crate::freeze::require_can_send;
+use crate::pause::require_not_paused;
+use crate::storage_types::DataKey;
+use crate::sweep;
+use soroban_sdk::{contracttype, panic_with_error, Address, Env, Symbol};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ScheduledTransfer {
+    pub id: u32,
+    pub from: Address,
+    pub to: Address,
+    pub amount: i128,
+    pub execute_at_ledger: u32,
+    pub executed: bool,
+    pub cancelled: bool,
+}
+
+/// Locks `amount` now for a single payment to `to` at `execute_at_ledger`.
+pub fn schedule_transfer(
+    e: &Env,
+    from: Address,
+    to: Address,
+    amount: i128,
+    execute_at_ledger: u32,
+) -> u32 {
+    from.require_auth();
+    require_not_paused(e);
+    require_can_send(e, &from);
+
+    if amount <= 0 {
+        panic_with_error!(e, Error::InvalidAmount);
+    }
+    if execute_at_ledger <= e.ledger().sequence() {
+        panic_with_error!(e, FeatureError::InvalidSchedule);
+    }
+
+    // 1. Move funds from the sender to the contract itself
+    spend_balance(e, from.clone(), amount);
+    receive_balance(e, e.current_contract_address(), amount);
+    sweep::hold_native(e, amount);
+
+    // 2. Increment and fetch the new Scheduled ID
+    let mut count: u32 = e.storage().instance().get(&DataKey::ScheduledCount).unwrap_or(0);
+    count += 1;
+    e.storage().instance().set(&DataKey::ScheduledCount, &count);
+
+    // 3. Store the record
+    let record = ScheduledTransfer {
+        id: count,
+        from: from.clone(),
+        to: to.clone(),
+        amount,
+        execute_at_ledger,
+        executed: false,
+        cancelled: false,
+    };
+    e.storage().persistent().set(&DataKey::Scheduled(count), &record);
+
+    // 4. Emit Event
+    e.events().publish(
+        (Symbol::new(e, "scheduled"), Symbol::new(e, "created"), from),
+        (to, amount, execute_at_ledger)
+    );
+
+    count
+}
+
+/// Pays out a scheduled transfer once its ledger is reached.
+/// Anyone can call this ("crank the contract").
+pub fn execute_scheduled(e: &Env, id: u32) {
+    require_not_paused(e);
+    let mut record = get_scheduled(e, id);
+
+    // State & Schedule Validation
+    if record.executed || record.cancelled {
+        panic_with_error!(e, FeatureError::InvalidState);
+    }
+    if e.ledger().sequence() < record.execute_at_ledger {
+        panic_with_error!(e, FeatureError::TooEarly);
+    }
+
+    // Update state
+    record.executed = true;
+    e.storage().persistent().set(&DataKey::Scheduled(id), &record);
+
+    // Move funds from contract to recipient
+    spend_balance(e, e.current_contract_address(), record.amount);
+    sweep::release_native(e, record.amount);
+    receive_balance(e, record.to.clone(), record.amount);
+
+    e.events().publish(
+        (Symbol::new(e, "scheduled"), Symbol::new(e, "executed"), id),
+        record.amount
+    );
+}
+
+/// Sender reclaims the funds of a transfer that has not executed yet.
+pub fn cancel_scheduled(e: &Env, id: u32) {
+    let mut record = get_scheduled(e, id);
+    record.from.require_auth();
+
+    if record.executed || record.cancelled {
+        panic_with_error!(e, FeatureError::InvalidState);
+    }
+
+    // Update state
+    record.cancelled = true;
+    e.storage().persistent().set(&DataKey::Scheduled(id), &record);
+
+    // Move funds from contract back to sender
+    spend_balance(e, e.current_contract_address(), record.amount);
+    sweep::release_native(e, record.amount);
+    receive_balance(e, record.from.clone(), record.amount);
+
+    e.events().publish(
+        (Symbol::new(e, "scheduled"), Symbol::new(e, "cancelled"), id),
+        record.amount
+    );
+}
+
+/// Helper to read a scheduled transfer record
+pub fn get_scheduled(e: &Env, id: u32) -> ScheduledTransfer {
+    e.storage()
+        .persistent()
+        .get(&DataKey::Scheduled(id))
+        .unwrap_or_else(|| panic_with_error!(e, FeatureError::ScheduledNotFound))
+}
@@ -0,0 +1,111 @@
+#[cfg(test)]
+mod scheduled_tests {
+    use crate::error::FeatureError;
+    use crate::{VeritixToken, VeritixTokenClient};
+    use soroban_sdk::{testutils::{Address as _, Ledger}, Address, Env, String};
+
+    fn setup_test(e: &Env) -> (Address, Address, VeritixTokenClient<'_>) {
+        let (_, sender, recipient, client) = setup_with_admin(e);
+        (sender, recipient, client)
+    }
+
+    fn setup_with_admin(e: &Env) -> (Address, Address, Address, VeritixTokenClient<'_>) {
+        e.mock_all_auths();
+
+        let contract_id = e.register_contract(None, VeritixToken);
+        let client = VeritixTokenClient::new(e, &contract_id);
+
+        let admin = Address::generate(e);
+        let sender = Address::generate(e);
+        let recipient = Address::generate(e);
+
+        client.initialize(
+            &admin,
+            &String::from_str(e, "Veritix"),
+            &String::from_str(e, "VTX"),
+            &7u32,
+        );
+        client.mint(&admin, &sender, &10_000i128);
+
+        e.ledger().set_sequence_number(100);
+
+        (admin, sender, recipient, client)
+    }
+
+    #[test]
+    fn test_execute_on_time() {
+        let e = Env::default();
+        let (sender, recipient, client) = setup_test(&e);
+
+        let id = client.schedule_transfer(&sender, &recipient, &750, &200);
+        assert_eq!(client.balance(&sender), 9250);
+        assert_eq!(client.balance(&client.address), 750);
+
+        e.ledger().set_sequence_number(200);
+        client.execute_scheduled(&id);
+
+        assert!(client.get_scheduled(&id).executed);
+        assert_eq!(client.balance(&recipient), 750);
+        assert_eq!(client.balance(&client.address), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #156)")]
+    fn test_execute_early_panics() {
+        let e = Env::default();
+        let (sender, recipient, client) = setup_test(&e);
+
+        let id = client.schedule_transfer(&sender, &recipient, &750, &200);
+        e.ledger().set_sequence_number(199);
+        client.execute_scheduled(&id);
+    }
+
+    #[test]
+    fn test_cancel_refunds_sender() {
+        let e = Env::default();
+        let (sender, recipient, client) = setup_test(&e);
+
+        let id = client.schedule_transfer(&sender, &recipient, &750, &200);
+        client.cancel_scheduled(&id);
+
+        assert!(client.get_scheduled(&id).cancelled);
+        assert_eq!(client.balance(&sender), 10_000);
+        assert_eq!(client.balance(&recipient), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #121)")]
+    fn test_execute_after_cancel_panics() {
+        let e = Env::default();
+        let (sender, recipient, client) = setup_test(&e);
+
+        let id = client.schedule_transfer(&sender, &recipient, &750, &200);
+        client.cancel_scheduled(&id);
+        e.ledger().set_sequence_number(200);
+        client.execute_scheduled(&id);
+    }
+
+    #[test]
+    fn test_frozen_or_blacklisted_sender_cannot_schedule() {
+        let e = Env::default();
+        let (admin, sender, recipient, client) = setup_with_admin(&e);
+        let other = Address::generate(&e);
+        client.mint(&admin, &other, &1_000i128);
+
+        client.freeze(&admin, &sender);
+        assert_eq!(
+            client.try_schedule_transfer(&sender, &recipient, &750, &200),
+            Err(Ok(FeatureError::AccountFrozen.into()))
+        );
+
+        client.blacklist(&admin, &other);
+        assert_eq!(
+            client.try_schedule_transfer(&other, &recipient, &750, &200),
+            Err(Ok(FeatureError::Blacklisted.into()))
+        );
+
+        assert_eq!(client.balance(&sender), 10_000);
+        assert_eq!(client.balance(&other), 1_000);
+        assert_eq!(client.balance(&client.address), 0);
+    }
+}
@@ -0,0 +1,77 @@
+use crate::balance::peek_balance;
+use crate::error::FeatureError;
+use crate::storage_types::DataKey;
+use soroban_sdk::{contracttype, panic_with_error, Address, Env, Symbol, Vec};
+
+/// Balance an address held when snapshot `snapshot_id` was taken.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Checkpoint {
+    pub snapshot_id: u32,
+    pub balance: i128,
+}
+
+pub fn read_snapshot_count(e: &Env) -> u32 {
+    e.storage().instance().get(&DataKey::SnapshotCount).unwrap_or(0)
+}
+
+/// Records a new snapshot and returns its id. Balances are captured lazily:
+/// the first change to an address afterwards stores its prior value.
+pub fn take_snapshot(e: &Env) -> u32 {
+    let id = read_snapshot_count(e) + 1;
+    e.storage().instance().set(&DataKey::SnapshotCount, &id);
+
+    e.events().publish(
+        (Symbol::new(e, "snapshot"), Symbol::new(e, "taken"), id),
+        e.ledger().sequence()
+    );
+
+    id
+}
+
+fn read_checkpoints(e: &Env, addr: &Address) -> Vec<Checkpoint> {
+    e.storage()
+        .persistent()
+        .get(&DataKey::Checkpoints(addr.clone()))
+        .unwrap_or(Vec::new(e))
+}
+
+/// Must be called before an address's balance changes, with the balance it
+/// is about to lose. Only the first change after each snapshot is recorded.
+pub fn checkpoint_balance(e: &Env, addr: &Address, current_balance: i128) {
+    let snapshot_id = read_snapshot_count(e);
+    if snapshot_id == 0 {
+        return;
+    }
+
+    let mut checkpoints = read_checkpoints(e, addr);
+    let already_recorded = match checkpoints.last() {
+        Some(last) => last.snapshot_id >= snapshot_id,
+        None => false,
+    };
+    if !already_recorded {
+        checkpoints.push_back(Checkpoint {
+            snapshot_id,
+            balance: current_balance,
+        });
+        e.storage()
+            .persistent()
+            .set(&DataKey::Checkpoints(addr.clone()), &checkpoints);
+    }
+}
+
+/// Balance held by `addr` at the moment snapshot `snapshot_id` was taken.
+pub fn balance_at(e: &Env, addr: Address, snapshot_id: u32) -> i128 {
+    if snapshot_id == 0 || snapshot_id > read_snapshot_count(e) {
+        panic_with_error!(e, FeatureError::InvalidSnapshot);
+    }
+
+    // The first checkpoint at or after the snapshot holds the value the
+    // address had at that snapshot. None means it has not changed since.
+    for checkpoint in read_checkpoints(e, &addr).iter() {
+        if checkpoint.snapshot_id >= snapshot_id {
+            return checkpoint.balance;
+        }
+    }
+    peek_balance(e, addr)
+}
@@ -0,0 +1,76 @@
+#[cfg(test)]
+mod snapshot_tests {
+    use crate::{VeritixToken, VeritixTokenClient};
+    use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+    fn setup_test(e: &Env) -> (Address, Address, Address, VeritixTokenClient<'_>) {
+        e.mock_all_auths();
+
+        let contract_id = e.register_contract(None, VeritixToken);
+        let client = VeritixTokenClient::new(e, &contract_id);
+
+        let admin = Address::generate(e);
+        let alice = Address::generate(e);
+        let bob = Address::generate(e);
+
+        client.initialize(
+            &admin,
+            &String::from_str(e, "Veritix"),
+            &String::from_str(e, "VTX"),
+            &7u32,
+        );
+        client.mint(&admin, &alice, &1000i128);
+
+        (admin, alice, bob, client)
+    }
+
+    #[test]
+    fn test_snapshot_unchanged_by_later_transfers() {
+        let e = Env::default();
+        let (admin, alice, bob, client) = setup_test(&e);
+
+        let snapshot = client.take_snapshot(&admin);
+        client.transfer(&alice, &bob, &400);
+        client.transfer(&alice, &bob, &100);
+
+        assert_eq!(client.voting_power_at(&alice, &snapshot), 1000);
+        assert_eq!(client.voting_power_at(&bob, &snapshot), 0);
+        assert_eq!(client.balance(&alice), 500);
+        assert_eq!(client.balance(&bob), 500);
+    }
+
+    #[test]
+    fn test_multiple_snapshots() {
+        let e = Env::default();
+        let (admin, alice, bob, client) = setup_test(&e);
+
+        let first = client.take_snapshot(&admin);
+        client.transfer(&alice, &bob, &300);
+        let second = client.take_snapshot(&admin);
+        client.transfer(&bob, &alice, &100);
+
+        assert_eq!(client.voting_power_at(&alice, &first), 1000);
+        assert_eq!(client.voting_power_at(&alice, &second), 700);
+        assert_eq!(client.voting_power_at(&bob, &first), 0);
+        assert_eq!(client.voting_power_at(&bob, &second), 300);
+    }
+
+    #[test]
+    fn test_snapshot_without_changes_reads_current_balance() {
+        let e = Env::default();
+        let (admin, alice, _, client) = setup_test(&e);
+
+        let snapshot = client.take_snapshot(&admin);
+
+        assert_eq!(client.voting_power_at(&alice, &snapshot), 1000);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #116)")]
+    fn test_unknown_snapshot_panics() {
+        let e = Env::default();
+        let (_, alice, _, client) = setup_test(&e);
+
+        client.voting_power_at(&alice, &1);
+    }
+}
@@ -1,6 +1,15 @@
-use crate::balance::{receive_balance, spend_balance};
-use crate::storage_types::DataKey;
-use soroban_sdk::{contracttype, Address, Env, Symbol, Vec};
+use crate::balance::{lock_balance, receive_balance, spend_balance, unlock_balance};
+use crate::error::Error;
+use crate::freeze::require_can_send;
+use crate::pause::require_not_paused;
+use crate::storage_types::{DataKey, SplitterKey};
+use crate::sweep;
+use soroban_sdk::{contracttype, panic_with_error, token, Address, Env, Map, Symbol, Vec};
+
+/// Hard upper bound on recipients per split or multi-escrow, so a full
+/// distribution loop always fits within a single transaction's budget.
+/// The admin can lower the working limit (see `write_max_recipients`).
+pub const MAX_RECIPIENTS: u32 = 20;
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -9,6 +18,16 @@ pub struct SplitRecipient {
     pub share_bps: u32, // 10000 bps = 100%
 }
 
+/// Which recipient absorbs the rounding dust when no `remainder_to` is set.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DustStrategy {
+    LastRecipient,
+    FirstRecipient,
+    /// The recipient with the largest share; the first of them on a tie.
+    ProRataLargest,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct SplitRecord {
@@ -17,6 +36,148 @@ pub struct SplitRecord {
     pub recipients: Vec<SplitRecipient>,
     pub total_amount: i128,
     pub distributed: bool,
+    /// Amount still owed to each recipient, for O(1) lookups in
+    /// `claim_split`. Entries are removed once claimed.
+    pub allocations: Map<Address, i128>,
+    pub claimed_amount: i128,
+    /// Receives the rounding dust instead of the last recipient, if set.
+    pub remainder_to: Option<Address>,
+    /// Picks the recipient that absorbs the dust when `remainder_to` is unset.
+    pub dust_strategy: DustStrategy,
+    /// Opt-in pull mode: `distribute` opens the split for `claim_split`
+    /// instead of pushing transfers.
+    pub claimable: bool,
+    /// Whether a claimable split has been opened by `distribute`.
+    pub claims_open: bool,
+    /// Absolute amounts parallel to `recipients` for a fixed-amount split,
+    /// whose recipients then carry 0 bps; empty for a bps split.
+    pub fixed_amounts: Vec<i128>,
+    /// SEP-41 token the split holds, or `None` for this token.
+    pub token: Option<Address>,
+}
+
+/// Recipient limit for splits and multi-escrows; `MAX_RECIPIENTS` unless
+/// the admin has lowered it.
+pub fn read_max_recipients(e: &Env) -> u32 {
+    e.storage().instance().get(&SplitterKey::MaxRecipients).unwrap_or(MAX_RECIPIENTS)
+}
+
+/// Stores the recipient limit. Panics with `InvalidAmount` unless it is
+/// between 1 and `MAX_RECIPIENTS`.
+/// Caller is responsible for admin authorization (see `check_admin`).
+pub fn write_max_recipients(e: &Env, max: u32) {
+    if max == 0 || max > MAX_RECIPIENTS {
+        panic_with_error!(e, Error::InvalidAmount);
+    }
+    e.storage().instance().set(&SplitterKey::MaxRecipients, &max);
+}
+
+/// Panics unless the recipient list is non-empty, within the recipient
+/// limit and free of duplicate addresses, and every share is non-zero
+/// with the shares summing to exactly 10000 bps (100.00%).
+pub fn validate_bps(recipients: &Vec<SplitRecipient>) {
+    let e = recipients.env();
+    // Bound the list before walking it
+    if recipients.len() > read_max_recipients(e) {
+        panic_with_error!(e, Error::TooManyRecipients);
+    }
+
+    let mut addresses = Vec::new(e);
+    let mut total_bps: u32 = 0;
+    for recipient in recipients.iter() {
+        if recipient.share_bps == 0 {
+            panic_with_error!(e, Error::InvalidBps);
+        }
+        total_bps = total_bps.saturating_add(recipient.share_bps);
+        addresses.push_back(recipient.address);
+    }
+    validate_recipients(e, &addresses);
+
+    if total_bps != 10000 {
+        panic_with_error!(e, Error::InvalidBps);
+    }
+}
+
+/// Panics on an empty list (`RecipientNotFound`), one beyond
+/// `read_max_recipients` (`TooManyRecipients`), or one that names the same
+/// address twice (`InvalidBeneficiary`).
+pub fn validate_recipients(e: &Env, addresses: &Vec<Address>) {
+    if addresses.is_empty() {
+        panic_with_error!(e, Error::RecipientNotFound);
+    }
+    if addresses.len() > read_max_recipients(e) {
+        panic_with_error!(e, Error::TooManyRecipients);
+    }
+
+    let mut seen: Map<Address, ()> = Map::new(e);
+    for address in addresses.iter() {
+        if seen.contains_key(address.clone()) {
+            panic_with_error!(e, Error::InvalidBeneficiary);
+        }
+        seen.set(address, ());
+    }
+}
+
+/// Computes each recipient's share of `total_amount`, in recipient order.
+/// The last recipient gets everything left to avoid rounding dust.
+pub fn calculate_distribution(
+    e: &Env,
+    total_amount: i128,
+    recipients: &Vec<SplitRecipient>,
+) -> Vec<i128> {
+    let mut amounts = Vec::new(e);
+    let mut remaining_amount = total_amount;
+    let len = recipients.len();
+
+    for (i, recipient) in recipients.iter().enumerate() {
+        let amount = if i == (len as usize - 1) {
+            remaining_amount
+        } else {
+            (total_amount * recipient.share_bps as i128) / 10000
+        };
+        amounts.push_back(amount);
+        remaining_amount -= amount;
+    }
+
+    amounts
+}
+
+/// Per-recipient amounts for a split, plus the dust owed to `remainder_to`.
+/// Without a remainder recipient the recipient picked by `strategy` absorbs
+/// the dust and the returned dust is 0.
+fn split_amounts(
+    e: &Env,
+    total_amount: i128,
+    recipients: &Vec<SplitRecipient>,
+    remainder_to: &Option<Address>,
+    strategy: DustStrategy,
+) -> (Vec<i128>, i128) {
+    let mut amounts = Vec::new(e);
+    let mut dust = total_amount;
+    for recipient in recipients.iter() {
+        let amount = (total_amount * recipient.share_bps as i128) / 10000;
+        amounts.push_back(amount);
+        dust -= amount;
+    }
+    if remainder_to.is_some() {
+        return (amounts, dust);
+    }
+
+    let index = match strategy {
+        DustStrategy::LastRecipient => recipients.len() - 1,
+        DustStrategy::FirstRecipient => 0,
+        DustStrategy::ProRataLargest => {
+            let mut largest = 0;
+            for (i, recipient) in recipients.iter().enumerate() {
+                if recipient.share_bps > recipients.get(largest).unwrap().share_bps {
+                    largest = i as u32;
+                }
+            }
+            largest
+        }
+    };
+    amounts.set(index, amounts.get(index).unwrap() + dust);
+    (amounts, 0)
 }
 
 pub fn create_split(
@@ -24,36 +185,212 @@ pub fn create_split(
     sender: Address,
     recipients: Vec<SplitRecipient>,
     total_amount: i128,
+    remainder_to: Option<Address>,
 ) -> u32 {
+    create_split_with_strategy(
+        e,
+        sender,
+        recipients,
+        total_amount,
+        remainder_to,
+        DustStrategy::LastRecipient,
+    )
+}
+
+/// Like `create_split`, with a choice of which recipient absorbs the
+/// rounding dust when there is no `remainder_to`.
+pub fn create_split_with_strategy(
+    e: &Env,
+    sender: Address,
+    recipients: Vec<SplitRecipient>,
+    total_amount: i128,
+    remainder_to: Option<Address>,
+    dust_strategy: DustStrategy,
+) -> u32 {
+    let record = build_split(e, sender, recipients, total_amount, remainder_to, dust_strategy);
+    lock_split(e, record)
+}
+
+/// Like `create_split`, but recipients pull their own shares: `distribute`
+/// only records that the shares are claimable, so the sender's call stays
+/// cheap however many recipients the split has.
+pub fn create_claimable_split(
+    e: &Env,
+    sender: Address,
+    recipients: Vec<SplitRecipient>,
+    total_amount: i128,
+    remainder_to: Option<Address>,
+) -> u32 {
+    let mut record =
+        build_split(e, sender, recipients, total_amount, remainder_to, DustStrategy::LastRecipient);
+    record.claimable = true;
+    lock_split(e, record)
+}
+
+/// Like `create_split`, but the split holds and pays out `total_amount` of
+/// the SEP-41 token at `token_address` (e.g. USDC) instead of this token.
+pub fn create_token_split(
+    e: &Env,
+    sender: Address,
+    recipients: Vec<SplitRecipient>,
+    total_amount: i128,
+    token_address: Address,
+    remainder_to: Option<Address>,
+) -> u32 {
+    if token_address == e.current_contract_address() {
+        panic_with_error!(e, Error::InvalidToken);
+    }
+    let mut record =
+        build_split(e, sender, recipients, total_amount, remainder_to, DustStrategy::LastRecipient);
+    record.token = Some(token_address);
+    lock_split(e, record)
+}
+
+/// Validates a bps split and works out each recipient's allocation. The
+/// record is returned unstored, with no ID, for `lock_split`.
+fn build_split(
+    e: &Env,
+    sender: Address,
+    recipients: Vec<SplitRecipient>,
+    total_amount: i128,
+    remainder_to: Option<Address>,
+    dust_strategy: DustStrategy,
+) -> SplitRecord {
     sender.require_auth();
+    require_not_paused(e);
+    if total_amount <= 0 {
+        panic_with_error!(e, Error::InvalidAmount);
+    }
 
     // 1. Validate BPS Sums to 10000 (100.00%)
-    let mut total_bps: u32 = 0;
-    for recipient in recipients.iter() {
-        total_bps += recipient.share_bps;
+    validate_bps(&recipients);
+
+    // 2. Work out what each recipient is owed
+    let mut allocations: Map<Address, i128> = Map::new(e);
+    let (amounts, dust) =
+        split_amounts(e, total_amount, &recipients, &remainder_to, dust_strategy);
+    for (recipient, amount) in recipients.iter().zip(amounts.iter()) {
+        let owed = allocations.get(recipient.address.clone()).unwrap_or(0);
+        allocations.set(recipient.address, owed + amount);
     }
-    if total_bps != 10000 {
-        panic!("total bps must equal 10000");
+    if let Some(remainder) = remainder_to.clone() {
+        if dust > 0 {
+            let owed = allocations.get(remainder.clone()).unwrap_or(0);
+            allocations.set(remainder, owed + dust);
+        }
     }
+    SplitRecord {
+        id: 0,
+        sender,
+        recipients,
+        total_amount,
+        distributed: false,
+        allocations,
+        claimed_amount: 0,
+        remainder_to,
+        dust_strategy,
+        claimable: false,
+        claims_open: false,
+        fixed_amounts: Vec::new(e),
+        token: None,
+    }
+}
+
+/// Creates a split where recipient `i` is owed exactly `amounts[i]` rather
+/// than a bps share. What is left of `total_amount` goes to `remainder_to`
+/// (e.g. the sender, to get it back) or else to the last recipient.
+pub fn create_fixed_split(
+    e: &Env,
+    sender: Address,
+    recipients: Vec<Address>,
+    amounts: Vec<i128>,
+    total_amount: i128,
+    remainder_to: Option<Address>,
+) -> u32 {
+    sender.require_auth();
+    require_not_paused(e);
+    if total_amount <= 0 {
+        panic_with_error!(e, Error::InvalidAmount);
+    }
+    if amounts.len() != recipients.len() {
+        panic_with_error!(e, Error::LengthMismatch);
+    }
+    validate_recipients(e, &recipients);
+
+    // 1. Fixed amounts must be positive and fit within the total
+    let mut allocations: Map<Address, i128> = Map::new(e);
+    let mut allocated: i128 = 0;
+    for (recipient, amount) in recipients.iter().zip(amounts.iter()) {
+        if amount <= 0 {
+            panic_with_error!(e, Error::InvalidAmount);
+        }
+        allocated = allocated
+            .checked_add(amount)
+            .filter(|sum| *sum <= total_amount)
+            .unwrap_or_else(|| panic_with_error!(e, Error::FixedSharesExceedTotal));
+        allocations.set(recipient, amount);
+    }
+    let remainder = total_amount - allocated;
+    if remainder > 0 {
+        let to = remainder_to.clone().unwrap_or_else(|| recipients.get(recipients.len() - 1).unwrap());
+        let owed = allocations.get(to.clone()).unwrap_or(0);
+        allocations.set(to, owed + remainder);
+    }
+
+    let mut shares = Vec::new(e);
+    for address in recipients.iter() {
+        shares.push_back(SplitRecipient { address, share_bps: 0 });
+    }
+    lock_split(
+        e,
+        SplitRecord {
+            id: 0,
+            sender,
+            recipients: shares,
+            total_amount,
+            distributed: false,
+            allocations,
+            claimed_amount: 0,
+            remainder_to,
+            dust_strategy: DustStrategy::LastRecipient,
+            claimable: false,
+            claims_open: false,
+            fixed_amounts: amounts,
+            token: None,
+        },
+    )
+}
 
-    // 2. Increment and get Split ID
+/// Moves the split's total from the sender into the contract, assigns the
+/// next split ID and stores the record. Returns the new ID.
+fn lock_split(e: &Env, mut record: SplitRecord) -> u32 {
+    require_can_send(e, &record.sender);
+
+    // 1. Increment and get Split ID
     let mut count: u32 = e.storage().instance().get(&DataKey::SplitCount).unwrap_or(0);
     count += 1;
     e.storage().instance().set(&DataKey::SplitCount, &count);
 
-    // 3. Move funds from sender to contract
-    // Note: Assuming contract address is e.current_contract_address()
-    spend_balance(e, sender.clone(), total_amount);
-    receive_balance(e, e.current_contract_address(), total_amount);
+    // 2. Move funds from sender to contract
+    match &record.token {
+        Some(token_address) => {
+            token::Client::new(e, token_address).transfer(
+                &record.sender,
+                &e.current_contract_address(),
+                &record.total_amount,
+            );
+            sweep::hold_in_token(e, token_address, record.total_amount);
+        }
+        None => {
+            spend_balance(e, record.sender.clone(), record.total_amount);
+            receive_balance(e, e.current_contract_address(), record.total_amount);
+            sweep::hold_native(e, record.total_amount);
+            lock_balance(e, record.sender.clone(), record.total_amount);
+        }
+    }
 
-    // 4. Store record
-    let record = SplitRecord {
-        id: count,
-        sender,
-        recipients,
-        total_amount,
-        distributed: false,
-    };
+    // 3. Store record
+    record.id = count;
     e.storage().persistent().set(&DataKey::Split(count), &record);
 
     count
@@ -62,41 +399,55 @@ pub fn create_split(
 pub fn distribute(e: &Env, caller: Address, split_id: u32) {
     caller.require_auth();
 
-    let mut record: SplitRecord = e
-        .storage()
-        .persistent()
-        .get(&DataKey::Split(split_id))
-        .expect("split record not found");
+    let mut record = get_split(e, split_id);
 
     // 1. Rules: Caller must be sender, cannot distribute twice
     if record.sender != caller {
-        panic!("unauthorized");
+        panic_with_error!(e, Error::Unauthorized);
     }
-    if record.distributed {
-        panic!("already distributed");
+    if record.distributed || record.claims_open {
+        panic_with_error!(e, Error::AlreadyDistributed);
+    }
+    if record.total_amount == 0 {
+        panic_with_error!(e, Error::NothingToDistribute);
     }
 
-    let mut remaining_amount = record.total_amount;
-    let len = record.recipients.len();
+    // A claimable split leaves the recorded allocations for recipients to pull
+    if record.claimable {
+        record.claims_open = true;
+        e.storage().persistent().set(&DataKey::Split(split_id), &record);
+        e.events().publish(
+            (Symbol::new(e, "split"), Symbol::new(e, "claims_open"), split_id),
+            record.total_amount - record.claimed_amount
+        );
+        return;
+    }
 
-    // 2. Proportional Distribution
-    for (i, recipient) in record.recipients.iter().enumerate() {
-        let amount_to_send = if i == (len as usize - 1) {
-            // Last recipient gets everything left to avoid rounding dust
-            remaining_amount
-        } else {
-            (record.total_amount * recipient.share_bps as i128) / 10000
+    // 2. Pay every allocation still owed in the creator's recipient order,
+    // then any separate remainder address; recipients who already claimed
+    // have no entry left, and a repeated address is paid once
+    let mut owed = record.allocations.clone();
+    let payees = record.recipients.iter().map(|r| r.address).chain(record.remainder_to.clone());
+    for recipient in payees {
+        let Some(amount_to_send) = owed.get(recipient.clone()) else {
+            continue;
         };
+        owed.remove(recipient.clone());
 
         // Transfer from contract to recipient
-        spend_balance(e, e.current_contract_address(), amount_to_send);
-        receive_balance(e, recipient.address.clone(), amount_to_send);
-        
-        remaining_amount -= amount_to_send;
+        pay_from_split(e, &record, &recipient, amount_to_send);
+        e.events().publish(
+            (Symbol::new(e, "split"), Symbol::new(e, "paid"), split_id),
+            (recipient, amount_to_send)
+        );
     }
 
     // 3. Mark distributed
+    if record.token.is_none() {
+        unlock_balance(e, record.sender.clone(), record.total_amount - record.claimed_amount);
+    }
     record.distributed = true;
+    record.allocations = Map::new(e);
     e.storage().persistent().set(&DataKey::Split(split_id), &record);
 
     // 4. Emit Observability Event
@@ -106,9 +457,65 @@ pub fn distribute(e: &Env, caller: Address, split_id: u32) {
     );
 }
 
+/// Lets a recipient pull their share before the sender distributes, or
+/// once a claimable split has been distributed. Once every recipient has
+/// claimed, the split counts as distributed.
+pub fn claim_split(e: &Env, split_id: u32, recipient: Address) -> i128 {
+    recipient.require_auth();
+
+    let mut record = get_split(e, split_id);
+    if record.distributed {
+        panic_with_error!(e, Error::AlreadyDistributed);
+    }
+    if record.claimable && !record.claims_open {
+        panic_with_error!(e, Error::ClaimsNotOpen);
+    }
+    let amount = record
+        .allocations
+        .get(recipient.clone())
+        .unwrap_or_else(|| panic_with_error!(e, Error::RecipientNotFound));
+
+    // Update state before moving funds
+    record.allocations.remove(recipient.clone());
+    record.claimed_amount += amount;
+    if record.allocations.is_empty() {
+        record.distributed = true;
+    }
+    e.storage().persistent().set(&DataKey::Split(split_id), &record);
+
+    if record.token.is_none() {
+        unlock_balance(e, record.sender.clone(), amount);
+    }
+    pay_from_split(e, &record, &recipient, amount);
+
+    e.events().publish(
+        (Symbol::new(e, "split"), Symbol::new(e, "claimed"), split_id),
+        (recipient, amount)
+    );
+
+    amount
+}
+
+/// Pays `amount` of the split's funds to `to`, in the split's token.
+fn pay_from_split(e: &Env, record: &SplitRecord, to: &Address, amount: i128) {
+    match &record.token {
+        Some(token_address) => {
+            sweep::release_in_token(e, token_address, amount);
+            token::Client::new(e, token_address).transfer(&e.current_contract_address(), to, &amount)
+        }
+        None => {
+            spend_balance(e, e.current_contract_address(), amount);
+            sweep::release_native(e, amount);
+            receive_balance(e, to.clone(), amount);
+        }
+    }
+}
+
 pub fn get_split(e: &Env, split_id: u32) -> SplitRecord {
-    e.storage()
-        .persistent()
-        .get(&DataKey::Split(split_id))
-        .expect("split record not found")
+    try_get_split(e, split_id).unwrap_or_else(|| panic_with_error!(e, Error::SplitNotFound))
+}
+
+/// Reads a split record, returning `None` if the id does not exist.
+pub fn try_get_split(e: &Env, split_id: u32) -> Option<SplitRecord> {
+    e.storage().persistent().get(&DataKey::Split(split_id))
 }
\ No newline at end of file
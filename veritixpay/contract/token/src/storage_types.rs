@@ -1,9 +1,12 @@
+use crate::roles::Role;
 use soroban_sdk::{contracttype, Address};
 
 pub const BALANCE_LIFETIME_THRESHOLD: u32 = 518400; // ~30 days
 pub const BALANCE_BUMP_AMOUNT: u32 = 535000;
 pub const INSTANCE_LIFETIME_THRESHOLD: u32 = 518400;
 pub const INSTANCE_BUMP_AMOUNT: u32 = 535000;
+pub const DAY_IN_LEDGERS: u32 = 17280; // ~5s per ledger
+pub const DEFAULT_ALLOWANCE_WINDOW: u32 = DAY_IN_LEDGERS;
 
 #[derive(Clone)]
 #[contracttype]
@@ -19,26 +22,171 @@ pub struct AllowanceValue {
     pub expiration_ledger: u32,
 }
 
+/// Contract-wide fee schedule. Basis-point fees are out of 10000.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct FeeConfig {
+    pub escrow_bps: u32,
+    pub recurring_bps: u32,
+    pub dispute_fee: i128,
+}
+
+/// Fee taken from every `transfer`/`transfer_from`. With no collector the
+/// fee is burned.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct TransferFee {
+    pub bps: u32,
+    pub collector: Option<Address>,
+    /// Share of the fee, out of 10000, reflected to holders instead.
+    pub reflection_bps: u32,
+}
+
 #[derive(Clone)]
 #[contracttype]
 pub enum DataKey {
     Admin,
+    PendingAdmin,
     Allowance(AllowanceDataKey),
+    DefaultAllowanceWindow,
     Balance(Address),
     Metadata,
+    TotalSupply,
     EscrowCount,
     Escrow(u32),
+    ActiveEscrowCount,
     RecurringCount,
     Recurring(u32),
     SplitCount,
     Split(u32),
     DisputeCount,
     Dispute(u32),
+    Evidence(u32),
+    ResolverStats(Address),
     
     // --- Added for Multi-Escrow (Issue #36) ---
     MultiEscrowCount,
     MultiEscrow(u32),
+    MultiClaimable(u32, Address),
     
     // --- Added for Freeze Functionality (Issue #35) ---
     Freeze(Address),
-}
\ No newline at end of file
+    FrozenAccounts,
+    Blacklist(Address),
+
+    // --- Added for Fee Schedule ---
+    FeeConfig,
+    TransferFee,
+
+    // --- Added for Reflection Rewards ---
+    RewardPerToken,
+    ReflectionPool,
+    RewardDebt(Address),
+
+    // --- Added for Dispute Appeals ---
+    AppealResolver,
+
+    // --- Added for Transfer Rate Limiting ---
+    RateLimit(Address),
+    RateUsage(Address),
+    MintLimit,
+    MintUsage,
+    MintFinalized,
+    SupplyCap,
+
+    // --- Added for Emergency Pause ---
+    Paused,
+
+    // --- Added for Role-Based Access ---
+    Role(Role, Address),
+
+    // --- Added for Multisig Admin ---
+    MultisigConfig,
+    AdminActionCount,
+    AdminAction(u32),
+
+    // --- Added for Balance Snapshots ---
+    SnapshotCount,
+    Checkpoints(Address),
+
+    // --- Added for Vote Delegation ---
+    Delegate(Address),
+    DelegatedVotes(Address),
+
+    // --- Added for Scheduled Transfers ---
+    ScheduledCount,
+    Scheduled(u32),
+
+    // --- Added for Hashlock Escrow (HTLC) ---
+    HashlockCount,
+    Hashlock(u32),
+}
+
+/// Keys for the admin timelock. `DataKey` is close to the contract spec's
+/// limit of 50 union cases, so newer subsystems get their own key enum.
+/// Variant names must not repeat a `DataKey` variant.
+#[derive(Clone)]
+#[contracttype]
+pub enum TimelockKey {
+    AdminDelay,
+    QueuedCount,
+    Queued(u32),
+}
+
+/// Keys for per-address accounting kept alongside `DataKey::Balance`.
+#[derive(Clone)]
+#[contracttype]
+pub enum BalanceKey {
+    /// Funds the address has locked in unsettled escrows and splits.
+    Locked(Address),
+}
+
+/// Keys for the contract's custody of other SEP-41 tokens.
+#[derive(Clone)]
+#[contracttype]
+pub enum CustodyKey {
+    /// Amount of the token at this address owed to open escrows, splits and
+    /// hashlock escrows.
+    Held(Address),
+}
+
+/// Keys for looking up escrows by participant.
+#[derive(Clone)]
+#[contracttype]
+pub enum EscrowIndexKey {
+    /// Ids of the escrows an address is depositor or beneficiary of, in
+    /// creation order.
+    EscrowsOf(Address),
+}
+
+/// Keys for milestone escrows.
+#[derive(Clone)]
+#[contracttype]
+pub enum MilestoneKey {
+    MilestoneCount,
+    MilestoneEscrow(u32),
+}
+
+/// Keys for splitter settings.
+#[derive(Clone)]
+#[contracttype]
+pub enum SplitterKey {
+    /// Admin-set cap on recipients per split or multi-escrow.
+    MaxRecipients,
+}
+
+/// Keys for dispute settings added after `DataKey` filled up.
+#[derive(Clone)]
+#[contracttype]
+pub enum DisputeKey {
+    /// Amount a claimant locks when opening a dispute.
+    DisputeBond,
+    /// A panel arbiter's vote; true releases to the beneficiary.
+    PanelVote(u32, Address),
+    /// Senior panel that appealed disputes escalate to.
+    AppealPanel,
+    /// Unsettled dispute holding an escrow; present until it settles.
+    EscrowDispute(u32),
+    /// Sum of the bonds locked by unsettled disputes.
+    HeldBonds,
+}
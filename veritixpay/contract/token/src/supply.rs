@@ -0,0 +1,73 @@
+use crate::balance::read_total_supply;
+use crate::error::FeatureError;
+use crate::storage_types::DataKey;
+use soroban_sdk::{panic_with_error, Env, Symbol};
+
+/// Largest recipient list accepted by `mint_batch`.
+pub const MAX_MINT_BATCH: u32 = 100;
+
+pub fn is_mint_finalized(e: &Env) -> bool {
+    e.storage()
+        .instance()
+        .get(&DataKey::MintFinalized)
+        .unwrap_or(false)
+}
+
+/// Turns minting off permanently. There is deliberately no way to undo this.
+/// Caller is responsible for admin authorization (see `check_admin`).
+pub fn finalize_supply(e: &Env) {
+    e.storage().instance().set(&DataKey::MintFinalized, &true);
+
+    e.events().publish(
+        (Symbol::new(e, "supply"), Symbol::new(e, "finalized")),
+        read_total_supply(e)
+    );
+}
+
+/// Panics once `finalize_supply` has run. Every mint path must call this.
+pub fn require_minting_enabled(e: &Env) {
+    if is_mint_finalized(e) {
+        panic_with_error!(e, FeatureError::MintingDisabled);
+    }
+}
+
+/// Maximum total supply; 0 means uncapped.
+pub fn read_supply_cap(e: &Env) -> i128 {
+    e.storage().instance().get(&DataKey::SupplyCap).unwrap_or(0)
+}
+
+/// Written at initialization; afterwards only `lower_supply_cap` changes it.
+pub fn write_supply_cap(e: &Env, max_supply: i128) {
+    e.storage().instance().set(&DataKey::SupplyCap, &max_supply);
+}
+
+/// Tightens the cap. It can never be raised or removed again, nor set below
+/// the current total supply. An uncapped token may adopt any valid cap.
+/// Caller is responsible for admin authorization (see `check_admin`).
+pub fn lower_supply_cap(e: &Env, new_cap: i128) {
+    let cap = read_supply_cap(e);
+    if new_cap <= 0 || (cap > 0 && new_cap >= cap) {
+        panic_with_error!(e, FeatureError::InvalidSupplyCap);
+    }
+    if new_cap < read_total_supply(e) {
+        panic_with_error!(e, FeatureError::InvalidSupplyCap);
+    }
+    write_supply_cap(e, new_cap);
+
+    e.events().publish(
+        (Symbol::new(e, "supply"), Symbol::new(e, "cap_lowered")),
+        new_cap
+    );
+}
+
+/// Panics if minting `amount` would push total supply past the cap.
+pub fn require_within_cap(e: &Env, amount: i128) {
+    let cap = read_supply_cap(e);
+    let exceeds = match read_total_supply(e).checked_add(amount) {
+        Some(supply) => supply > cap,
+        None => true,
+    };
+    if cap > 0 && exceeds {
+        panic_with_error!(e, FeatureError::SupplyCapExceeded);
+    }
+}
@@ -0,0 +1,110 @@
+use crate::balance::{peek_balance, receive_balance, spend_balance};
+use crate::error::{Error, FeatureError};
+use crate::storage_types::CustodyKey;
+use soroban_sdk::{panic_with_error, token, Address, Env, Symbol};
+
+/// How much of this token the contract itself custodies.
+pub fn contract_balance(e: &Env) -> i128 {
+    peek_balance(e, e.current_contract_address())
+}
+
+/// How much of `token_address` the contract holds; this token is read from
+/// the internal ledger, any other through its SEP-41 `balance`.
+pub fn contract_token_balance(e: &Env, token_address: Address) -> i128 {
+    if token_address == e.current_contract_address() {
+        contract_balance(e)
+    } else {
+        token::Client::new(e, &token_address).balance(&e.current_contract_address())
+    }
+}
+
+/// Sum of the SEP-41 `token_address` still owed by open escrows, splits and
+/// hashlock escrows. Kept as a running total, so it costs one read.
+pub fn held_in_token(e: &Env, token_address: &Address) -> i128 {
+    e.storage()
+        .persistent()
+        .get(&CustodyKey::Held(token_address.clone()))
+        .unwrap_or(0)
+}
+
+/// Records `amount` of `token_address` taken into custody for a record.
+pub fn hold_in_token(e: &Env, token_address: &Address, amount: i128) {
+    let held = held_in_token(e, token_address)
+        .checked_add(amount)
+        .unwrap_or_else(|| panic_with_error!(e, Error::BalanceOverflow));
+    e.storage()
+        .persistent()
+        .set(&CustodyKey::Held(token_address.clone()), &held);
+}
+
+/// Releases `amount` of `token_address` once it is paid out of custody.
+/// Saturating, as records made before the total existed are untracked.
+pub fn release_in_token(e: &Env, token_address: &Address, amount: i128) {
+    let key = CustodyKey::Held(token_address.clone());
+    let held = (held_in_token(e, token_address) - amount).max(0);
+    if held == 0 {
+        e.storage().persistent().remove(&key);
+    } else {
+        e.storage().persistent().set(&key, &held);
+    }
+}
+
+/// Records `amount` of this token taken into custody for an escrow, split,
+/// scheduled transfer or hashlock escrow. Tracked under the contract's own
+/// address in `CustodyKey::Held`.
+pub fn hold_native(e: &Env, amount: i128) {
+    hold_in_token(e, &e.current_contract_address(), amount);
+}
+
+/// Releases `amount` of this token once it is paid out of custody.
+pub fn release_native(e: &Env, amount: i128) {
+    release_in_token(e, &e.current_contract_address(), amount);
+}
+
+/// How much of the SEP-41 `token_address` the contract holds beyond what
+/// open escrows, splits and hashlock escrows in that token are owed.
+pub fn external_surplus(e: &Env, token_address: &Address) -> i128 {
+    contract_token_balance(e, token_address.clone()) - held_in_token(e, token_address)
+}
+
+/// Sum of this token still owed by open escrows, multi-escrows, milestone
+/// escrows, splits, scheduled transfers, hashlock escrows, unclaimed
+/// reflections and unsettled dispute bonds. Each part is a running total,
+/// so this costs three reads however many records exist.
+pub fn locked_obligations(e: &Env) -> i128 {
+    held_in_token(e, &e.current_contract_address())
+        + crate::reflection::read_reflection_pool(e)
+        + crate::dispute::held_bonds(e)
+}
+
+/// Withdraws stranded funds held by the contract to `to`.
+///
+/// For this token, only the surplus above `locked_obligations` can be swept.
+/// For any other SEP-41 `token_address`, only the balance above what open
+/// escrows, splits and hashlock escrows in that token hold can be swept.
+/// Caller is responsible for admin authorization (see `check_admin`).
+pub fn admin_sweep(e: &Env, token_address: Address, amount: i128, to: Address) {
+    if amount <= 0 {
+        panic_with_error!(e, Error::InvalidAmount);
+    }
+
+    let contract = e.current_contract_address();
+    if token_address == contract {
+        let surplus = contract_balance(e) - locked_obligations(e);
+        if amount > surplus {
+            panic_with_error!(e, FeatureError::SweepExceedsSurplus);
+        }
+        spend_balance(e, contract, amount);
+        receive_balance(e, to.clone(), amount);
+    } else {
+        if amount > external_surplus(e, &token_address) {
+            panic_with_error!(e, FeatureError::SweepExceedsSurplus);
+        }
+        token::Client::new(e, &token_address).transfer(&contract, &to, &amount);
+    }
+
+    e.events().publish(
+        (Symbol::new(e, "admin"), Symbol::new(e, "swept"), token_address),
+        (to, amount)
+    );
+}
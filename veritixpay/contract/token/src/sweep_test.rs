@@ -0,0 +1,215 @@
+#[cfg(test)]
+mod sweep_tests {
+    use crate::error::FeatureError;
+    use crate::roles::Role;
+    use crate::splitter::SplitRecipient;
+    use crate::{VeritixToken, VeritixTokenClient};
+    use soroban_sdk::{testutils::Address as _, token, vec, Address, Bytes, BytesN, Env, String};
+
+    fn setup_test(e: &Env) -> (Address, Address, VeritixTokenClient<'_>) {
+        e.mock_all_auths();
+
+        let contract_id = e.register_contract(None, VeritixToken);
+        let client = VeritixTokenClient::new(e, &contract_id);
+
+        let admin = Address::generate(e);
+        let user = Address::generate(e);
+
+        client.initialize(
+            &admin,
+            &String::from_str(e, "Veritix"),
+            &String::from_str(e, "VTX"),
+            &7u32,
+        );
+        client.mint(&admin, &user, &10_000i128);
+
+        (admin, user, client)
+    }
+
+    #[test]
+    fn test_sweep_stranded_balance_to_admin() {
+        let e = Env::default();
+        let (admin, user, client) = setup_test(&e);
+
+        // Funds sent straight to the contract are tied to no record
+        client.transfer(&user, &client.address, &300);
+        client.admin_sweep(&admin, &client.address, &300, &admin);
+
+        assert_eq!(client.balance(&admin), 300);
+        assert_eq!(client.balance(&client.address), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #115)")]
+    fn test_sweep_cannot_touch_escrowed_funds() {
+        let e = Env::default();
+        let (admin, user, client) = setup_test(&e);
+        let beneficiary = Address::generate(&e);
+
+        client.transfer(&user, &client.address, &100);
+        client.create_escrow(&user, &beneficiary, &1000, &1000, &0);
+
+        client.admin_sweep(&admin, &client.address, &101, &admin);
+    }
+
+    #[test]
+    fn test_sweep_cannot_touch_milestone_funds() {
+        let e = Env::default();
+        let (admin, user, client) = setup_test(&e);
+        let beneficiary = Address::generate(&e);
+
+        client.transfer(&user, &client.address, &100);
+        let id = client.create_milestone_escrow(&user, &beneficiary, &vec![&e, 400, 600]);
+        client.approve_milestone(&id, &0);
+
+        assert_eq!(
+            client.try_admin_sweep(&admin, &client.address, &101, &admin),
+            Err(Ok(FeatureError::SweepExceedsSurplus.into()))
+        );
+
+        // Only the settled milestone leaves the obligations
+        client.release_milestone(&id, &0);
+        assert_eq!(
+            client.try_admin_sweep(&admin, &client.address, &101, &admin),
+            Err(Ok(FeatureError::SweepExceedsSurplus.into()))
+        );
+        client.admin_sweep(&admin, &client.address, &100, &admin);
+        assert_eq!(client.balance(&client.address), 600);
+    }
+
+    #[test]
+    fn test_sweep_cannot_touch_dispute_bonds() {
+        let e = Env::default();
+        let (admin, user, client) = setup_test(&e);
+        let beneficiary = Address::generate(&e);
+        let resolver = Address::generate(&e);
+        client.grant_role(&admin, &Role::Resolver, &resolver);
+        client.set_dispute_bond(&admin, &100);
+
+        let escrow_id = client.create_escrow(&user, &beneficiary, &1000, &1000, &0);
+        let dispute_id = client.open_dispute(&user, &escrow_id, &resolver);
+        assert_eq!(client.balance(&client.address), 1100);
+        assert_eq!(
+            client.try_admin_sweep(&admin, &client.address, &1, &admin),
+            Err(Ok(FeatureError::SweepExceedsSurplus.into()))
+        );
+
+        // Settlement pays the bond out, leaving nothing held
+        client.resolve_dispute(&resolver, &dispute_id, &false);
+        assert_eq!(client.balance(&user), 10_000);
+        assert_eq!(client.balance(&client.address), 0);
+    }
+
+    #[test]
+    fn test_sweep_external_token() {
+        let e = Env::default();
+        let (admin, _, client) = setup_test(&e);
+
+        let asset = e.register_stellar_asset_contract_v2(admin.clone());
+        token::StellarAssetClient::new(&e, &asset.address()).mint(&client.address, &500);
+
+        client.admin_sweep(&admin, &asset.address(), &500, &admin);
+
+        assert_eq!(token::Client::new(&e, &asset.address()).balance(&admin), 500);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #115)")]
+    fn test_sweep_external_token_cannot_touch_token_escrows() {
+        let e = Env::default();
+        let (admin, user, client) = setup_test(&e);
+        let beneficiary = Address::generate(&e);
+
+        let asset = e.register_stellar_asset_contract_v2(admin.clone());
+        let asset_admin = token::StellarAssetClient::new(&e, &asset.address());
+        asset_admin.mint(&user, &300);
+        asset_admin.mint(&client.address, &50);
+        client.create_token_escrow(&user, &beneficiary, &asset.address(), &300, &1000, &0);
+
+        client.admin_sweep(&admin, &asset.address(), &51, &admin);
+    }
+
+    #[test]
+    fn test_held_in_token_tracks_lock_and_settle() {
+        let e = Env::default();
+        let (admin, user, client) = setup_test(&e);
+        let beneficiary = Address::generate(&e);
+        let asset = e.register_stellar_asset_contract_v2(admin.clone());
+        let asset_admin = token::StellarAssetClient::new(&e, &asset.address());
+        asset_admin.mint(&user, &1000);
+        asset_admin.mint(&client.address, &50);
+        let held = || e.as_contract(&client.address, || crate::sweep::held_in_token(&e, &asset.address()));
+
+        let escrow_id = client.create_token_escrow(&user, &beneficiary, &asset.address(), &300, &1000, &0);
+        let recipients = vec![&e, SplitRecipient { address: beneficiary.clone(), share_bps: 10000 }];
+        let split_id = client.create_token_split(&user, &recipients, &200, &asset.address(), &None);
+        assert_eq!(held(), 500);
+        assert_eq!(
+            client.try_admin_sweep(&admin, &asset.address(), &51, &admin),
+            Err(Ok(FeatureError::SweepExceedsSurplus.into()))
+        );
+
+        client.refund_escrow(&user, &escrow_id);
+        assert_eq!(held(), 200);
+        client.distribute(&user, &split_id);
+        assert_eq!(held(), 0);
+        client.admin_sweep(&admin, &asset.address(), &50, &admin);
+    }
+
+    #[test]
+    fn test_native_custody_tracks_lock_and_settle() {
+        let e = Env::default();
+        let (admin, user, client) = setup_test(&e);
+        let beneficiary = Address::generate(&e);
+        let held = || e.as_contract(&client.address, || crate::sweep::held_in_token(&e, &client.address));
+        let preimage = Bytes::from_slice(&e, b"veritix-secret");
+        let hashlock: BytesN<32> = e.crypto().sha256(&preimage).into();
+        let recipients = vec![&e, SplitRecipient { address: beneficiary.clone(), share_bps: 10000 }];
+
+        let escrow_id = client.create_escrow(&user, &beneficiary, &100, &1000, &0);
+        let multi_id = client.create_multi_escrow(&user, &recipients, &200, &1000, &0);
+        let milestone_id = client.create_milestone_escrow(&user, &beneficiary, &vec![&e, 300i128]);
+        let split_id = client.create_split(&user, &recipients, &400, &None);
+        let scheduled_id = client.schedule_transfer(&user, &beneficiary, &500, &1000);
+        let hashlock_id = client.create_hashlock_escrow(&user, &beneficiary, &600, &hashlock, &1000);
+        client.transfer(&user, &client.address, &50);
+        assert_eq!(held(), 2100);
+        assert_eq!(
+            client.try_admin_sweep(&admin, &client.address, &51, &admin),
+            Err(Ok(FeatureError::SweepExceedsSurplus.into()))
+        );
+
+        client.refund_escrow(&user, &escrow_id);
+        client.release_multi_escrow_to_claims(&user, &multi_id);
+        assert_eq!(held(), 2000);
+        client.claim_multi_escrow(&multi_id, &beneficiary);
+        client.approve_milestone(&milestone_id, &0);
+        client.release_milestone(&milestone_id, &0);
+        client.distribute(&user, &split_id);
+        client.cancel_scheduled(&scheduled_id);
+        client.claim_hashlock(&hashlock_id, &preimage);
+        assert_eq!(held(), 0);
+
+        client.admin_sweep(&admin, &client.address, &50, &admin);
+        assert_eq!(client.balance(&client.address), 0);
+    }
+
+    #[test]
+    fn test_contract_balance_reflects_active_escrow() {
+        let e = Env::default();
+        let (admin, user, client) = setup_test(&e);
+        let beneficiary = Address::generate(&e);
+
+        assert_eq!(client.contract_balance(), 0);
+        let id = client.create_escrow(&user, &beneficiary, &1200, &1000, &0);
+        assert_eq!(client.contract_balance(), 1200);
+        assert_eq!(client.contract_token_balance(&client.address), 1200);
+
+        client.release_escrow(&beneficiary, &id);
+        assert_eq!(client.contract_balance(), 0);
+
+        let asset = e.register_stellar_asset_contract_v2(admin);
+        token::StellarAssetClient::new(&e, &asset.address()).mint(&client.address, &75);
+        assert_eq!(client.contract_token_balance(&asset.address()), 75);
+    }
+}
@@ -2,13 +2,20 @@
 
 use super::*;
 use soroban_sdk::{
-    testutils::Address as _,
-    Address, Env, String,
+    symbol_short,
+    testutils::{storage::Persistent as _, Address as _, Events, Ledger},
+    vec, Address, Bytes, BytesN, Env, IntoVal, String, Symbol, Val,
 };
 
+use crate::error::{Error, FeatureError};
+use crate::fee::MAX_TRANSFER_FEE_BPS;
+use crate::freeze::FreezeMode;
+use crate::roles::Role;
+use crate::splitter::SplitRecipient;
+use crate::storage_types::{FeeConfig, TransferFee};
 use crate::VeritixTokenClient;
 
-fn setup() -> (Env, VeritixTokenClient, Address, Address) {
+fn setup() -> (Env, VeritixTokenClient<'static>, Address, Address) {
     let env = Env::default();
     env.mock_all_auths();
 
@@ -37,6 +44,23 @@ fn test_initialize() {
     assert_eq!(client.decimals(), 7u32);
 }
 
+#[test]
+fn test_token_metadata_matches_getters() {
+    let (env, client, admin, _) = setup();
+
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "Veritix"),
+        &String::from_str(&env, "VTX"),
+        &7u32,
+    );
+
+    let metadata = client.token_metadata();
+    assert_eq!(metadata.name, client.name());
+    assert_eq!(metadata.symbol, client.symbol());
+    assert_eq!(metadata.decimal, client.decimals());
+}
+
 #[test]
 #[should_panic]
 fn test_initialize_twice_panics() {
@@ -58,6 +82,76 @@ fn test_initialize_twice_panics() {
     );
 }
 
+#[test]
+#[should_panic(expected = "Error(Contract, #101)")]
+fn decimal_is_over_eighteen() {
+    let (env, client, admin, _) = setup();
+
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "Veritix"),
+        &String::from_str(&env, "VTX"),
+        &19u32,
+    );
+}
+
+#[test]
+fn test_initialize_max_decimals() {
+    let (env, client, admin, _) = setup();
+
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "Veritix"),
+        &String::from_str(&env, "VTX"),
+        &18u32,
+    );
+
+    assert_eq!(client.decimals(), 18u32);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #102)")]
+fn test_initialize_empty_symbol_panics() {
+    let (env, client, admin, _) = setup();
+
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "Veritix"),
+        &String::from_str(&env, ""),
+        &7u32,
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #102)")]
+fn test_initialize_long_name_panics() {
+    let (env, client, admin, _) = setup();
+
+    client.initialize(
+        &admin,
+        &String::from_str(&env, &"V".repeat(65)),
+        &String::from_str(&env, "VTX"),
+        &7u32,
+    );
+}
+
+#[test]
+fn test_initialize_metadata_at_bounds() {
+    let (env, client, admin, _) = setup();
+    let name = "N".repeat(64);
+    let symbol = "S".repeat(12);
+
+    client.initialize(
+        &admin,
+        &String::from_str(&env, &name),
+        &String::from_str(&env, &symbol),
+        &7u32,
+    );
+
+    assert_eq!(client.name(), String::from_str(&env, &name));
+    assert_eq!(client.symbol(), String::from_str(&env, &symbol));
+}
+
 #[test]
 fn test_mint() {
     let (env, client, admin, user) = setup();
@@ -181,6 +275,54 @@ fn test_transfer_from() {
     assert_eq!(client.balance(&receiver), 300i128);
 }
 
+#[test]
+fn test_approve_for_uses_relative_expiration() {
+    let (env, client, admin, user) = setup();
+    let spender = Address::generate(&env);
+
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "Veritix"),
+        &String::from_str(&env, "VTX"),
+        &7u32,
+    );
+
+    env.ledger().set_sequence_number(500);
+    client.approve_for(&user, &spender, &400i128, &100u32);
+
+    let allowance = env.as_contract(&client.address, || {
+        crate::allowance::read_allowance(&env, user.clone(), spender.clone())
+    });
+    assert_eq!(allowance.amount, 400i128);
+    assert_eq!(allowance.expiration_ledger, 600);
+}
+
+#[test]
+fn test_relative_expiration_overflow_rejected() {
+    let (env, client, admin, user) = setup();
+    let spender = Address::generate(&env);
+
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "Veritix"),
+        &String::from_str(&env, "VTX"),
+        &7u32,
+    );
+
+    env.ledger().set_sequence_number(500);
+    assert_eq!(
+        client.try_approve_for(&user, &spender, &400i128, &u32::MAX),
+        Err(Ok(FeatureError::InvalidExpiration.into()))
+    );
+
+    client.set_default_allowance_window(&admin, &u32::MAX);
+    assert_eq!(
+        client.try_increase_allowance(&user, &spender, &100i128, &None),
+        Err(Ok(FeatureError::InvalidExpiration.into()))
+    );
+    assert_eq!(client.allowance(&user, &spender), 0);
+}
+
 #[test]
 fn test_approve_and_spend_allowance() {
     let (env, client, admin, user) = setup();
@@ -218,6 +360,1462 @@ fn test_expired_allowance_panics() {
 
     // Expired immediately (0 ledger)
     client.approve(&user, &spender, &400i128, &0u32);
+    env.ledger().set_sequence_number(1);
 
     client.transfer_from(&spender, &user, &spender, &100i128);
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_transfer_from_preserves_allowance_expiration() {
+    let (env, client, admin, user) = setup();
+    let spender = Address::generate(&env);
+    let receiver = Address::generate(&env);
+
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "Veritix"),
+        &String::from_str(&env, "VTX"),
+        &7u32,
+    );
+
+    client.mint(&admin, &user, &1000i128);
+
+    // Long-dated approval, far beyond any default window
+    client.approve(&user, &spender, &500i128, &500_000u32);
+    env.ledger().set_sequence_number(50);
+    client.transfer_from(&spender, &user, &receiver, &200i128);
+
+    let allowance = env.as_contract(&client.address, || {
+        crate::allowance::read_allowance(&env, user.clone(), spender.clone())
+    });
+    assert_eq!(allowance.amount, 300i128);
+    assert_eq!(allowance.expiration_ledger, 500_000u32);
+}
+
+#[test]
+fn test_increase_allowance_preserves_expiration() {
+    let (env, client, admin, user) = setup();
+    let spender = Address::generate(&env);
+
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "Veritix"),
+        &String::from_str(&env, "VTX"),
+        &7u32,
+    );
+
+    client.approve(&user, &spender, &500i128, &500_000u32);
+    env.ledger().set_sequence_number(50);
+    client.increase_allowance(&user, &spender, &250i128, &None);
+
+    let allowance = env.as_contract(&client.address, || {
+        crate::allowance::read_allowance(&env, user.clone(), spender.clone())
+    });
+    assert_eq!(allowance.amount, 750i128);
+    assert_eq!(allowance.expiration_ledger, 500_000u32);
+}
+
+#[test]
+fn test_decrease_allowance_preserves_expiration() {
+    let (env, client, admin, user) = setup();
+    let spender = Address::generate(&env);
+
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "Veritix"),
+        &String::from_str(&env, "VTX"),
+        &7u32,
+    );
+
+    client.approve(&user, &spender, &500i128, &500_000u32);
+    client.decrease_allowance(&user, &spender, &200i128);
+
+    let allowance = env.as_contract(&client.address, || {
+        crate::allowance::read_allowance(&env, user.clone(), spender.clone())
+    });
+    assert_eq!(allowance.amount, 300i128);
+    assert_eq!(allowance.expiration_ledger, 500_000u32);
+
+    // Going below zero is rejected rather than clamped
+    assert_eq!(
+        client.try_decrease_allowance(&user, &spender, &301i128),
+        Err(Ok(Error::InsufficientAllowance.into()))
+    );
+
+    // Decreasing to exactly zero removes the allowance
+    client.decrease_allowance(&user, &spender, &300i128);
+    assert_eq!(client.allowance(&user, &spender), 0i128);
+}
+
+#[test]
+fn test_increase_allowance_new_uses_default_window() {
+    let (env, client, admin, user) = setup();
+    let spender = Address::generate(&env);
+
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "Veritix"),
+        &String::from_str(&env, "VTX"),
+        &7u32,
+    );
+
+    env.ledger().set_sequence_number(10);
+    client.increase_allowance(&user, &spender, &100i128, &None);
+    assert_eq!(client.allowance(&user, &spender), 100i128);
+
+    let allowance = env.as_contract(&client.address, || {
+        crate::allowance::read_allowance(&env, user.clone(), spender.clone())
+    });
+    assert_eq!(
+        allowance.expiration_ledger,
+        10 + crate::storage_types::DEFAULT_ALLOWANCE_WINDOW
+    );
+
+    // An explicit expiration still overrides
+    client.increase_allowance(&user, &spender, &100i128, &Some(20u32));
+    let allowance = env.as_contract(&client.address, || {
+        crate::allowance::read_allowance(&env, user.clone(), spender.clone())
+    });
+    assert_eq!(allowance.amount, 200i128);
+    assert_eq!(allowance.expiration_ledger, 20u32);
+}
+
+#[test]
+fn test_approve_checked_updates_matching_allowance() {
+    let (env, client, admin, user) = setup();
+    let spender = Address::generate(&env);
+
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "Veritix"),
+        &String::from_str(&env, "VTX"),
+        &7u32,
+    );
+
+    client.approve(&user, &spender, &500i128, &1000u32);
+    client.approve_checked(&user, &spender, &500i128, &200i128, &1000u32);
+    assert_eq!(client.allowance(&user, &spender), 200i128);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #104)")]
+fn test_approve_checked_mismatch_panics() {
+    let (env, client, admin, user) = setup();
+    let spender = Address::generate(&env);
+    let receiver = Address::generate(&env);
+
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "Veritix"),
+        &String::from_str(&env, "VTX"),
+        &7u32,
+    );
+
+    client.mint(&admin, &user, &1000i128);
+    client.approve(&user, &spender, &500i128, &1000u32);
+    // Spender front-runs the update
+    client.transfer_from(&spender, &user, &receiver, &500i128);
+    client.approve_checked(&user, &spender, &500i128, &200i128, &1000u32);
+}
+
+#[test]
+fn test_set_default_allowance_window() {
+    let (env, client, admin, _) = setup();
+
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "Veritix"),
+        &String::from_str(&env, "VTX"),
+        &7u32,
+    );
+
+    assert_eq!(
+        client.default_allowance_window(),
+        crate::storage_types::DEFAULT_ALLOWANCE_WINDOW
+    );
+    client.set_default_allowance_window(&admin, &1000u32);
+    assert_eq!(client.default_allowance_window(), 1000u32);
+}
+
+#[test]
+fn test_approve_default_uses_configured_window() {
+    let (env, client, admin, user) = setup();
+    let spender = Address::generate(&env);
+
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "Veritix"),
+        &String::from_str(&env, "VTX"),
+        &7u32,
+    );
+
+    env.ledger().set_sequence_number(50);
+    client.set_default_allowance_window(&admin, &1000u32);
+    client.approve_default(&user, &spender, &300i128);
+
+    let allowance = env.as_contract(&client.address, || {
+        crate::allowance::read_allowance(&env, user.clone(), spender.clone())
+    });
+    assert_eq!(allowance.amount, 300i128);
+    assert_eq!(allowance.expiration_ledger, 1050);
+}
+
+#[test]
+#[should_panic]
+fn test_set_default_allowance_window_unauthorized_panics() {
+    let (env, client, admin, user) = setup();
+
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "Veritix"),
+        &String::from_str(&env, "VTX"),
+        &7u32,
+    );
+
+    client.set_default_allowance_window(&user, &1000u32);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #105)")]
+fn test_frozen_account_cannot_transfer() {
+    let (env, client, admin, user) = setup();
+    let receiver = Address::generate(&env);
+
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "Veritix"),
+        &String::from_str(&env, "VTX"),
+        &7u32,
+    );
+
+    client.mint(&admin, &user, &1000i128);
+    client.freeze(&admin, &user);
+    client.transfer(&user, &receiver, &100i128);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #105)")]
+fn test_burn_from_frozen_account_panics() {
+    let (env, client, admin, user) = setup();
+    let spender = Address::generate(&env);
+
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "Veritix"),
+        &String::from_str(&env, "VTX"),
+        &7u32,
+    );
+
+    client.mint(&admin, &user, &1000i128);
+    client.approve(&user, &spender, &500i128, &1000u32);
+    client.freeze(&admin, &user);
+    client.burn_from(&spender, &user, &100i128);
+}
+
+#[test]
+fn test_outbound_frozen_account_can_receive() {
+    let (env, client, admin, user) = setup();
+
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "Veritix"),
+        &String::from_str(&env, "VTX"),
+        &7u32,
+    );
+
+    client.freeze(&admin, &user);
+    client.mint(&admin, &user, &1000i128);
+    assert_eq!(client.balance(&user), 1000i128);
+
+    client.unfreeze(&admin, &user);
+    client.transfer(&user, &admin, &100i128);
+    assert_eq!(client.balance(&user), 900i128);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #5)")]
+fn test_mint_to_fully_frozen_account_panics() {
+    let (env, client, admin, user) = setup();
+
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "Veritix"),
+        &String::from_str(&env, "VTX"),
+        &7u32,
+    );
+
+    client.freeze_with_mode(&admin, &user, &FreezeMode::Full);
+    client.mint(&admin, &user, &1000i128);
+}
+
+#[test]
+fn test_display_units_round_trip() {
+    let (env, client, admin, _) = setup();
+
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "Veritix"),
+        &String::from_str(&env, "VTX"),
+        &7u32,
+    );
+
+    assert_eq!(client.to_display_units(&12_345_678i128), (1, 2_345_678));
+    assert_eq!(client.to_display_units(&10_000_000i128), (1, 0));
+    assert_eq!(client.to_display_units(&9i128), (0, 9));
+
+    for raw in [0i128, 9, 10_000_000, 12_345_678, 123_456_789_000_001] {
+        let (whole, frac) = client.to_display_units(&raw);
+        assert_eq!(client.from_display_units(&whole, &frac), raw);
+    }
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #20)")]
+fn test_from_display_units_fraction_too_large_panics() {
+    let (env, client, admin, _) = setup();
+
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "Veritix"),
+        &String::from_str(&env, "VTX"),
+        &7u32,
+    );
+
+    client.from_display_units(&1i128, &10_000_000i128);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")]
+fn test_mint_balance_overflow_panics() {
+    let (env, client, admin, user) = setup();
+
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "Veritix"),
+        &String::from_str(&env, "VTX"),
+        &7u32,
+    );
+
+    client.mint(&admin, &user, &(i128::MAX - 1));
+    client.mint(&admin, &user, &2i128);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #3)")]
+fn test_mint_supply_overflow_panics() {
+    let (env, client, admin, user) = setup();
+    let other = Address::generate(&env);
+
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "Veritix"),
+        &String::from_str(&env, "VTX"),
+        &7u32,
+    );
+
+    client.mint(&admin, &user, &(i128::MAX - 1));
+    client.mint(&admin, &other, &2i128);
+}
+
+#[test]
+fn test_freeze_many() {
+    let (env, client, admin, user) = setup();
+    let second = Address::generate(&env);
+    let third = Address::generate(&env);
+
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "Veritix"),
+        &String::from_str(&env, "VTX"),
+        &7u32,
+    );
+
+    let targets = vec![&env, user.clone(), second.clone(), third.clone()];
+    client.freeze_many(&admin, &targets);
+
+    assert!(client.is_frozen(&user));
+    assert!(client.is_frozen(&second));
+    assert!(client.is_frozen(&third));
+    assert_eq!(client.frozen_accounts(), targets);
+
+    client.unfreeze_many(&admin, &vec![&env, second.clone()]);
+    assert!(!client.is_frozen(&second));
+    assert_eq!(client.frozen_accounts(), vec![&env, user, third]);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #107)")]
+fn test_freeze_many_empty_panics() {
+    let (env, client, admin, _) = setup();
+
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "Veritix"),
+        &String::from_str(&env, "VTX"),
+        &7u32,
+    );
+
+    client.freeze_many(&admin, &soroban_sdk::Vec::new(&env));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #106)")]
+fn test_transfer_to_blacklisted_recipient_panics() {
+    let (env, client, admin, user) = setup();
+    let sanctioned = Address::generate(&env);
+
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "Veritix"),
+        &String::from_str(&env, "VTX"),
+        &7u32,
+    );
+
+    client.mint(&admin, &user, &1000i128);
+    client.blacklist(&admin, &sanctioned);
+    client.transfer(&user, &sanctioned, &100i128);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #106)")]
+fn test_blacklisted_sender_cannot_transfer() {
+    let (env, client, admin, user) = setup();
+    let receiver = Address::generate(&env);
+
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "Veritix"),
+        &String::from_str(&env, "VTX"),
+        &7u32,
+    );
+
+    client.mint(&admin, &user, &1000i128);
+    client.blacklist(&admin, &user);
+    client.transfer(&user, &receiver, &100i128);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #106)")]
+fn test_create_escrow_with_blacklisted_beneficiary_panics() {
+    let (env, client, admin, user) = setup();
+    let sanctioned = Address::generate(&env);
+
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "Veritix"),
+        &String::from_str(&env, "VTX"),
+        &7u32,
+    );
+
+    client.mint(&admin, &user, &1000i128);
+    client.blacklist(&admin, &sanctioned);
+    client.create_escrow(&user, &sanctioned, &500i128, &1000u32, &0u32);
+}
+
+#[test]
+fn test_frozen_or_blacklisted_sender_cannot_fund_contracts() {
+    let (env, client, admin, user) = setup();
+    let sanctioned = Address::generate(&env);
+    let receiver = Address::generate(&env);
+
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "Veritix"),
+        &String::from_str(&env, "VTX"),
+        &7u32,
+    );
+
+    client.mint(&admin, &user, &1000i128);
+    client.mint(&admin, &sanctioned, &1000i128);
+    client.freeze(&admin, &user);
+    client.blacklist(&admin, &sanctioned);
+
+    let recipients = vec![&env, SplitRecipient { address: receiver.clone(), share_bps: 10_000 }];
+    let hashlock: BytesN<32> = env.crypto().sha256(&Bytes::from_array(&env, &[1u8; 4])).into();
+    for (sender, error) in [
+        (user.clone(), FeatureError::AccountFrozen),
+        (sanctioned.clone(), FeatureError::Blacklisted),
+    ] {
+        let expected = Err(Ok(error.into()));
+        assert_eq!(client.try_create_escrow(&sender, &receiver, &100, &1000, &0), expected);
+        assert_eq!(
+            client.try_create_multi_escrow(&sender, &recipients, &100, &1000, &0),
+            expected
+        );
+        assert_eq!(
+            client.try_create_milestone_escrow(&sender, &receiver, &vec![&env, 100i128]),
+            expected
+        );
+        assert_eq!(
+            client.try_create_hashlock_escrow(&sender, &receiver, &100, &hashlock, &1000),
+            expected
+        );
+        assert_eq!(client.try_create_split(&sender, &recipients, &100, &None), expected);
+        assert_eq!(
+            client.try_create_fixed_split(
+                &sender,
+                &vec![&env, receiver.clone()],
+                &vec![&env, 100i128],
+                &100,
+                &None
+            ),
+            expected
+        );
+    }
+
+    assert_eq!(client.balance(&user), 1000i128);
+    assert_eq!(client.balance(&sanctioned), 1000i128);
+    assert_eq!(client.balance(&client.address), 0);
+}
+
+#[test]
+fn test_unblacklist_restores_transfers() {
+    let (env, client, admin, user) = setup();
+    let receiver = Address::generate(&env);
+
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "Veritix"),
+        &String::from_str(&env, "VTX"),
+        &7u32,
+    );
+
+    client.mint(&admin, &user, &1000i128);
+    client.blacklist(&admin, &receiver);
+    assert!(client.is_blacklisted(&receiver));
+    assert_last_event(
+        &env,
+        (
+            client.address.clone(),
+            (Symbol::new(&env, "blacklist"), Symbol::new(&env, "added"), receiver.clone())
+                .into_val(&env),
+            ().into_val(&env)
+        )
+    );
+
+    client.unblacklist(&admin, &receiver);
+    assert_last_event(
+        &env,
+        (
+            client.address.clone(),
+            (Symbol::new(&env, "blacklist"), Symbol::new(&env, "removed"), receiver.clone())
+                .into_val(&env),
+            ().into_val(&env)
+        )
+    );
+    client.transfer(&user, &receiver, &100i128);
+    assert_eq!(client.balance(&receiver), 100i128);
+}
+
+#[test]
+fn test_set_and_get_fee_config() {
+    let (env, client, admin, _) = setup();
+
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "Veritix"),
+        &String::from_str(&env, "VTX"),
+        &7u32,
+    );
+
+    let config = FeeConfig {
+        escrow_bps: 250,
+        recurring_bps: 100,
+        dispute_fee: 50,
+    };
+    client.set_fee_config(&admin, &config);
+
+    assert_eq!(client.get_fee_config(), config);
+}
+
+#[test]
+#[should_panic]
+fn test_set_fee_config_unauthorized_panics() {
+    let (env, client, admin, user) = setup();
+
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "Veritix"),
+        &String::from_str(&env, "VTX"),
+        &7u32,
+    );
+
+    client.set_fee_config(
+        &user,
+        &FeeConfig {
+            escrow_bps: 250,
+            recurring_bps: 100,
+            dispute_fee: 50,
+        },
+    );
+}
+
+#[test]
+fn test_peek_balance_does_not_extend_ttl() {
+    let (env, client, admin, user) = setup();
+
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "Veritix"),
+        &String::from_str(&env, "VTX"),
+        &7u32,
+    );
+
+    client.mint(&admin, &user, &1000i128);
+
+    let key = crate::storage_types::DataKey::Balance(user.clone());
+    let ttl_before = env.as_contract(&client.address, || env.storage().persistent().get_ttl(&key));
+
+    // Advance so any bump would be observable
+    env.ledger().set_sequence_number(env.ledger().sequence() + 1000);
+    let ttl_mid = env.as_contract(&client.address, || env.storage().persistent().get_ttl(&key));
+    assert_eq!(ttl_mid, ttl_before - 1000);
+
+    let peeked = env.as_contract(&client.address, || {
+        crate::balance::peek_balance(&env, user.clone())
+    });
+    assert_eq!(peeked, 1000i128);
+    assert_eq!(client.balance(&user), 1000i128);
+
+    let ttl_after = env.as_contract(&client.address, || env.storage().persistent().get_ttl(&key));
+    assert_eq!(ttl_after, ttl_mid);
+}
+
+#[test]
+fn test_mint_split() {
+    let (env, client, admin, _) = setup();
+    let team = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let community = Address::generate(&env);
+
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "Veritix"),
+        &String::from_str(&env, "VTX"),
+        &7u32,
+    );
+
+    let recipients = vec![
+        &env,
+        SplitRecipient { address: team.clone(), share_bps: 5000 },
+        SplitRecipient { address: treasury.clone(), share_bps: 3000 },
+        SplitRecipient { address: community.clone(), share_bps: 2000 },
+    ];
+    client.mint_split(&admin, &1001i128, &recipients);
+
+    assert_eq!(client.balance(&team), 500i128);
+    assert_eq!(client.balance(&treasury), 300i128);
+    assert_eq!(client.balance(&community), 201i128); // picks up the dust
+    assert_eq!(client.total_supply(), 1001i128);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #52)")]
+fn test_mint_split_invalid_bps_panics() {
+    let (env, client, admin, user) = setup();
+
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "Veritix"),
+        &String::from_str(&env, "VTX"),
+        &7u32,
+    );
+
+    let recipients = vec![&env, SplitRecipient { address: user, share_bps: 9999 }];
+    client.mint_split(&admin, &1000i128, &recipients);
+}
+
+#[test]
+fn test_finalize_supply_disables_mint() {
+    let (env, client, admin, user) = setup();
+    let receiver = Address::generate(&env);
+
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "Veritix"),
+        &String::from_str(&env, "VTX"),
+        &7u32,
+    );
+
+    client.mint(&admin, &user, &1000i128);
+    client.finalize_supply(&admin);
+    assert!(client.is_mint_finalized());
+
+    // Transfers are unaffected
+    client.transfer(&user, &receiver, &100i128);
+    assert_eq!(client.balance(&receiver), 100i128);
+
+    let result = client.try_mint(&admin, &user, &1i128);
+    assert!(result.is_err());
+    assert_eq!(client.total_supply(), 1000i128);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #110)")]
+fn test_mint_split_after_finalize_panics() {
+    let (env, client, admin, user) = setup();
+
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "Veritix"),
+        &String::from_str(&env, "VTX"),
+        &7u32,
+    );
+
+    client.finalize_supply(&admin);
+    let recipients = vec![&env, SplitRecipient { address: user, share_bps: 10000 }];
+    client.mint_split(&admin, &1000i128, &recipients);
+}
+
+#[test]
+fn test_initialize_with_cap_allows_mint_up_to_cap() {
+    let (env, client, admin, user) = setup();
+
+    client.initialize_with_cap(
+        &admin,
+        &String::from_str(&env, "Veritix"),
+        &String::from_str(&env, "VTX"),
+        &7u32,
+        &1000i128,
+    );
+
+    client.mint(&admin, &user, &1000i128);
+    assert_eq!(client.supply_cap(), 1000i128);
+    assert_eq!(client.total_supply(), 1000i128);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #112)")]
+fn test_mint_beyond_cap_panics() {
+    let (env, client, admin, user) = setup();
+
+    client.initialize_with_cap(
+        &admin,
+        &String::from_str(&env, "Veritix"),
+        &String::from_str(&env, "VTX"),
+        &7u32,
+        &1000i128,
+    );
+
+    client.mint(&admin, &user, &600i128);
+    client.mint(&admin, &user, &401i128);
+}
+
+#[test]
+fn test_lower_supply_cap_only_downward() {
+    let (env, client, admin, user) = setup();
+
+    client.initialize_with_cap(
+        &admin,
+        &String::from_str(&env, "Veritix"),
+        &String::from_str(&env, "VTX"),
+        &7u32,
+        &1000i128,
+    );
+    client.mint(&admin, &user, &400i128);
+
+    client.lower_supply_cap(&admin, &500i128);
+    assert_eq!(client.supply_cap(), 500i128);
+    assert!(client.try_lower_supply_cap(&admin, &600i128).is_err());
+    assert!(client.try_lower_supply_cap(&admin, &300i128).is_err());
+    assert!(client.try_mint(&admin, &user, &101i128).is_err());
+    client.mint(&admin, &user, &100i128);
+}
+
+#[test]
+fn test_transfer_fee_to_collector() {
+    let (env, client, admin, user) = setup();
+    let receiver = Address::generate(&env);
+    let collector = Address::generate(&env);
+
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "Veritix"),
+        &String::from_str(&env, "VTX"),
+        &7u32,
+    );
+
+    client.mint(&admin, &user, &10_000i128);
+    client.set_transfer_fee(&admin, &TransferFee { bps: 250, collector: Some(collector.clone()), reflection_bps: 0 });
+    client.transfer(&user, &receiver, &1000i128);
+
+    assert_eq!(client.balance(&user), 9000i128); // Debited the full amount
+    assert_eq!(client.balance(&receiver), 975i128);
+    assert_eq!(client.balance(&collector), 25i128);
+    assert_eq!(client.total_supply(), 10_000i128);
+}
+
+#[test]
+fn test_transfer_fee_capped_at_max_bps() {
+    let (env, client, admin, _) = setup();
+
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "Veritix"),
+        &String::from_str(&env, "VTX"),
+        &7u32,
+    );
+
+    let at_cap = TransferFee { bps: MAX_TRANSFER_FEE_BPS, collector: None, reflection_bps: 0 };
+    client.set_transfer_fee(&admin, &at_cap);
+    assert_eq!(client.get_transfer_fee(), at_cap);
+
+    assert_eq!(
+        client.try_set_transfer_fee(
+            &admin,
+            &TransferFee { bps: MAX_TRANSFER_FEE_BPS + 1, collector: None, reflection_bps: 0 }
+        ),
+        Err(Ok(FeatureError::TransferFeeTooHigh.into()))
+    );
+    assert_eq!(client.get_transfer_fee(), at_cap);
+}
+
+#[test]
+fn test_transfer_fee_burned_without_collector() {
+    let (env, client, admin, user) = setup();
+    let spender = Address::generate(&env);
+    let receiver = Address::generate(&env);
+
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "Veritix"),
+        &String::from_str(&env, "VTX"),
+        &7u32,
+    );
+
+    client.mint(&admin, &user, &10_000i128);
+    client.set_transfer_fee(&admin, &TransferFee { bps: 100, collector: None, reflection_bps: 0 });
+    client.approve(&user, &spender, &2000i128, &1000u32);
+    client.transfer_from(&spender, &user, &receiver, &2000i128);
+
+    assert_eq!(client.balance(&user), 8000i128);
+    assert_eq!(client.balance(&receiver), 1980i128);
+    assert_eq!(client.total_supply(), 9980i128);
+}
+
+/// Compares the most recent event, the way SEP-41 indexers read it.
+fn assert_last_event(env: &Env, expected: (Address, soroban_sdk::Vec<Val>, Val)) {
+    let events = env.events().all();
+    assert_eq!(events.slice(events.len() - 1..), vec![env, expected]);
+}
+
+#[test]
+fn test_core_events_match_sep41() {
+    let (env, client, admin, user) = setup();
+    let receiver = Address::generate(&env);
+    let spender = Address::generate(&env);
+
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "Veritix"),
+        &String::from_str(&env, "VTX"),
+        &7u32,
+    );
+
+    client.mint(&admin, &user, &1000i128);
+    assert_last_event(
+        &env,
+        (
+            client.address.clone(),
+            (symbol_short!("mint"), admin.clone(), user.clone()).into_val(&env),
+            1000i128.into_val(&env)
+        )
+    );
+
+    client.transfer(&user, &receiver, &100i128);
+    assert_last_event(
+        &env,
+        (
+            client.address.clone(),
+            (symbol_short!("transfer"), user.clone(), receiver.clone()).into_val(&env),
+            100i128.into_val(&env)
+        )
+    );
+
+    client.approve(&user, &spender, &300i128, &5000u32);
+    assert_last_event(
+        &env,
+        (
+            client.address.clone(),
+            (symbol_short!("approve"), user.clone(), spender.clone()).into_val(&env),
+            (300i128, 5000u32).into_val(&env)
+        )
+    );
+
+    client.burn(&user, &50i128);
+    assert_last_event(
+        &env,
+        (
+            client.address.clone(),
+            (symbol_short!("burn"), user.clone()).into_val(&env),
+            50i128.into_val(&env)
+        )
+    );
+}
+
+#[test]
+fn test_transfer_from_and_burn_from_emit_remaining_allowance() {
+    let (env, client, admin, user) = setup();
+    let spender = Address::generate(&env);
+    let receiver = Address::generate(&env);
+
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "Veritix"),
+        &String::from_str(&env, "VTX"),
+        &7u32,
+    );
+
+    client.mint(&admin, &user, &1000i128);
+    client.approve(&user, &spender, &500i128, &1000u32);
+
+    client.transfer_from(&spender, &user, &receiver, &200i128);
+    assert_last_event(
+        &env,
+        (
+            client.address.clone(),
+            (symbol_short!("approve"), user.clone(), spender.clone()).into_val(&env),
+            (300i128, 1000u32).into_val(&env)
+        )
+    );
+
+    client.burn_from(&spender, &user, &50i128);
+    assert_last_event(
+        &env,
+        (
+            client.address.clone(),
+            (symbol_short!("approve"), user.clone(), spender.clone()).into_val(&env),
+            (250i128, 1000u32).into_val(&env)
+        )
+    );
+}
+
+#[test]
+fn test_quote_escrow_release_matches_release() {
+    let (env, client, admin, user) = setup();
+    let beneficiary = Address::generate(&env);
+
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "Veritix"),
+        &String::from_str(&env, "VTX"),
+        &7u32,
+    );
+    client.set_fee_config(
+        &admin,
+        &FeeConfig {
+            escrow_bps: 250,
+            recurring_bps: 0,
+            dispute_fee: 0,
+        },
+    );
+
+    client.mint(&admin, &user, &1000i128);
+    let id = client.create_escrow(&user, &beneficiary, &1000i128, &1000u32, &0u32);
+
+    assert_eq!(client.quote_escrow_release(&1000i128), (25, 975));
+    client.release_escrow(&beneficiary, &id);
+    assert_eq!(client.balance(&beneficiary), 975);
+    assert_eq!(client.balance(&admin), 25);
+}
+
+#[test]
+fn test_quote_transfer_matches_transfer() {
+    let (env, client, admin, user) = setup();
+    let receiver = Address::generate(&env);
+    let collector = Address::generate(&env);
+
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "Veritix"),
+        &String::from_str(&env, "VTX"),
+        &7u32,
+    );
+    client.set_transfer_fee(
+        &admin,
+        &TransferFee {
+            bps: 100,
+            collector: Some(collector.clone()),
+            reflection_bps: 0,
+        },
+    );
+
+    client.mint(&admin, &user, &1000i128);
+    let (fee, net) = client.quote_transfer(&500i128);
+    assert_eq!((fee, net), (5, 495));
+
+    client.transfer(&user, &receiver, &500i128);
+    assert_eq!(client.balance(&receiver), net);
+    assert_eq!(client.balance(&collector), fee);
+}
+
+#[test]
+fn test_sweep_expired_allowance_removes_entry() {
+    let (env, client, admin, user) = setup();
+    let spender = Address::generate(&env);
+
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "Veritix"),
+        &String::from_str(&env, "VTX"),
+        &7u32,
+    );
+
+    client.approve(&user, &spender, &500i128, &10u32);
+    let key = crate::storage_types::DataKey::Allowance(crate::storage_types::AllowanceDataKey {
+        from: user.clone(),
+        spender: spender.clone(),
+    });
+    assert!(env.as_contract(&client.address, || env.storage().persistent().has(&key)));
+
+    // Still live at its expiration ledger
+    env.ledger().set_sequence_number(10);
+    assert!(client.try_sweep_allowance(&user, &spender).is_err());
+
+    env.ledger().set_sequence_number(11);
+    client.sweep_allowance(&user, &spender);
+    assert!(!env.as_contract(&client.address, || env.storage().persistent().has(&key)));
+}
+
+#[test]
+fn test_reverse_mint_moves_misdirected_tokens() {
+    let (env, client, admin, user) = setup();
+    let intended = Address::generate(&env);
+
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "Veritix"),
+        &String::from_str(&env, "VTX"),
+        &7u32,
+    );
+
+    client.mint(&admin, &user, &1000i128);
+    client.reverse_mint(&admin, &user, &600i128, &intended);
+
+    assert_eq!(client.balance(&user), 400i128);
+    assert_eq!(client.balance(&intended), 600i128);
+    assert_eq!(client.total_supply(), 1000i128);
+    assert_last_event(
+        &env,
+        (
+            client.address.clone(),
+            (symbol_short!("mint"), symbol_short!("reversed")).into_val(&env),
+            (user.clone(), intended.clone(), 600i128).into_val(&env)
+        )
+    );
+}
+
+#[test]
+fn test_is_active_account_after_first_receive() {
+    let (env, client, admin, user) = setup();
+
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "Veritix"),
+        &String::from_str(&env, "VTX"),
+        &7u32,
+    );
+
+    assert!(!client.is_active_account(&user));
+    client.mint(&admin, &user, &100i128);
+    assert!(client.is_active_account(&user));
+
+    // Stays active once the balance is spent down to zero
+    client.burn(&user, &100i128);
+    assert!(client.is_active_account(&user));
+}
+
+#[test]
+fn test_standard_token_client_interop() {
+    let (env, client, admin, user) = setup();
+    let other = Address::generate(&env);
+
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "Veritix"),
+        &String::from_str(&env, "VTX"),
+        &7u32,
+    );
+    client.mint(&admin, &user, &1000i128);
+
+    // Tooling that only knows SEP-41 talks to the contract through token::Client
+    let sep41 = soroban_sdk::token::Client::new(&env, &client.address);
+    assert_eq!(sep41.decimals(), 7);
+    assert_eq!(sep41.symbol(), String::from_str(&env, "VTX"));
+    sep41.transfer(&user, &other, &400i128);
+    sep41.approve(&user, &other, &100i128, &1000u32);
+    sep41.transfer_from(&other, &user, &other, &100i128);
+
+    assert_eq!(sep41.balance(&user), 500i128);
+    assert_eq!(sep41.balance(&other), 500i128);
+    assert_eq!(sep41.allowance(&user, &other), 0i128);
+}
+
+#[test]
+fn test_set_authorized_blocks_spending() {
+    let (env, client, admin, user) = setup();
+
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "Veritix"),
+        &String::from_str(&env, "VTX"),
+        &7u32,
+    );
+    client.mint(&admin, &user, &1000i128);
+    assert!(client.authorized(&user));
+    assert_eq!(client.spendable_balance(&user), 1000i128);
+
+    client.set_authorized(&admin, &user, &false);
+    assert!(!client.authorized(&user));
+    assert!(client.try_transfer(&user, &admin, &1i128).is_err());
+    assert_eq!(client.spendable_balance(&user), 0i128);
+    assert_eq!(client.balance(&user), 1000i128);
+
+    client.set_authorized(&admin, &user, &true);
+    assert!(client.authorized(&user));
+    assert_eq!(client.spendable_balance(&user), 1000i128);
+}
+
+#[test]
+fn test_typed_errors_reach_the_client() {
+    let (env, client, admin, user) = setup();
+    let other = Address::generate(&env);
+
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "Veritix"),
+        &String::from_str(&env, "VTX"),
+        &7u32,
+    );
+    client.mint(&admin, &user, &100i128);
+
+    assert_eq!(
+        client.try_transfer(&user, &other, &101i128),
+        Err(Ok(Error::InsufficientBalance.into()))
+    );
+    assert_eq!(
+        client.try_transfer_from(&other, &user, &other, &1i128),
+        Err(Ok(Error::InsufficientAllowance.into()))
+    );
+
+    let id = client.create_escrow(&user, &other, &50i128, &1000u32, &0u32);
+    client.release_escrow(&other, &id);
+    assert_eq!(client.try_release_escrow(&other, &id), Err(Ok(Error::AlreadySettled.into())));
+    assert_eq!(client.try_get_escrow(&99u32), Err(Ok(Error::EscrowNotFound.into())));
+}
+
+#[test]
+fn test_supply_tracks_mint_burn_and_clawback() {
+    let (env, client, admin, user) = setup();
+    let spender = Address::generate(&env);
+
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "Veritix"),
+        &String::from_str(&env, "VTX"),
+        &7u32,
+    );
+
+    client.mint(&admin, &user, &1000i128);
+    assert_eq!(client.total_supply(), 1000i128);
+
+    client.burn(&user, &100i128);
+    client.approve(&user, &spender, &100i128, &1000u32);
+    client.burn_from(&spender, &user, &50i128);
+    assert_eq!(client.total_supply(), 850i128);
+
+    client.clawback(&admin, &user, &350i128);
+    assert_eq!(client.balance(&user), 500i128);
+    assert_eq!(client.total_supply(), 500i128);
+}
+
+#[test]
+fn test_pause_halts_fund_movement() {
+    let (env, client, admin, user) = setup();
+    let other = Address::generate(&env);
+
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "Veritix"),
+        &String::from_str(&env, "VTX"),
+        &7u32,
+    );
+    client.mint(&admin, &user, &1000i128);
+    let recurring_id = client.setup_recurring(&user, &other, &100i128, &10u32, &2u32);
+    env.ledger().with_mut(|li| li.sequence_number += 10);
+
+    client.pause(&admin);
+    assert!(client.is_paused());
+
+    let paused = Err(Ok(Error::ContractPaused.into()));
+    let recipients = vec![&env, SplitRecipient { address: other.clone(), share_bps: 10000 }];
+    assert_eq!(client.try_transfer(&user, &other, &1i128), paused);
+    assert_eq!(client.try_burn(&user, &1i128), paused);
+    assert_eq!(
+        client.try_create_escrow(&user, &other, &1i128, &1000u32, &0u32),
+        Err(Ok(Error::ContractPaused.into()))
+    );
+    assert_eq!(
+        client.try_create_split(&user, &recipients, &1i128, &None),
+        Err(Ok(Error::ContractPaused.into()))
+    );
+    assert_eq!(client.try_execute_recurring(&recurring_id), paused);
+
+    // Admin actions such as minting continue while paused
+    client.mint(&admin, &other, &5i128);
+
+    client.unpause(&admin);
+    assert!(!client.is_paused());
+    client.transfer(&user, &other, &1i128);
+    client.execute_recurring(&recurring_id);
+    assert_eq!(client.balance(&other), 106i128);
+}
+
+#[test]
+fn test_pause_blocks_scheduled_hashlock_and_settlement_paths() {
+    let (env, client, admin, user) = setup();
+    let other = Address::generate(&env);
+
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "Veritix"),
+        &String::from_str(&env, "VTX"),
+        &7u32,
+    );
+    client.mint(&admin, &user, &1000i128);
+    let preimage = Bytes::from_slice(&env, b"veritix-secret");
+    let hashlock: BytesN<32> = env.crypto().sha256(&preimage).into();
+    let scheduled_id = client.schedule_transfer(&user, &other, &100i128, &10u32);
+    let hashlock_id = client.create_hashlock_escrow(&user, &other, &100i128, &hashlock, &100u32);
+    let escrow_id = client.create_escrow(&user, &other, &100i128, &1000u32, &0u32);
+
+    env.ledger().with_mut(|li| li.sequence_number += 10);
+    client.pause(&admin);
+
+    let paused = Err(Ok(Error::ContractPaused.into()));
+    let recipients = vec![&env, SplitRecipient { address: other.clone(), share_bps: 10000 }];
+    assert_eq!(client.try_execute_scheduled(&scheduled_id), paused);
+    assert_eq!(client.try_claim_hashlock(&hashlock_id, &preimage), paused);
+    assert_eq!(client.try_settle_external(&escrow_id, &Address::generate(&env)), paused);
+    assert_eq!(
+        client.try_schedule_transfer(&user, &other, &1i128, &10u32),
+        Err(Ok(Error::ContractPaused.into()))
+    );
+    assert_eq!(
+        client.try_create_hashlock_escrow(&user, &other, &1i128, &hashlock, &100u32),
+        Err(Ok(Error::ContractPaused.into()))
+    );
+    assert_eq!(
+        client.try_create_multi_escrow(&user, &recipients, &1i128, &1000u32, &0u32),
+        Err(Ok(Error::ContractPaused.into()))
+    );
+
+    client.unpause(&admin);
+    client.execute_scheduled(&scheduled_id);
+    client.claim_hashlock(&hashlock_id, &preimage);
+    assert_eq!(client.balance(&other), 200i128);
+}
+
+#[test]
+fn test_roles_delegate_minting_freezing_and_pausing() {
+    let (env, client, admin, user) = setup();
+    let operator = Address::generate(&env);
+
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "Veritix"),
+        &String::from_str(&env, "VTX"),
+        &7u32,
+    );
+    assert!(client.try_mint(&operator, &user, &100i128).is_err());
+
+    client.grant_role(&admin, &Role::Minter, &operator);
+    client.grant_role(&admin, &Role::Freezer, &operator);
+    client.grant_role(&admin, &Role::Pauser, &operator);
+    assert!(client.has_role(&Role::Minter, &operator));
+
+    client.mint(&operator, &user, &100i128);
+    client.freeze(&operator, &user);
+    assert!(client.is_frozen(&user));
+    client.unfreeze(&operator, &user);
+    client.pause(&operator);
+    client.unpause(&operator);
+    assert_eq!(client.balance(&user), 100i128);
+
+    // Roles are independent of the admin, who keeps every permission
+    client.revoke_role(&admin, &Role::Minter, &operator);
+    assert!(client.try_mint(&operator, &user, &1i128).is_err());
+    client.mint(&admin, &user, &1i128);
+    assert!(client.try_grant_role(&operator, &Role::Minter, &operator).is_err());
+}
+
+#[test]
+fn test_two_step_admin_handover() {
+    let (env, client, admin, user) = setup();
+    let new_admin = Address::generate(&env);
+
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "Veritix"),
+        &String::from_str(&env, "VTX"),
+        &7u32,
+    );
+    assert!(client.try_accept_admin().is_err());
+
+    client.propose_admin(&new_admin);
+    assert_eq!(client.pending_admin(), Some(new_admin.clone()));
+    assert_last_event(
+        &env,
+        (
+            client.address.clone(),
+            (Symbol::new(&env, "admin"), Symbol::new(&env, "proposed")).into_val(&env),
+            (admin.clone(), new_admin.clone()).into_val(&env)
+        )
+    );
+
+    // Nothing changes until the proposed admin accepts
+    client.mint(&admin, &user, &1i128);
+    assert!(client.try_mint(&new_admin, &user, &1i128).is_err());
+
+    client.accept_admin();
+    assert_eq!(client.pending_admin(), None);
+    assert_last_event(
+        &env,
+        (
+            client.address.clone(),
+            (Symbol::new(&env, "admin"), Symbol::new(&env, "accepted")).into_val(&env),
+            (admin.clone(), new_admin.clone()).into_val(&env)
+        )
+    );
+    client.mint(&new_admin, &user, &1i128);
+    assert!(client.try_mint(&admin, &user, &1i128).is_err());
+}
+
+#[test]
+fn test_set_admin_only_proposes() {
+    let (env, client, admin, user) = setup();
+    let new_admin = Address::generate(&env);
+
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "Veritix"),
+        &String::from_str(&env, "VTX"),
+        &7u32,
+    );
+
+    client.set_admin(&new_admin);
+    assert_eq!(client.pending_admin(), Some(new_admin.clone()));
+    client.mint(&admin, &user, &1i128);
+
+    client.accept_admin();
+    client.mint(&new_admin, &user, &1i128);
+}
+
+#[test]
+fn test_mint_batch_updates_supply_once() {
+    let (env, client, admin, user) = setup();
+    let second = Address::generate(&env);
+    let third = Address::generate(&env);
+
+    client.initialize_with_cap(
+        &admin,
+        &String::from_str(&env, "Veritix"),
+        &String::from_str(&env, "VTX"),
+        &7u32,
+        &1000i128,
+    );
+
+    let mints = vec![&env, (user.clone(), 100i128), (second.clone(), 200i128), (third.clone(), 300i128)];
+    client.mint_batch(&admin, &mints);
+
+    assert_eq!(client.balance(&user), 100i128);
+    assert_eq!(client.balance(&second), 200i128);
+    assert_eq!(client.balance(&third), 300i128);
+    assert_eq!(client.total_supply(), 600i128);
+    assert_last_event(
+        &env,
+        (
+            client.address.clone(),
+            (symbol_short!("mint"), admin.clone(), third.clone()).into_val(&env),
+            300i128.into_val(&env)
+        )
+    );
+
+    // The cap applies to the batch total
+    let over_cap = vec![&env, (user.clone(), 300i128), (second.clone(), 101i128)];
+    assert!(client.try_mint_batch(&admin, &over_cap).is_err());
+    assert!(client.try_mint_batch(&admin, &vec![&env, (user.clone(), 0i128)]).is_err());
+    assert!(client.try_mint_batch(&admin, &soroban_sdk::Vec::new(&env)).is_err());
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #110)")]
+fn test_mint_batch_after_finalize_panics() {
+    let (env, client, admin, user) = setup();
+
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "Veritix"),
+        &String::from_str(&env, "VTX"),
+        &7u32,
+    );
+    client.finalize_supply(&admin);
+    client.mint_batch(&admin, &vec![&env, (user, 1i128)]);
+}
+
+#[test]
+fn test_transfer_with_memo_emits_reference() {
+    let (env, client, admin, user) = setup();
+    let merchant = Address::generate(&env);
+    let memo = BytesN::from_array(&env, &[7u8; 32]);
+
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "Veritix"),
+        &String::from_str(&env, "VTX"),
+        &7u32,
+    );
+    client.mint(&admin, &user, &1000i128);
+    client.transfer_with_memo(&user, &merchant, &250i128, &memo);
+
+    assert_eq!(client.balance(&merchant), 250i128);
+    assert_last_event(
+        &env,
+        (
+            client.address.clone(),
+            (symbol_short!("transfer"), user.clone(), merchant.clone(), memo.clone()).into_val(&env),
+            250i128.into_val(&env)
+        )
+    );
+}
+
+#[test]
+fn test_negative_amounts_rejected() {
+    let (env, client, admin, user) = setup();
+    let other = Address::generate(&env);
+    let spender = Address::generate(&env);
+
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "Veritix"),
+        &String::from_str(&env, "VTX"),
+        &7u32,
+    );
+    client.mint(&admin, &user, &1000i128);
+    client.mint(&admin, &other, &1000i128);
+    client.approve(&other, &spender, &500i128, &1000u32);
+
+    let invalid = Err(Ok(Error::InvalidAmount.into()));
+    assert_eq!(client.try_transfer(&user, &other, &-400i128), invalid);
+    assert_eq!(client.try_transfer_from(&spender, &other, &user, &-400i128), invalid);
+    assert_eq!(
+        client.try_transfer_with_memo(&user, &other, &-400i128, &BytesN::from_array(&env, &[0u8; 32])),
+        invalid
+    );
+    assert_eq!(client.try_burn(&user, &-400i128), invalid);
+    assert_eq!(client.try_mint(&admin, &user, &-400i128), invalid);
+
+    assert_eq!(client.balance(&user), 1000i128);
+    assert_eq!(client.balance(&other), 1000i128);
+    assert_eq!(client.total_supply(), 2000i128);
+}
@@ -0,0 +1,120 @@
+use crate::error::Error;
+use crate::storage_types::TimelockKey;
+use soroban_sdk::{contracttype, panic_with_error, Address, Env, String, Symbol};
+
+/// A sensitive admin call that must wait out the admin delay.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TimelockedAction {
+    Clawback(Address, i128),
+    SetAdmin(Address),
+    /// New name and symbol; decimals never change.
+    SetMetadata(String, String),
+    /// Changes the delay itself; 0 turns the timelock off.
+    SetDelay(u32),
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct QueuedAction {
+    pub id: u32,
+    pub action: TimelockedAction,
+    pub execute_after_ledger: u32,
+    pub executed: bool,
+    pub cancelled: bool,
+}
+
+/// Ledgers a queued action must wait; 0 means no timelock.
+pub fn read_admin_delay(e: &Env) -> u32 {
+    e.storage().instance().get(&TimelockKey::AdminDelay).unwrap_or(0)
+}
+
+/// Caller is responsible for admin authorization (see `check_admin`).
+pub fn write_admin_delay(e: &Env, delay_ledgers: u32) {
+    e.storage().instance().set(&TimelockKey::AdminDelay, &delay_ledgers);
+    e.events().publish(
+        (Symbol::new(e, "timelock"), Symbol::new(e, "delay_set")),
+        delay_ledgers
+    );
+}
+
+/// Panics while a delay is configured. Every direct path to a
+/// `TimelockedAction` must call this.
+pub fn require_no_timelock(e: &Env) {
+    if read_admin_delay(e) > 0 {
+        panic_with_error!(e, Error::TimelockRequired);
+    }
+}
+
+/// Queues `action` to run once the current delay has passed.
+/// Caller is responsible for admin authorization (see `check_admin`).
+pub fn queue_action(e: &Env, action: TimelockedAction) -> u32 {
+    let mut count: u32 = e.storage().instance().get(&TimelockKey::QueuedCount).unwrap_or(0);
+    count += 1;
+    e.storage().instance().set(&TimelockKey::QueuedCount, &count);
+
+    let execute_after_ledger = e.ledger().sequence() + read_admin_delay(e);
+    let record = QueuedAction {
+        id: count,
+        action: action.clone(),
+        execute_after_ledger,
+        executed: false,
+        cancelled: false,
+    };
+    e.storage().persistent().set(&TimelockKey::Queued(count), &record);
+
+    e.events().publish(
+        (Symbol::new(e, "timelock"), Symbol::new(e, "queued"), count),
+        (action, execute_after_ledger)
+    );
+
+    count
+}
+
+/// Caller is responsible for admin authorization (see `check_admin`).
+pub fn cancel_action(e: &Env, action_id: u32) {
+    let mut record = get_queued_action(e, action_id);
+    require_pending(e, &record);
+
+    record.cancelled = true;
+    e.storage().persistent().set(&TimelockKey::Queued(action_id), &record);
+    e.events().publish(
+        (Symbol::new(e, "timelock"), Symbol::new(e, "cancelled"), action_id),
+        ()
+    );
+}
+
+/// Marks a queued action executed once its delay has passed and returns it
+/// for the caller to apply. Anyone may trigger execution.
+pub fn take_ready_action(e: &Env, action_id: u32) -> TimelockedAction {
+    let mut record = get_queued_action(e, action_id);
+    require_pending(e, &record);
+    if e.ledger().sequence() < record.execute_after_ledger {
+        panic_with_error!(e, Error::ActionNotReady);
+    }
+
+    record.executed = true;
+    e.storage().persistent().set(&TimelockKey::Queued(action_id), &record);
+    e.events().publish(
+        (Symbol::new(e, "timelock"), Symbol::new(e, "executed"), action_id),
+        ()
+    );
+
+    record.action
+}
+
+fn require_pending(e: &Env, record: &QueuedAction) {
+    if record.executed {
+        panic_with_error!(e, Error::AlreadyExecuted);
+    }
+    if record.cancelled {
+        panic_with_error!(e, Error::ActionCancelled);
+    }
+}
+
+pub fn get_queued_action(e: &Env, action_id: u32) -> QueuedAction {
+    e.storage()
+        .persistent()
+        .get(&TimelockKey::Queued(action_id))
+        .unwrap_or_else(|| panic_with_error!(e, Error::ActionNotFound))
+}
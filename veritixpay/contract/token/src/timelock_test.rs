@@ -0,0 +1,188 @@
+#[cfg(test)]
+mod timelock_tests {
+    use crate::error::Error;
+    use crate::roles::Role;
+    use crate::storage_types::TransferFee;
+    use crate::timelock::TimelockedAction;
+    use crate::{VeritixToken, VeritixTokenClient};
+    use soroban_sdk::{
+        testutils::{Address as _, Events, Ledger},
+        vec, Address, Env, IntoVal, String, Symbol,
+    };
+
+    const DELAY: u32 = 100;
+
+    fn setup_test(e: &Env) -> (Address, Address, VeritixTokenClient<'_>) {
+        e.mock_all_auths();
+
+        let contract_id = e.register_contract(None, VeritixToken);
+        let client = VeritixTokenClient::new(e, &contract_id);
+
+        let admin = Address::generate(e);
+        let holder = Address::generate(e);
+
+        client.initialize(
+            &admin,
+            &String::from_str(e, "Veritix"),
+            &String::from_str(e, "VTX"),
+            &7u32,
+        );
+        client.mint(&admin, &holder, &1000i128);
+        e.ledger().set_sequence_number(10);
+        client.set_admin_timelock(&admin, &DELAY);
+
+        (admin, holder, client)
+    }
+
+    #[test]
+    fn test_direct_calls_blocked_while_timelocked() {
+        let e = Env::default();
+        let (admin, holder, client) = setup_test(&e);
+        let required = Err(Ok(Error::TimelockRequired.into()));
+
+        assert_eq!(client.try_clawback(&admin, &holder, &1), required);
+        assert_eq!(client.try_set_admin(&holder), required);
+        assert_eq!(
+            client.try_update_metadata(&admin, &String::from_str(&e, "New"), &String::from_str(&e, "NEW")),
+            required
+        );
+        assert_eq!(client.try_set_admin_timelock(&admin, &0), required);
+        assert_eq!(client.try_reverse_mint(&admin, &holder, &1, &admin), required);
+        assert_eq!(client.try_set_authorized(&admin, &holder, &false), required);
+        assert_eq!(client.try_blacklist(&admin, &holder), required);
+        assert_eq!(client.try_accept_admin(), required);
+        assert_eq!(client.try_unblacklist(&admin, &holder), required);
+        assert_eq!(client.try_grant_role(&admin, &Role::Minter, &holder), required);
+        assert_eq!(client.try_revoke_role(&admin, &Role::Minter, &holder), required);
+        assert_eq!(
+            client.try_set_transfer_fee(&admin, &TransferFee { bps: 100, collector: None, reflection_bps: 0 }),
+            required
+        );
+        assert_eq!(client.try_admin_sweep(&admin, &client.address, &1, &admin), required);
+    }
+
+    #[test]
+    fn test_enabling_timelock_drops_pending_admin() {
+        let e = Env::default();
+        e.mock_all_auths();
+        let contract_id = e.register_contract(None, VeritixToken);
+        let client = VeritixTokenClient::new(&e, &contract_id);
+        let admin = Address::generate(&e);
+        client.initialize(&admin, &String::from_str(&e, "Veritix"), &String::from_str(&e, "VTX"), &7u32);
+
+        client.propose_admin(&Address::generate(&e));
+        client.set_admin_timelock(&admin, &DELAY);
+
+        assert_eq!(client.pending_admin(), None);
+    }
+
+    #[test]
+    fn test_queued_clawback_waits_for_delay() {
+        let e = Env::default();
+        let (admin, holder, client) = setup_test(&e);
+
+        let action = TimelockedAction::Clawback(holder.clone(), 400);
+        let id = client.queue_admin_action(&admin, &action);
+        let events = e.events().all();
+        assert_eq!(
+            events.slice(events.len() - 1..),
+            vec![
+                &e,
+                (
+                    client.address.clone(),
+                    (Symbol::new(&e, "timelock"), Symbol::new(&e, "queued"), id).into_val(&e),
+                    (action, 10 + DELAY).into_val(&e),
+                ),
+            ]
+        );
+
+        e.ledger().set_sequence_number(10 + DELAY - 1);
+        assert_eq!(client.try_execute_admin_action(&id), Err(Ok(Error::ActionNotReady.into())));
+
+        e.ledger().set_sequence_number(10 + DELAY);
+        client.execute_admin_action(&id);
+        assert_eq!(client.balance(&holder), 600);
+        assert_eq!(client.total_supply(), 600);
+        assert!(client.get_queued_action(&id).executed);
+    }
+
+    #[test]
+    fn test_cancelled_action_never_runs() {
+        let e = Env::default();
+        let (admin, holder, client) = setup_test(&e);
+
+        let id = client.queue_admin_action(&admin, &TimelockedAction::SetAdmin(holder.clone()));
+        client.cancel_admin_action(&admin, &id);
+
+        e.ledger().set_sequence_number(10 + DELAY);
+        assert_eq!(client.try_execute_admin_action(&id), Err(Ok(Error::ActionCancelled.into())));
+        assert!(client.try_mint(&holder, &holder, &1).is_err());
+    }
+
+    #[test]
+    fn test_metadata_and_delay_changes_are_queued() {
+        let e = Env::default();
+        let (admin, _, client) = setup_test(&e);
+
+        let rename = TimelockedAction::SetMetadata(String::from_str(&e, "Veritix Pay"), String::from_str(&e, "VTXP"));
+        let rename_id = client.queue_admin_action(&admin, &rename);
+        let disable_id = client.queue_admin_action(&admin, &TimelockedAction::SetDelay(0));
+
+        e.ledger().set_sequence_number(10 + DELAY);
+        client.execute_admin_action(&rename_id);
+        client.execute_admin_action(&disable_id);
+
+        assert_eq!(client.name(), String::from_str(&e, "Veritix Pay"));
+        assert_eq!(client.symbol(), String::from_str(&e, "VTXP"));
+        assert_eq!(client.decimals(), 7);
+        assert_eq!(client.admin_timelock(), 0);
+
+        // With the timelock off, direct calls work again
+        client.update_metadata(&admin, &String::from_str(&e, "Veritix"), &String::from_str(&e, "VTX"));
+        assert_eq!(client.symbol(), String::from_str(&e, "VTX"));
+    }
+
+    #[test]
+    fn test_multisig_and_timelock_are_exclusive() {
+        let e = Env::default();
+        let (admin, holder, client) = setup_test(&e);
+        let signers = vec![&e, Address::generate(&e), Address::generate(&e)];
+
+        assert_eq!(
+            client.try_set_multisig(&admin, &signers, &2),
+            Err(Ok(Error::TimelockRequired.into()))
+        );
+
+        // An action still queued when multisig begins can no longer run
+        let rotate_id = client.queue_admin_action(&admin, &TimelockedAction::SetAdmin(holder.clone()));
+        let disable_id = client.queue_admin_action(&admin, &TimelockedAction::SetDelay(0));
+        e.ledger().set_sequence_number(10 + DELAY);
+        client.execute_admin_action(&disable_id);
+        client.set_multisig(&admin, &signers, &2);
+
+        assert_eq!(
+            client.try_execute_admin_action(&rotate_id),
+            Err(Ok(Error::MultisigRequired.into()))
+        );
+        assert!(!client.get_queued_action(&rotate_id).executed);
+    }
+
+    #[test]
+    fn test_queued_rotation_drops_pending_admin() {
+        let e = Env::default();
+        let (admin, holder, client) = setup_test(&e);
+
+        let rotate_id = client.queue_admin_action(&admin, &TimelockedAction::SetAdmin(holder.clone()));
+        let disable_id = client.queue_admin_action(&admin, &TimelockedAction::SetDelay(0));
+        e.ledger().set_sequence_number(10 + DELAY);
+        client.execute_admin_action(&disable_id);
+
+        // A handover proposed by the outgoing admin must not survive rotation
+        client.propose_admin(&Address::generate(&e));
+        client.execute_admin_action(&rotate_id);
+
+        assert_eq!(client.pending_admin(), None);
+        assert!(client.try_accept_admin().is_err());
+        client.mint(&holder, &holder, &1);
+    }
+}
@@ -0,0 +1,71 @@
+use crate::balance::peek_balance;
+use crate::storage_types::DataKey;
+use soroban_sdk::{Address, Env, Symbol};
+
+pub fn read_delegate(e: &Env, delegator: &Address) -> Option<Address> {
+    e.storage()
+        .persistent()
+        .get(&DataKey::Delegate(delegator.clone()))
+}
+
+fn read_delegated_votes(e: &Env, delegatee: &Address) -> i128 {
+    e.storage()
+        .persistent()
+        .get(&DataKey::DelegatedVotes(delegatee.clone()))
+        .unwrap_or(0)
+}
+
+fn add_delegated_votes(e: &Env, delegatee: &Address, delta: i128) {
+    let total = read_delegated_votes(e, delegatee) + delta;
+    e.storage()
+        .persistent()
+        .set(&DataKey::DelegatedVotes(delegatee.clone()), &total);
+}
+
+/// Points the delegator's voting weight at `delegatee` without moving
+/// tokens. Delegating to yourself clears the delegation.
+pub fn delegate(e: &Env, delegator: Address, delegatee: Address) {
+    delegator.require_auth();
+
+    let balance = peek_balance(e, delegator.clone());
+
+    // 1. Old delegatee loses the weight
+    if let Some(previous) = read_delegate(e, &delegator) {
+        add_delegated_votes(e, &previous, -balance);
+    }
+
+    // 2. New delegatee gains it
+    if delegatee == delegator {
+        e.storage()
+            .persistent()
+            .remove(&DataKey::Delegate(delegator.clone()));
+    } else {
+        add_delegated_votes(e, &delegatee, balance);
+        e.storage()
+            .persistent()
+            .set(&DataKey::Delegate(delegator.clone()), &delegatee);
+    }
+
+    e.events().publish(
+        (Symbol::new(e, "votes"), Symbol::new(e, "delegated"), delegator),
+        delegatee
+    );
+}
+
+/// Keeps the delegatee's total in step with the delegator's balance.
+/// Called by `balance.rs` whenever a balance changes by `delta`.
+pub fn move_delegated_votes(e: &Env, addr: &Address, delta: i128) {
+    if let Some(delegatee) = read_delegate(e, addr) {
+        add_delegated_votes(e, &delegatee, delta);
+    }
+}
+
+/// The account's own balance (unless delegated away) plus every balance
+/// delegated to it.
+pub fn get_votes(e: &Env, account: Address) -> i128 {
+    let own = match read_delegate(e, &account) {
+        Some(_) => 0,
+        None => peek_balance(e, account.clone()),
+    };
+    own + read_delegated_votes(e, &account)
+}
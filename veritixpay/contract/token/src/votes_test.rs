@@ -0,0 +1,76 @@
+#[cfg(test)]
+mod votes_tests {
+    use crate::{VeritixToken, VeritixTokenClient};
+    use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+    fn setup_test(e: &Env) -> (Address, Address, Address, VeritixTokenClient<'_>) {
+        e.mock_all_auths();
+
+        let contract_id = e.register_contract(None, VeritixToken);
+        let client = VeritixTokenClient::new(e, &contract_id);
+
+        let admin = Address::generate(e);
+        let alice = Address::generate(e);
+        let bob = Address::generate(e);
+
+        client.initialize(
+            &admin,
+            &String::from_str(e, "Veritix"),
+            &String::from_str(e, "VTX"),
+            &7u32,
+        );
+        client.mint(&admin, &alice, &1000i128);
+        client.mint(&admin, &bob, &500i128);
+
+        (admin, alice, bob, client)
+    }
+
+    #[test]
+    fn test_two_delegators_aggregate_on_delegatee() {
+        let e = Env::default();
+        let (admin, alice, bob, client) = setup_test(&e);
+        let delegatee = Address::generate(&e);
+        client.mint(&admin, &delegatee, &100i128);
+
+        client.delegate(&alice, &delegatee);
+        client.delegate(&bob, &delegatee);
+
+        assert_eq!(client.get_votes(&delegatee), 1600);
+        assert_eq!(client.get_votes(&alice), 0);
+        assert_eq!(client.get_votes(&bob), 0);
+    }
+
+    #[test]
+    fn test_redelegation_moves_weight() {
+        let e = Env::default();
+        let (_, alice, _, client) = setup_test(&e);
+        let first = Address::generate(&e);
+        let second = Address::generate(&e);
+
+        client.delegate(&alice, &first);
+        client.delegate(&alice, &second);
+
+        assert_eq!(client.get_votes(&first), 0);
+        assert_eq!(client.get_votes(&second), 1000);
+
+        // Delegating to self takes the weight back
+        client.delegate(&alice, &alice);
+        assert_eq!(client.get_votes(&second), 0);
+        assert_eq!(client.get_votes(&alice), 1000);
+    }
+
+    #[test]
+    fn test_transfers_update_delegated_votes() {
+        let e = Env::default();
+        let (_, alice, bob, client) = setup_test(&e);
+        let delegatee = Address::generate(&e);
+
+        client.delegate(&alice, &delegatee);
+        client.transfer(&alice, &bob, &300);
+        assert_eq!(client.get_votes(&delegatee), 700);
+
+        client.transfer(&bob, &alice, &100);
+        assert_eq!(client.get_votes(&delegatee), 800);
+        assert_eq!(client.get_votes(&bob), 700);
+    }
+}